@@ -0,0 +1,34 @@
+use anyhow::Result;
+use aoc_core::{input, parse, Answer, ParseError, Solution};
+
+pub struct SonarSweep;
+
+impl Solution for SonarSweep {
+    type Input = Vec<i64>;
+
+    fn parse_input(input: &str) -> Result<Vec<i64>, ParseError> {
+        parse_buffer(input)
+    }
+
+    fn part_one(input: Self::Input) -> Result<Answer> {
+        Ok(Answer::from(part_one(&input)))
+    }
+
+    fn part_two(input: Self::Input) -> Result<Answer> {
+        Ok(Answer::from(part_two(&input)))
+    }
+}
+
+fn parse_buffer(buffer: &str) -> Result<Vec<i64>, ParseError> {
+    input::lines(buffer)
+        .map(|l| parse::value(buffer, l, "a depth"))
+        .collect()
+}
+
+fn part_one(input: &[i64]) -> usize {
+    input.windows(2).filter(|w| w[0] < w[1]).count()
+}
+
+fn part_two(input: &[i64]) -> usize {
+    input.windows(4).filter(|w| w[0] < w[3]).count()
+}
@@ -1,27 +1,37 @@
-use std::io::{self, Read};
+use anyhow::Result;
+use aoc_core::parse::{self, Within};
+use aoc_core::{input, Answer, ParseError, Solution};
 
-fn main() -> io::Result<()> {
-    let mut stdin = io::stdin();
-    let mut buffer = String::new();
-    stdin.read_to_string(&mut buffer)?;
+pub struct Dive;
 
-    let commands: Vec<Command> = buffer.lines().map(|x| Command::new(x)).collect();
+impl Solution for Dive {
+    type Input = Vec<Command>;
 
-    part_one(&commands);
-    part_two(&commands);
-    Ok(())
+    fn parse_input(input: &str) -> Result<Vec<Command>, ParseError> {
+        input::lines(input)
+            .map(|l| Command::parse(input, l))
+            .collect()
+    }
+
+    fn part_one(commands: Self::Input) -> Result<Answer> {
+        Ok(Answer::from(part_one(&commands)))
+    }
+
+    fn part_two(commands: Self::Input) -> Result<Answer> {
+        Ok(Answer::from(part_two(&commands)))
+    }
 }
 
-fn part_one(commands: &Vec<Command>) {
+fn part_one(commands: &[Command]) -> i32 {
     let mut location = Location {
         horizontal: 0,
         depth: 0,
     };
     location = commands.iter().fold(location, |l, c| l.execute_command(c));
-    println!("Part 1: {}", location.multiply());
+    location.multiply()
 }
 
-fn part_two(commands: &Vec<Command>) {
+fn part_two(commands: &[Command]) -> i32 {
     let mut submarine_state = SubmarineState {
         location: Location {
             horizontal: 0,
@@ -32,24 +42,26 @@ fn part_two(commands: &Vec<Command>) {
     submarine_state = commands
         .iter()
         .fold(submarine_state, |s, c| s.execute_command(c));
-    println!("Part 2: {}", submarine_state.location.multiply());
+    submarine_state.location.multiply()
 }
 
-enum Command {
+pub enum Command {
     Forward(i32),
     Down(i32),
     Up(i32),
 }
 
 impl Command {
-    pub fn new(line: &str) -> Command {
-        let parts: Vec<&str> = line.split_whitespace().collect();
-        let amount = parts[1].parse::<i32>().unwrap();
-        match parts[0] {
-            "forward" => Command::Forward(amount),
-            "down" => Command::Down(amount),
-            "up" => Command::Up(amount),
-            _ => Command::Forward(0),
+    fn parse(input: &str, line: &str) -> Result<Command, ParseError> {
+        let mut parts = line.split_whitespace();
+        let direction = parse::next(&mut parts, line, "a direction").within(input, line)?;
+        let amount = parse::next(&mut parts, line, "an amount").within(input, line)?;
+        let amount = parse::value(input, amount, "an amount")?;
+        match direction {
+            "forward" => Ok(Command::Forward(amount)),
+            "down" => Ok(Command::Down(amount)),
+            "up" => Ok(Command::Up(amount)),
+            _ => Err(ParseError::at(input, direction, "forward, down or up")),
         }
     }
 }
@@ -91,16 +103,3 @@ impl SubmarineState {
         self
     }
 }
-
-#[cfg(test)]
-mod tests {
-    // TODO common setup which loads the Strings from the files (test.txt, input.txt)
-    #[test]
-    fn part_one_test() {}
-    #[test]
-    fn part_one_input() {}
-    #[test]
-    fn part_two_test() {}
-    #[test]
-    fn part_two_input() {}
-}
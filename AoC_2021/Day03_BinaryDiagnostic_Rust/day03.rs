@@ -1,17 +1,31 @@
-use std::io::{self, Read};
+use anyhow::Result;
+use aoc_core::{input, Answer, ParseError, Solution};
+use log::debug;
 
-fn main() -> io::Result<()> {
-    let mut stdin = io::stdin();
-    let mut buffer = String::new();
-    stdin.read_to_string(&mut buffer)?;
-    let lines: Vec<&str> = buffer.lines().collect();
+pub struct BinaryDiagnostic;
 
-    part_one(&lines);
-    part_two(&lines);
-    Ok(())
+impl Solution for BinaryDiagnostic {
+    type Input = Vec<String>;
+
+    fn parse_input(input: &str) -> Result<Vec<String>, ParseError> {
+        if let Some(i) = input.find(|c: char| !"01\r\n".contains(c)) {
+            return Err(ParseError::at(input, &input[i..], "a binary digit"));
+        }
+        Ok(input::lines(input).map(String::from).collect())
+    }
+
+    fn part_one(lines: Self::Input) -> Result<Answer> {
+        let lines: Vec<&str> = lines.iter().map(String::as_str).collect();
+        Ok(Answer::from(part_one(&lines)))
+    }
+
+    fn part_two(lines: Self::Input) -> Result<Answer> {
+        let lines: Vec<&str> = lines.iter().map(String::as_str).collect();
+        Ok(Answer::from(part_two(&lines)))
+    }
 }
 
-fn part_one(lines: &Vec<&str>) {
+fn part_one(lines: &Vec<&str>) -> u32 {
     let num_reports = lines.len();
     let line_length = lines.first().unwrap().len();
     let mut count_ones: Vec<usize> = vec![0; line_length];
@@ -26,7 +40,7 @@ fn part_one(lines: &Vec<&str>) {
         .iter()
         .map(|c| if *c > num_reports / 2 { '1' } else { '0' })
         .collect();
-    println!("{:?}", gamma_rate_binary);
+    debug!("gamma rate {:?}", gamma_rate_binary);
 
     let gamma_rate_string: String = gamma_rate_binary.iter().collect();
     let epsilon_rate_string: String = gamma_rate_binary
@@ -37,10 +51,10 @@ fn part_one(lines: &Vec<&str>) {
     let gamma_rate = u32::from_str_radix(&gamma_rate_string, 2).unwrap();
     let epsilon_rate = u32::from_str_radix(&epsilon_rate_string, 2).unwrap();
 
-    println!("Part 1: {}", gamma_rate * epsilon_rate);
+    gamma_rate * epsilon_rate
 }
 
-fn part_two(lines: &Vec<&str>) {
+fn part_two(lines: &Vec<&str>) -> u32 {
     let mut oxygen_generator_rating = lines.clone();
     let mut co2_scrubber_rating = lines.clone();
     for pos in 0..lines.first().unwrap().len() {
@@ -59,7 +73,7 @@ fn part_two(lines: &Vec<&str>) {
         u32::from_str_radix(oxygen_generator_rating.first().unwrap(), 2).unwrap();
     let co2_scrubber_rating = u32::from_str_radix(co2_scrubber_rating.first().unwrap(), 2).unwrap();
 
-    println!("Part 2: {}", oxygen_generator_rating * co2_scrubber_rating);
+    oxygen_generator_rating * co2_scrubber_rating
 }
 
 enum Rating {
@@ -100,16 +114,3 @@ fn filter_by_pos(pos: usize, required_in_pos: char, lines: Vec<&str>) -> Vec<&st
         .filter(|slice| (*slice).chars().nth(pos).unwrap() == required_in_pos)
         .collect()
 }
-
-#[cfg(test)]
-mod tests {
-    // TODO common setup which loads the Strings from the files (test.txt, input.txt)
-    #[test]
-    fn part_one_test() {}
-    #[test]
-    fn part_one_input() {}
-    #[test]
-    fn part_two_test() {}
-    #[test]
-    fn part_two_input() {}
-}
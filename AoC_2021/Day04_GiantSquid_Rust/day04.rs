@@ -1,46 +1,44 @@
+use anyhow::Result;
+use aoc_core::{input, parse, Answer, ParseError, Solution};
 use std::collections::{HashMap, HashSet};
-use std::io::{self, Read};
-
-fn main() -> io::Result<()> {
-    let mut stdin = io::stdin();
-    let mut buffer = String::new();
-    stdin.read_to_string(&mut buffer)?;
-    part_one(&buffer);
-    part_two(&buffer);
-    Ok(())
-}
 
-fn parse_input(buffer: &str) -> (Vec<i32>, Vec<BingoCard>) {
-    let mut iter = buffer.split_terminator("\r\n\r\n");
+fn parse_input(buffer: &str) -> Result<(Vec<i32>, Vec<BingoCard>), ParseError> {
+    let mut iter = input::blocks(buffer).into_iter();
 
-    let guesses: Vec<i32> = iter
-        .nth(0)
-        .expect("there was no guesses line")
-        .split(",")
-        .map(|x| x.parse::<i32>().expect("failed to parse a guess as i32"))
-        .collect();
+    let guesses = parse::next(&mut iter, buffer, "the guesses line")?
+        .split(',')
+        .map(|x| parse::value(buffer, x, "a guess"))
+        .collect::<Result<Vec<i32>, _>>()?;
 
-    let bingo_cards: Vec<BingoCard> = iter.map(|x| BingoCard::new(x)).collect();
+    let bingo_cards = iter
+        .map(|card| BingoCard::parse(buffer, card))
+        .collect::<Result<Vec<_>, _>>()?;
 
-    (guesses, bingo_cards)
+    Ok((guesses, bingo_cards))
 }
 
-fn part_one(buffer: &str) {
-    let (guesses, mut bingo_cards) = parse_input(buffer);
+pub struct GiantSquid;
 
-    let (last_guess, first_winner) =
-        play_until_first_winner(&guesses, &mut bingo_cards).expect("no first winner");
+impl Solution for GiantSquid {
+    type Input = (Vec<i32>, Vec<BingoCard>);
 
-    println!("Part 1: {}", last_guess * first_winner.sum_of_unmarked());
-}
+    fn parse_input(input: &str) -> Result<(Vec<i32>, Vec<BingoCard>), ParseError> {
+        parse_input(input)
+    }
 
-fn part_two(buffer: &str) {
-    let (guesses, mut bingo_cards) = parse_input(buffer);
+    fn part_one((guesses, mut bingo_cards): Self::Input) -> Result<Answer> {
+        let (last_guess, first_winner) =
+            play_until_first_winner(&guesses, &mut bingo_cards).expect("no first winner");
 
-    let (last_guess, last_winner) =
-        play_until_last_winner(&guesses, &mut bingo_cards).expect("no last winner");
+        Ok(Answer::from(last_guess * first_winner.sum_of_unmarked()))
+    }
+
+    fn part_two((guesses, mut bingo_cards): Self::Input) -> Result<Answer> {
+        let (last_guess, last_winner) =
+            play_until_last_winner(&guesses, &mut bingo_cards).expect("no last winner");
 
-    println!("Part 2: {}", last_guess * last_winner.sum_of_unmarked());
+        Ok(Answer::from(last_guess * last_winner.sum_of_unmarked()))
+    }
 }
 
 fn play_until_first_winner(
@@ -82,7 +80,7 @@ fn play_until_last_winner(
 }
 
 #[derive(Debug, Clone)]
-struct BingoCard {
+pub struct BingoCard {
     card: Vec<Vec<i32>>,
     rows: usize,
     cols: usize,
@@ -94,28 +92,33 @@ struct BingoCard {
 }
 
 impl BingoCard {
-    fn new(buffer: &str) -> BingoCard {
-        let card: Vec<Vec<i32>> = buffer
-            .lines()
+    fn parse(input: &str, buffer: &str) -> Result<BingoCard, ParseError> {
+        let card = input::lines(buffer)
             .map(|line| {
                 line.split_whitespace()
-                    .map(|x| {
-                        x.parse::<i32>()
-                            .expect("failed to parse a card entry as i32")
-                    })
-                    .collect::<Vec<_>>()
+                    .map(|x| parse::value(input, x, "a card entry"))
+                    .collect::<Result<Vec<i32>, _>>()
             })
-            .collect();
+            .collect::<Result<Vec<_>, _>>()?;
         let rows = card.len();
         let cols = card[0].len();
+        for (line, numbers) in input::lines(buffer).zip(card.iter()) {
+            if numbers.len() != cols {
+                return Err(ParseError::at(
+                    input,
+                    line,
+                    format!("{} card entries", cols),
+                ));
+            }
+        }
         let mut num_to_pos = HashMap::new();
-        for row in 0..rows {
-            for col in 0..cols {
-                num_to_pos.insert(card[row][col], (row, col));
+        for (row, numbers) in card.iter().enumerate() {
+            for (col, num) in numbers.iter().enumerate() {
+                num_to_pos.insert(*num, (row, col));
             }
         }
 
-        BingoCard {
+        Ok(BingoCard {
             card,
             rows,
             cols,
@@ -124,7 +127,7 @@ impl BingoCard {
             correct_in_col: HashMap::new(),
             marked_on_card: HashSet::new(),
             is_winning: false,
-        }
+        })
     }
 
     fn play_guess(&mut self, guess: &i32) {
@@ -155,16 +158,3 @@ impl BingoCard {
         sum
     }
 }
-
-#[cfg(test)]
-mod tests {
-    // TODO common setup which loads the Strings from the files (test.txt, input.txt)
-    #[test]
-    fn part_one_test() {}
-    #[test]
-    fn part_one_input() {}
-    #[test]
-    fn part_two_test() {}
-    #[test]
-    fn part_two_input() {}
-}
@@ -1,24 +1,35 @@
+use anyhow::Result;
+use aoc_core::{input, parse, Answer, ParseError, Solution};
 use lazy_static::lazy_static;
 use regex::Regex;
 use std::collections::HashMap;
-use std::io::{self, Read};
 use std::mem;
 
-fn main() -> io::Result<()> {
-    let mut stdin = io::stdin();
-    let mut buffer = String::new();
-    stdin.read_to_string(&mut buffer)?;
-    part_one(&buffer);
-    part_two(&buffer);
-    Ok(())
+fn parse_input(buffer: &str) -> Result<Vec<LineSegment>, ParseError> {
+    input::lines(buffer)
+        .map(|l| LineSegment::parse(buffer, l))
+        .collect()
 }
 
-fn parse_input(buffer: &str) -> Vec<LineSegment> {
-    buffer.lines().map(|line| LineSegment::new(line)).collect()
+pub struct HydrothermalVenture;
+
+impl Solution for HydrothermalVenture {
+    type Input = Vec<LineSegment>;
+
+    fn parse_input(input: &str) -> Result<Vec<LineSegment>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(line_segments: Self::Input) -> Result<Answer> {
+        Ok(Answer::from(part_one(&line_segments)))
+    }
+
+    fn part_two(line_segments: Self::Input) -> Result<Answer> {
+        Ok(Answer::from(part_two(&line_segments)))
+    }
 }
 
-fn part_one(buffer: &str) {
-    let line_segments = parse_input(buffer);
+fn part_one(line_segments: &[LineSegment]) -> usize {
     let mut line_counts: HashMap<(i32, i32), i32> = HashMap::new();
     for line_segment in line_segments.iter().filter(|s| s.is_axis_aligned()) {
         if line_segment.is_vertical() {
@@ -37,11 +48,10 @@ fn part_one(buffer: &str) {
     }
     let intersections = line_counts.iter().filter(|(_, val)| **val > 1).count();
 
-    println!("Part 1: {}", intersections);
+    intersections
 }
 
-fn part_two(buffer: &str) {
-    let line_segments = parse_input(buffer);
+fn part_two(line_segments: &[LineSegment]) -> usize {
     let mut line_counts: HashMap<(i32, i32), i32> = HashMap::new();
     let (axis_aligned, diagonal): (Vec<LineSegment>, Vec<LineSegment>) =
         line_segments.iter().partition(|s| s.is_axis_aligned());
@@ -75,32 +85,33 @@ fn part_two(buffer: &str) {
     }
     let intersections = line_counts.iter().filter(|(_, val)| **val > 1).count();
 
-    println!("Part 2: {}", intersections);
+    intersections
 }
 
 // Line segment
 #[derive(Clone, Copy)]
-struct LineSegment {
+pub struct LineSegment {
     start_point: (i32, i32),
     end_point: (i32, i32),
 }
 
 impl LineSegment {
-    fn new(line: &str) -> LineSegment {
+    fn parse(input: &str, line: &str) -> Result<LineSegment, ParseError> {
         lazy_static! {
             static ref RE: Regex =
-                Regex::new(r"(?P<x1>\d+),(?P<y1>\d+) -> (?P<x2>\d+),(?P<y2>\d+)")
+                Regex::new(r"^(?P<x1>\d+),(?P<y1>\d+) -> (?P<x2>\d+),(?P<y2>\d+)$")
                     .expect("failed to parse regex");
         }
-        let captures = RE.captures(line).expect("line didn't match");
-        let x1 = LineSegment::match_to_i32(captures.name("x1"));
-        let y1 = LineSegment::match_to_i32(captures.name("y1"));
-        let x2 = LineSegment::match_to_i32(captures.name("x2"));
-        let y2 = LineSegment::match_to_i32(captures.name("y2"));
-        LineSegment {
+        let captures = parse::captures(&RE, input, line)?;
+        let coordinate = |name: &str| parse::value(input, &captures[name], "a coordinate");
+        let x1 = coordinate("x1")?;
+        let y1 = coordinate("y1")?;
+        let x2 = coordinate("x2")?;
+        let y2 = coordinate("y2")?;
+        Ok(LineSegment {
             start_point: (x1, y1),
             end_point: (x2, y2),
-        }
+        })
     }
     fn is_vertical(&self) -> bool {
         self.start_point.0 == self.end_point.0
@@ -141,24 +152,4 @@ impl LineSegment {
         let dy = self.end_point.1 - self.start_point.1;
         (dx.signum(), dy.signum())
     }
-
-    fn match_to_i32(regex_match: Option<regex::Match>) -> i32 {
-        match regex_match {
-            Some(x) => x.as_str().parse().unwrap_or_default(),
-            None => 0,
-        }
-    }
-}
-
-#[cfg(test)]
-mod tests {
-    // TODO common setup which loads the Strings from the files (test.txt, input.txt)
-    #[test]
-    fn part_one_test() {}
-    #[test]
-    fn part_one_input() {}
-    #[test]
-    fn part_two_test() {}
-    #[test]
-    fn part_two_input() {}
 }
@@ -1,26 +1,35 @@
+use anyhow::Result;
+use aoc_core::{input, parse, Answer, ParseError, Solution};
 use std::collections::HashMap;
-use std::io::{self, Read};
 
-fn main() -> io::Result<()> {
-    let mut stdin = io::stdin();
-    let mut buffer = String::new();
-    stdin.read_to_string(&mut buffer)?;
-    part_one(&buffer);
-    part_two(&buffer);
-    Ok(())
-}
+pub struct Lanternfish;
+
+impl Solution for Lanternfish {
+    type Input = Vec<i64>;
+
+    fn parse_input(input: &str) -> Result<Vec<i64>, ParseError> {
+        let line = input::lines(input)
+            .next()
+            .ok_or_else(|| ParseError::missing(input, "a list of timers"))?;
+        line.split(',')
+            .map(|s| parse::value(input, s, "a timer"))
+            .collect()
+    }
+
+    fn part_one(initial_timers: Self::Input) -> Result<Answer> {
+        let amount_fishes = fishes_for_days(&initial_timers, 80);
+
+        Ok(Answer::from(amount_fishes))
+    }
 
-fn part_one(buffer: &str) {
-    let initial_timers: Vec<i64> = buffer
-        .split(",")
-        .map(|s| s.parse().expect("couldn't parse as i64"))
-        .collect();
-    let amount_fishes = fishes_for_days(&initial_timers, 80);
+    fn part_two(initial_timers: Self::Input) -> Result<Answer> {
+        let amount_fishes = fishes_for_days(&initial_timers, 256);
 
-    println!("Part 1: {}", amount_fishes);
+        Ok(Answer::from(amount_fishes))
+    }
 }
 
-fn fishes_for_days(initial_timers: &Vec<i64>, days: i64) -> i64 {
+fn fishes_for_days(initial_timers: &[i64], days: i64) -> i64 {
     let mut swarm = Lanternfishes {
         memo: HashMap::new(),
     };
@@ -30,16 +39,6 @@ fn fishes_for_days(initial_timers: &Vec<i64>, days: i64) -> i64 {
     amount_fishes
 }
 
-fn part_two(buffer: &str) {
-    let initial_timers: Vec<i64> = buffer
-        .split(",")
-        .map(|s| s.parse().expect("couldn't parse as i64"))
-        .collect();
-    let amount_fishes = fishes_for_days(&initial_timers, 256);
-
-    println!("Part 2: {}", amount_fishes);
-}
-
 struct Lanternfishes {
     memo: HashMap<(i64, i64), i64>,
 }
@@ -81,16 +80,3 @@ impl Lanternfishes {
         answer
     }
 }
-
-#[cfg(test)]
-mod tests {
-    // TODO common setup which loads the Strings from the files (test.txt, input.txt)
-    #[test]
-    fn part_one_test() {}
-    #[test]
-    fn part_one_input() {}
-    #[test]
-    fn part_two_test() {}
-    #[test]
-    fn part_two_input() {}
-}
@@ -0,0 +1,65 @@
+use anyhow::Result;
+use aoc_core::{input, parse, Answer, ParseError, Solution};
+use std::cmp;
+
+pub struct TheTreacheryOfWhales;
+
+impl Solution for TheTreacheryOfWhales {
+    type Input = Vec<i32>;
+
+    fn parse_input(input: &str) -> Result<Vec<i32>, ParseError> {
+        let line = input::lines(input)
+            .next()
+            .ok_or_else(|| ParseError::missing(input, "a list of positions"))?;
+        line.split(',')
+            .map(|x| parse::value(input, x, "a position"))
+            .collect()
+    }
+
+    fn part_one(mut crab_positions: Self::Input) -> Result<Answer> {
+        let align_to = median(&mut crab_positions);
+        let cost = crab_positions
+            .iter()
+            .fold(0, |acc, x| acc + (align_to - x).abs());
+        Ok(Answer::from(cost))
+    }
+
+    fn part_two(mut crab_positions: Self::Input) -> Result<Answer> {
+        crab_positions.sort_unstable();
+        let min = crab_positions.first().unwrap();
+        let max = crab_positions.last().unwrap();
+        let mut cost_min = i32::MAX;
+        for i in *min..*max + 1 {
+            let cost = crab_positions
+                .iter()
+                .fold(0, |acc, x| acc + gauss_distance(i, *x));
+            cost_min = cmp::min(cost_min, cost);
+        }
+        Ok(Answer::from(cost_min))
+    }
+}
+
+fn median(nums: &mut [i32]) -> i32 {
+    nums.sort_unstable();
+    let idx = nums.len() / 2;
+    nums[idx]
+}
+
+fn gauss_distance(a: i32, b: i32) -> i32 {
+    (a - b).abs() * ((a - b).abs() + 1) / 2
+}
+
+// sum 1 to n = (n * (n+1)) / 2
+// (a, b, c)
+// determine x such that min |a-x| + |b-x| + |c-x|
+// d(a, x) = sum k=1 to |a-x|: k => gauss |a-x| * (|a-x| + 1) / 2 => (x-a)^2 + |x-a| => 2(x-a) + sign(x-a)
+//
+
+// how can this be solved mathematically:
+// min_x sum i=1 to n: d( |crab_i - x| )
+// d(x) = x * (x+1) / 2
+// reformulate to:
+// min_z sum i=1 to n: d( z )
+// s.t. z_i >= +(crab_i - x)
+//      z_i >= -(crab_i - x)
+// constrained quadratic optimization problem
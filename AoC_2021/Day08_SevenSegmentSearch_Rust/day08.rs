@@ -1,43 +1,58 @@
+use anyhow::Result;
+use aoc_core::parse::{self, Within};
+use aoc_core::{input, Answer, ParseError, Solution};
 use itertools::Itertools;
+use log::trace;
 use std::collections::{HashMap, HashSet};
-use std::io::{self, Read};
-
-fn main() -> io::Result<()> {
-    let mut stdin = io::stdin();
-    let mut buffer = String::new();
-    stdin.read_to_string(&mut buffer)?;
-    part_one(&buffer);
-    part_two(&buffer);
-    Ok(())
-}
 
-fn parse_input(buffer: &str) -> Vec<(Vec<&str>, Vec<&str>)> {
+// the ten unique signal patterns and the four output digits of a display
+type Entry = (Vec<String>, Vec<String>);
+
+fn parse_input(buffer: &str) -> Result<Vec<Entry>, ParseError> {
+    if let Some(i) = buffer.find(|c: char| !("abcdefg| \r\n".contains(c))) {
+        return Err(ParseError::at(buffer, &buffer[i..], "a segment"));
+    }
     let mut output = Vec::new();
-    for line in buffer.lines() {
-        let mut pipe_split = line.split("|");
-        let digits: Vec<&str> = pipe_split
-            .nth(0)
-            .unwrap()
-            .split_whitespace()
-            .filter(|s| s.len() > 0)
-            .collect();
-        let output_digits: Vec<&str> = pipe_split
-            .nth(0)
-            .unwrap()
-            .split_whitespace()
-            .filter(|s| s.len() > 0)
-            .collect();
+    for line in input::lines(buffer) {
+        let mut pipe_split = line.split('|');
+        let digits_str = parse::next(&mut pipe_split, line, "the ten unique signal patterns")
+            .within(buffer, line)?;
+        let output_str =
+            parse::next(&mut pipe_split, line, "| and the output value").within(buffer, line)?;
+        let digits: Vec<String> = digits_str.split_whitespace().map(String::from).collect();
+        if digits.len() != 10 {
+            return Err(ParseError::at(
+                buffer,
+                digits_str,
+                "ten unique signal patterns",
+            ));
+        }
+        let output_digits: Vec<String> = output_str.split_whitespace().map(String::from).collect();
         output.push((digits, output_digits));
     }
-    output
+    Ok(output)
 }
 
-fn part_one(buffer: &str) {
-    let input = parse_input(buffer);
-    let sum = input.iter().fold(0, |acc, (_, out)| {
-        acc + out.iter().filter(|x| is_simple_digit(x)).count()
-    });
-    println!("Part 1: {}", sum);
+pub struct SevenSegmentSearch;
+
+impl Solution for SevenSegmentSearch {
+    type Input = Vec<Entry>;
+
+    fn parse_input(input: &str) -> Result<Vec<Entry>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(input: Self::Input) -> Result<Answer> {
+        let sum = input.iter().fold(0, |acc, (_, out)| {
+            acc + out.iter().filter(|x| is_simple_digit(x)).count()
+        });
+        Ok(Answer::from(sum))
+    }
+
+    fn part_two(input: Self::Input) -> Result<Answer> {
+        let answer = input.iter().fold(0, |acc, (d, od)| acc + decode(d, od));
+        Ok(Answer::from(answer))
+    }
 }
 
 fn is_simple_digit(digit: &str) -> bool {
@@ -46,12 +61,6 @@ fn is_simple_digit(digit: &str) -> bool {
     len == 2 || len == 4 || len == 3 || len == 7
 }
 
-fn part_two(buffer: &str) {
-    let input = parse_input(buffer);
-    let answer = input.iter().fold(0, |acc, (d, od)| acc + decode(d, od));
-    println!("Part 2: {}", answer);
-}
-
 // seven segment odering
 //   0000
 //  1    2
@@ -60,31 +69,31 @@ fn part_two(buffer: &str) {
 //  4    5
 //  4    5
 //   6666
-fn decode(digits: &Vec<&str>, output_digits: &Vec<&str>) -> i32 {
+fn decode(digits: &[String], output_digits: &[String]) -> i32 {
     // brute force
     let decodings = possible_decodings();
     for decoding in decodings.iter() {
         if let Some(mapping) = digit_mapping(digits, decoding) {
-            //println!("{:?}", mapping);
+            trace!("{:?}", mapping);
 
             let mut thousands_chars: Vec<char> = output_digits[0].chars().collect();
             thousands_chars.sort();
-            //println!("{:?}", thousands_chars);
+            trace!("{:?}", thousands_chars);
             let thousands = mapping[&thousands_chars];
 
             let mut hundreds_chars: Vec<char> = output_digits[1].chars().collect();
             hundreds_chars.sort();
-            //println!("{:?}", hundreds_chars);
+            trace!("{:?}", hundreds_chars);
             let hundreds = mapping[&hundreds_chars];
 
             let mut tens_chars: Vec<char> = output_digits[2].chars().collect();
             tens_chars.sort();
-            //println!("{:?}", tens_chars);
+            trace!("{:?}", tens_chars);
             let tens = mapping[&tens_chars];
 
             let mut ones_chars: Vec<char> = output_digits[3].chars().collect();
             ones_chars.sort();
-            //println!("{:?}", ones_chars);
+            trace!("{:?}", ones_chars);
             let ones = mapping[&ones_chars];
 
             return 1000 * thousands + 100 * hundreds + 10 * tens + ones;
@@ -95,7 +104,7 @@ fn decode(digits: &Vec<&str>, output_digits: &Vec<&str>) -> i32 {
 
 fn possible_decodings() -> Vec<HashMap<char, i32>> {
     let perms: Vec<Vec<i32>> = (0..7).permutations(7).collect();
-    let chars = vec!['a', 'b', 'c', 'd', 'e', 'f', 'g'];
+    let chars = ['a', 'b', 'c', 'd', 'e', 'f', 'g'];
     let mut mappings = Vec::new();
     for perm in perms.iter() {
         let mut mapping = HashMap::new();
@@ -107,8 +116,8 @@ fn possible_decodings() -> Vec<HashMap<char, i32>> {
     mappings
 }
 
-fn digit_mapping<'a>(
-    digits: &Vec<&'a str>,
+fn digit_mapping(
+    digits: &[String],
     decoding: &HashMap<char, i32>,
 ) -> Option<HashMap<Vec<char>, i32>> {
     let mut digit_mapping = HashMap::new();
@@ -135,7 +144,7 @@ fn is_valid_mapping(digit_mapping: &HashMap<Vec<char>, i32>) -> bool {
             return false;
         }
     }
-    if values_set.iter().count() != 10 {
+    if values_set.len() != 10 {
         return false;
     }
     true
@@ -158,7 +167,7 @@ fn digit_to_segments(digit: &str, decoding: &HashMap<char, i32>) -> Vec<i32> {
 // 7 -> 3,[0,2,5]
 // 8 -> 7,[0,1,2,3,4,5,6]
 // 9 -> 6,[0,1,2,3,5,6]
-fn segments_to_digit(segments: &Vec<i32>) -> Option<i32> {
+fn segments_to_digit(segments: &[i32]) -> Option<i32> {
     match segments[..] {
         [0, 1, 2, 4, 5, 6] => Some(0),
         [2, 5] => Some(1),
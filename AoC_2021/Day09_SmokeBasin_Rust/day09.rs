@@ -0,0 +1,90 @@
+use anyhow::Result;
+use aoc_core::grid::Pos;
+use aoc_core::{Answer, Grid, ParseError, Solution};
+use log::debug;
+use std::collections::{HashSet, VecDeque};
+
+pub struct SmokeBasin;
+
+impl Solution for SmokeBasin {
+    type Input = Heightmap;
+
+    fn parse_input(input: &str) -> Result<Heightmap, ParseError> {
+        Heightmap::parse(input)
+    }
+
+    fn part_one(heightmap: Self::Input) -> Result<Answer> {
+        let risk_levels_sum: i32 = heightmap
+            .low_points()
+            .iter()
+            .map(|&pos| heightmap.heights[pos] + 1)
+            .sum();
+
+        Ok(Answer::from(risk_levels_sum))
+    }
+
+    fn part_two(heightmap: Self::Input) -> Result<Answer> {
+        let low_points = heightmap.low_points();
+        let mut basin_sizes: Vec<usize> = low_points
+            .iter()
+            .map(|lp| heightmap.basin_size(*lp))
+            .collect();
+        basin_sizes.sort();
+
+        debug!("low_points: {:?}", low_points);
+        debug!("basin_sizes: {:?}", basin_sizes);
+        let answer: usize = basin_sizes.iter().rev().take(3).product();
+        Ok(Answer::from(answer))
+    }
+}
+
+pub struct Heightmap {
+    heights: Grid<i32>,
+}
+
+impl Heightmap {
+    fn parse(buffer: &str) -> Result<Heightmap, ParseError> {
+        let heights = Grid::parse(buffer, "a height", |c| c.to_digit(10).map(|d| d as i32))?;
+        Ok(Heightmap { heights })
+    }
+
+    fn low_points(&self) -> Vec<Pos> {
+        let mut low_points = Vec::new();
+        for pos in self.heights.positions() {
+            let current_height = self.heights[pos];
+            let found_smaller_or_equal = self
+                .heights
+                .neighbors4(pos)
+                .any(|neighbor| self.heights[neighbor] <= current_height);
+            if !found_smaller_or_equal {
+                // low_point
+                low_points.push(pos);
+            }
+        }
+        low_points
+    }
+
+    fn basin_size(&self, low_point: Pos) -> usize {
+        let mut visited: HashSet<Pos> = HashSet::new();
+        let mut queue: VecDeque<Pos> = VecDeque::new();
+
+        queue.push_back(low_point);
+        visited.insert(low_point);
+
+        while let Some(pos) = queue.pop_front() {
+            for neighbor in self.heights.neighbors4(pos) {
+                if visited.contains(&neighbor) || self.heights[neighbor] == 9 {
+                    continue;
+                }
+                let height_difference = self.heights[neighbor] - self.heights[pos];
+
+                if height_difference >= 0 {
+                    queue.push_back(neighbor);
+                    visited.insert(neighbor);
+                }
+            }
+        }
+
+        visited.len()
+    }
+}
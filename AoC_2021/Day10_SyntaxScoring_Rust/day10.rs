@@ -1,17 +1,35 @@
-use std::io::{self, Read};
+use anyhow::Result;
+use aoc_core::{input, Answer, ParseError, Solution};
 
-fn main() -> io::Result<()> {
-    let mut stdin = io::stdin();
-    let mut buffer = String::new();
-    stdin.read_to_string(&mut buffer)?;
-    part_one(&buffer);
-    part_two(&buffer);
-    Ok(())
-}
+pub struct SyntaxScoring;
+
+impl Solution for SyntaxScoring {
+    type Input = Vec<String>;
+
+    fn parse_input(input: &str) -> Result<Vec<String>, ParseError> {
+        input::lines(input)
+            .map(|l| match l.find(|c| !"()[]{}<>".contains(c)) {
+                Some(i) => Err(ParseError::at(input, &l[i..], "a bracket")),
+                None => Ok(String::from(l)),
+            })
+            .collect()
+    }
+
+    fn part_one(lines: Self::Input) -> Result<Answer> {
+        let syntax_error_score: i64 = lines.iter().map(|l| score_line(l)).sum();
+        Ok(Answer::from(syntax_error_score))
+    }
 
-fn part_one(buffer: &str) {
-    let syntax_error_score: i64 = buffer.lines().map(|l| score_line(l)).sum();
-    println!("Part 1: {}", syntax_error_score);
+    fn part_two(lines: Self::Input) -> Result<Answer> {
+        let mut line_scores: Vec<i64> = lines
+            .iter()
+            .filter(|l| score_line(l) == 0)
+            .map(|l| score_completion(l))
+            .collect();
+        line_scores.sort_unstable();
+        let middle: usize = line_scores.len() / 2;
+        Ok(Answer::from(line_scores[middle]))
+    }
 }
 
 fn score_line(line: &str) -> i64 {
@@ -30,17 +48,14 @@ fn score_line(line: &str) -> i64 {
 }
 
 fn is_opening(c: char) -> bool {
-    match c {
-        '(' | '[' | '{' | '<' => true,
-        _ => false,
-    }
+    matches!(c, '(' | '[' | '{' | '<')
 }
 
 fn is_corresponding_closing(open: char, close: char) -> bool {
-    match (open, close) {
-        ('(', ')') | ('[', ']') | ('{', '}') | ('<', '>') => true,
-        _ => false,
-    }
+    matches!(
+        (open, close),
+        ('(', ')') | ('[', ']') | ('{', '}') | ('<', '>')
+    )
 }
 
 fn score_illegal(illegal: char) -> i64 {
@@ -53,17 +68,6 @@ fn score_illegal(illegal: char) -> i64 {
     }
 }
 
-fn part_two(buffer: &str) {
-    let incomplete_lines: Vec<&str> = buffer.lines().filter(|l| score_line(l) == 0).collect();
-    let mut line_scores: Vec<i64> = incomplete_lines
-        .iter()
-        .map(|l| score_completion(l))
-        .collect();
-    line_scores.sort_unstable();
-    let middle: usize = line_scores.len() / 2;
-    println!("Part 2: {}", line_scores[middle]);
-}
-
 fn score_completion(line: &str) -> i64 {
     let mut stack = Vec::new();
     for c in line.chars() {
@@ -0,0 +1,88 @@
+use anyhow::Result;
+use aoc_core::grid::{Pos, EIGHT_NEIGHBORS};
+use aoc_core::{Answer, Grid, ParseError, Solution};
+use std::collections::{HashSet, VecDeque};
+
+#[derive(Debug, Clone)]
+pub struct DumboOctopusGrid {
+    grid: Grid<u32>,
+}
+
+impl DumboOctopusGrid {
+    fn simulate_day(&mut self) -> usize {
+        let mut already_flashing: HashSet<Pos> = HashSet::new();
+        let mut flashing_queue: VecDeque<Pos> = VecDeque::new();
+
+        for pos in self.grid.positions() {
+            let value = &mut self.grid[pos];
+            *value += 1;
+            if *value > 9 {
+                already_flashing.insert(pos);
+                flashing_queue.push_back(pos);
+            }
+        }
+
+        while let Some(pos) = flashing_queue.pop_front() {
+            for offset in EIGHT_NEIGHBORS {
+                let Some(neighbor_pos) = self.grid.offset(pos, offset) else {
+                    continue;
+                };
+                if already_flashing.contains(&neighbor_pos) {
+                    continue;
+                }
+                let value = &mut self.grid[neighbor_pos];
+                *value += 1;
+                if *value > 9 {
+                    already_flashing.insert(neighbor_pos);
+                    flashing_queue.push_back(neighbor_pos);
+                }
+            }
+        }
+        for pos in already_flashing.iter() {
+            self.grid[*pos] = 0
+        }
+        already_flashing.len()
+    }
+}
+
+pub struct DumboOctopus;
+
+impl Solution for DumboOctopus {
+    type Input = DumboOctopusGrid;
+
+    fn parse_input(input: &str) -> Result<DumboOctopusGrid, ParseError> {
+        parse_buffer(input)
+    }
+
+    fn part_one(mut grid: Self::Input) -> Result<Answer> {
+        Ok(Answer::from(part_one(&mut grid)))
+    }
+
+    fn part_two(mut grid: Self::Input) -> Result<Answer> {
+        Ok(Answer::from(part_two(&mut grid)))
+    }
+}
+
+fn parse_buffer(buffer: &str) -> Result<DumboOctopusGrid, ParseError> {
+    let grid = Grid::parse(buffer, "an energy level", |c| c.to_digit(10))?;
+    Ok(DumboOctopusGrid { grid })
+}
+
+fn part_one(input: &mut DumboOctopusGrid) -> usize {
+    let mut flashes_total = 0;
+    for _ in 0..100 {
+        flashes_total += input.simulate_day();
+    }
+    flashes_total
+}
+
+fn part_two(input: &mut DumboOctopusGrid) -> usize {
+    let mut day = 0;
+    loop {
+        day += 1;
+        let amount = input.simulate_day();
+        if amount == input.grid.rows() * input.grid.cols() {
+            break day;
+        }
+    }
+}
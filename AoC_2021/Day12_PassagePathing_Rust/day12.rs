@@ -1,24 +1,13 @@
+use anyhow::Result;
+use aoc_core::{input, parse, Answer, ParseError, Solution};
 use lazy_static::lazy_static;
+use log::debug;
 use multiset::HashMultiSet;
 use regex::Regex;
 use std::collections::HashMap;
-use std::io::{self, Read};
-
-fn main() {
-    let mut stdin = io::stdin();
-    let mut buffer = String::new();
-    stdin
-        .read_to_string(&mut buffer)
-        .expect("failed to read file");
-    let mut input_part_one = parse_buffer(&buffer);
-    let mut input_part_two = input_part_one.clone();
-    println!("{:?}", input_part_one);
-    println!("Part 1: {}", part_one(&mut input_part_one));
-    println!("Part 2: {}", part_two(&mut input_part_two));
-}
 
 #[derive(Debug, Clone)]
-struct CaveGraph {
+pub struct CaveGraph {
     adjacency: HashMap<String, HashMultiSet<String>>,
     start_label: String,
     end_label: String,
@@ -31,7 +20,7 @@ impl CaveGraph {
             .adjacency
             .keys()
             .filter(|k| CaveGraph::big_cave(k))
-            .map(|c| c.clone()) // don't know if this is needed but just do it for safety for now
+            .cloned() // don't know if this is needed but just do it for safety for now
             .collect();
         for big_cave in big_caves.iter() {
             let neighbors = self.adjacency[big_cave].clone();
@@ -55,12 +44,12 @@ impl CaveGraph {
 
     fn count_paths(&self, allow_twice: bool) -> i64 {
         let mut path_count = 0;
-        let mut visited: HashMap<String, i64> = HashMap::new();
+        let visited: HashMap<String, i64> = HashMap::new();
         self.dfs(
             &mut path_count,
             self.start_label.clone(),
             self.end_label.clone(),
-            &mut visited,
+            &visited,
             allow_twice,
         );
         path_count
@@ -102,9 +91,27 @@ impl CaveGraph {
     }
 }
 
-fn parse_buffer(buffer: &str) -> CaveGraph {
+pub struct PassagePathing;
+
+impl Solution for PassagePathing {
+    type Input = CaveGraph;
+
+    fn parse_input(input: &str) -> Result<CaveGraph, ParseError> {
+        parse_buffer(input)
+    }
+
+    fn part_one(mut cave_graph: Self::Input) -> Result<Answer> {
+        Ok(Answer::from(part_one(&mut cave_graph)))
+    }
+
+    fn part_two(mut cave_graph: Self::Input) -> Result<Answer> {
+        Ok(Answer::from(part_two(&mut cave_graph)))
+    }
+}
+
+fn parse_buffer(buffer: &str) -> Result<CaveGraph, ParseError> {
     lazy_static! {
-        static ref RE: Regex = Regex::new("^(?P<A>.*)-(?P<B>.*)$").unwrap();
+        static ref RE: Regex = Regex::new("^(?P<A>[A-Za-z]+)-(?P<B>[A-Za-z]+)$").unwrap();
     }
 
     let mut cave_graph = CaveGraph {
@@ -112,8 +119,8 @@ fn parse_buffer(buffer: &str) -> CaveGraph {
         start_label: String::from("start"),
         end_label: String::from("end"),
     };
-    for line in buffer.lines() {
-        let caps = RE.captures(line).unwrap();
+    for line in input::lines(buffer) {
+        let caps = parse::captures(&RE, buffer, line)?;
         let node_a = caps.name("A").unwrap().as_str().to_string();
         let node_b = caps.name("B").unwrap().as_str().to_string();
 
@@ -129,13 +136,13 @@ fn parse_buffer(buffer: &str) -> CaveGraph {
             .or_insert(HashMultiSet::new());
         adjacent_node_b.insert(node_a.clone());
     }
-    cave_graph
+    Ok(cave_graph)
 }
 
 fn part_one(cave_graph: &mut CaveGraph) -> i64 {
     let allow_twice = false;
     cave_graph.eliminate_big_caves(allow_twice);
-    println!("{:?}", cave_graph);
+    debug!("{:?}", cave_graph);
 
     cave_graph.count_paths(allow_twice)
 }
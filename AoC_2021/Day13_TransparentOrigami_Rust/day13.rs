@@ -0,0 +1,138 @@
+use anyhow::Result;
+use aoc_core::{input, ocr, parse, Answer, ParseError, Solution};
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::collections::{HashSet, VecDeque};
+
+pub struct Origami {
+    dots: HashSet<Dot>,
+    instructions: VecDeque<FoldingInstruction>,
+}
+
+impl Origami {
+    fn count_dots(&self) -> i128 {
+        self.dots.len() as i128
+    }
+    fn execute_instruction(&mut self) -> bool {
+        let mut new_dots = HashSet::new();
+        if let Some(instruction) = self.instructions.pop_front() {
+            for dot in &self.dots {
+                new_dots.insert(instruction.fold_dot(dot));
+            }
+            self.dots = new_dots;
+            true
+        } else {
+            false
+        }
+    }
+    fn render(&self) -> Vec<String> {
+        let max_x = self.dots.iter().map(|d| d.x).max().unwrap_or(-1);
+        let max_y = self.dots.iter().map(|d| d.y).max().unwrap_or(-1);
+        (0..=max_y)
+            .map(|y| {
+                (0..=max_x)
+                    .map(|x| {
+                        if self.dots.contains(&Dot { x, y }) {
+                            '#'
+                        } else {
+                            '.'
+                        }
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+struct Dot {
+    x: i128,
+    y: i128,
+}
+
+impl Dot {
+    fn parse(input: &str, line: &str) -> Result<Self, ParseError> {
+        lazy_static! {
+            static ref RE: Regex = Regex::new(r"^(\d+),(\d+)$").unwrap();
+        }
+        let captures = parse::captures(&RE, input, line)?;
+        Ok(Dot {
+            x: parse::value(input, captures.get(1).unwrap().as_str(), "an x coordinate")?,
+            y: parse::value(input, captures.get(2).unwrap().as_str(), "a y coordinate")?,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+enum FoldingInstruction {
+    X(i128),
+    Y(i128),
+}
+
+impl FoldingInstruction {
+    fn parse(input: &str, line: &str) -> Result<Self, ParseError> {
+        lazy_static! {
+            static ref RE: Regex = Regex::new(r"^fold along ([xy])=(\d+)$").unwrap();
+        }
+        let captures = parse::captures(&RE, input, line)?;
+        let at = parse::value(input, captures.get(2).unwrap().as_str(), "a fold position")?;
+        Ok(match &captures[1] {
+            "x" => FoldingInstruction::X(at),
+            _ => FoldingInstruction::Y(at),
+        })
+    }
+
+    // fold on Y = 7
+    // only dots Y > 7 are affected
+    // dot (x, 10) for example
+    // 10 - (7+1) = 2
+    // (7-1) - (10 - (7+1)) = 4
+    //
+    // new coordinate becomes (x, 4)
+    //
+    // general formula:
+    // fa - 1 - (a - (fa + 1))
+    // fa - 1 - a + fa + 1
+    // 2fa - a
+    fn fold_dot(&self, dot: &Dot) -> Dot {
+        match (self, dot) {
+            (&FoldingInstruction::X(f), &Dot { x, y }) if x > f => Dot { x: 2 * f - x, y },
+            (&FoldingInstruction::Y(f), &Dot { x, y }) if y > f => Dot { x, y: 2 * f - y },
+            (_, &Dot { x, y }) => Dot { x, y },
+        }
+    }
+}
+
+fn parse_input(input: &str) -> Result<Origami, ParseError> {
+    let mut dots = HashSet::new();
+    let mut instructions = VecDeque::new();
+    let mut blocks = input::blocks(input).into_iter();
+    for line in input::lines(parse::next(&mut blocks, input, "the dots")?) {
+        dots.insert(Dot::parse(input, line)?);
+    }
+    for line in input::lines(parse::next(&mut blocks, input, "the folding instructions")?) {
+        instructions.push_back(FoldingInstruction::parse(input, line)?);
+    }
+    Ok(Origami { dots, instructions })
+}
+
+pub struct TransparentOrigami;
+
+impl Solution for TransparentOrigami {
+    type Input = Origami;
+
+    fn parse_input(input: &str) -> Result<Origami, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(mut origami: Self::Input) -> Result<Answer> {
+        origami.execute_instruction();
+
+        Ok(Answer::from(origami.count_dots()))
+    }
+
+    fn part_two(mut origami: Self::Input) -> Result<Answer> {
+        while origami.execute_instruction() {}
+        Ok(ocr::answer(origami.render()))
+    }
+}
@@ -0,0 +1,162 @@
+use anyhow::Result;
+use aoc_core::{input, parse, Answer, ParseError, Solution};
+use lazy_static::lazy_static;
+use log::debug;
+use regex::Regex;
+use std::collections::{BTreeMap, HashMap};
+
+/// A polymer as the number of times each pair of neighbouring elements
+/// occurs, which stays as small as the rules no matter how long the polymer
+/// grows.
+#[derive(Debug, Clone)]
+pub struct Polymer {
+    pairs: HashMap<(char, char), i128>,
+    // every element but the last one is the first of a pair, and the last
+    // one never changes
+    last: char,
+    rules: HashMap<(char, char), char>,
+}
+
+impl Polymer {
+    /// Inserts an element between every pair with a rule.
+    pub fn step(&mut self) {
+        let mut pairs = HashMap::with_capacity(self.pairs.len());
+        for (&(left, right), &count) in self.pairs.iter() {
+            match self.rules.get(&(left, right)) {
+                Some(&inserted) => {
+                    *pairs.entry((left, inserted)).or_insert(0) += count;
+                    *pairs.entry((inserted, right)).or_insert(0) += count;
+                }
+                None => *pairs.entry((left, right)).or_insert(0) += count,
+            }
+        }
+        self.pairs = pairs;
+    }
+
+    pub fn steps(&mut self, steps: usize) {
+        for _ in 0..steps {
+            self.step();
+        }
+    }
+
+    /// How often every element occurs.
+    pub fn histogram(&self) -> BTreeMap<char, i128> {
+        let mut histogram = BTreeMap::from([(self.last, 1)]);
+        for (&(first, _), &count) in self.pairs.iter() {
+            *histogram.entry(first).or_insert(0) += count;
+        }
+        histogram
+    }
+
+    fn most_minus_least_common(&self) -> i128 {
+        let histogram = self.histogram();
+        debug!("{:?}", histogram);
+        let most = histogram.values().max().copied().unwrap_or(0);
+        let least = histogram.values().min().copied().unwrap_or(0);
+        most - least
+    }
+}
+
+fn parse_input(input: &str) -> Result<Polymer, ParseError> {
+    lazy_static! {
+        static ref RULE: Regex = Regex::new(r"^([A-Z])([A-Z]) -> ([A-Z])$").unwrap();
+    }
+    let mut blocks = input::blocks(input).into_iter();
+    let template = parse::next(&mut blocks, input, "the polymer template")?;
+    if !template.chars().all(|c| c.is_ascii_uppercase()) {
+        return Err(ParseError::at(input, template, "a polymer template"));
+    }
+    let elements: Vec<char> = template.chars().collect();
+    let mut pairs = HashMap::new();
+    for pair in elements.windows(2) {
+        *pairs.entry((pair[0], pair[1])).or_insert(0) += 1;
+    }
+    let mut rules = HashMap::new();
+    let rule_lines = parse::next(&mut blocks, input, "the pair insertion rules")?;
+    for line in input::lines(rule_lines) {
+        let caps = parse::captures(&RULE, input, line)?;
+        let element = |i: usize| caps[i].chars().next().unwrap();
+        rules.insert((element(1), element(2)), element(3));
+    }
+    Ok(Polymer {
+        pairs,
+        last: elements[elements.len() - 1],
+        rules,
+    })
+}
+
+aoc_core::params! {
+    pub struct Params {
+        steps_one: usize = 10,
+        steps_two: usize = 40,
+    }
+}
+
+pub struct ExtendedPolymerization;
+
+impl Solution for ExtendedPolymerization {
+    type Input = Polymer;
+
+    fn parse_input(input: &str) -> Result<Polymer, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(mut polymer: Self::Input) -> Result<Answer> {
+        polymer.steps(Params::get()?.steps_one);
+        Ok(Answer::from(polymer.most_minus_least_common()))
+    }
+
+    fn part_two(mut polymer: Self::Input) -> Result<Answer> {
+        polymer.steps(Params::get()?.steps_two);
+        Ok(Answer::from(polymer.most_minus_least_common()))
+    }
+
+    fn params() -> Vec<(&'static str, String)> {
+        Params::defaults()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use lazy_static::lazy_static;
+    use std::fs;
+
+    lazy_static! {
+        static ref TEST: String = read_from_file("Day14_ExtendedPolymerization_Rust/test.txt");
+    }
+
+    fn read_from_file(filename: &str) -> String {
+        fs::read_to_string(filename)
+            .unwrap_or_else(|msg| panic!("error reading {}: {}", filename, msg))
+    }
+
+    #[test]
+    fn histogram_follows_the_steps() {
+        let mut polymer = parse_input(&TEST).unwrap();
+        assert_eq!(
+            polymer.histogram(),
+            BTreeMap::from([('B', 1), ('C', 1), ('N', 2)])
+        );
+        // NCNBCHB
+        polymer.step();
+        assert_eq!(
+            polymer.histogram(),
+            BTreeMap::from([('B', 2), ('C', 2), ('H', 1), ('N', 2)])
+        );
+        polymer.steps(4);
+        assert_eq!(polymer.histogram().values().sum::<i128>(), 97);
+        polymer.steps(5);
+        assert_eq!(
+            polymer.histogram(),
+            BTreeMap::from([('B', 1749), ('C', 298), ('H', 161), ('N', 865)])
+        );
+    }
+
+    #[test]
+    fn parse_error_points_at_the_rule() {
+        let err = parse_input("NNCB\n\nCH -> B\nHH => N\n").err().unwrap();
+        assert_eq!(err.line, 4);
+        assert!(parse_input("NNCB\n").is_err());
+    }
+}
@@ -0,0 +1,65 @@
+use anyhow::Result;
+use aoc_core::grid::Pos;
+use aoc_core::noise::Noise;
+use aoc_core::{search, Answer, Grid, ParseError, Solution};
+
+// each tile of the full map increments the risk levels by its distance to the
+// top left tile
+fn enlarged_grid(grid: &Grid<i32>, times: usize) -> Grid<i32> {
+    let (rows, cols) = (grid.rows(), grid.cols());
+    Grid::from_fn(times * rows, times * cols, |(row, col)| {
+        let increment = (row / rows + col / cols) as i32;
+        let risk = grid[(row % rows, col % cols)];
+        // shift range 1..9 to 0..8 to enable mod
+        let incremented = (risk - 1 + increment) % 9;
+        // shift back
+        incremented + 1
+    })
+}
+
+pub struct Chiton;
+
+impl Solution for Chiton {
+    type Input = Grid<i32>;
+
+    fn parse_input(input: &str) -> Result<Grid<i32>, ParseError> {
+        parse_buffer(input)
+    }
+
+    fn part_one(grid: Self::Input) -> Result<Answer> {
+        Ok(Answer::from(part_one(&grid)))
+    }
+
+    fn part_two(grid: Self::Input) -> Result<Answer> {
+        Ok(Answer::from(part_two(&grid)))
+    }
+
+    fn worst_cases() -> Vec<(&'static str, String)> {
+        // twice the side of real caves, four times the positions to search
+        let mut noise = Noise::new(15);
+        let mut large_cave = String::new();
+        for _ in 0..200 {
+            large_cave.extend((0..200).map(|_| char::from(b'0' + noise.range(1..=9) as u8)));
+            large_cave.push('\n');
+        }
+        vec![("large_cave", large_cave)]
+    }
+}
+
+fn parse_buffer(buffer: &str) -> Result<Grid<i32>, ParseError> {
+    Grid::parse(buffer, "a risk level", |c| c.to_digit(10).map(|d| d as i32))
+}
+
+fn part_one(grid: &Grid<i32>) -> i32 {
+    let target = (grid.rows() - 1, grid.cols() - 1);
+    // the start doesn't count towards the risk, every entered position does
+    let neighbors = |&pos: &Pos| grid.neighbors4(pos).map(|n| (n, grid[n]));
+    search::dijkstra([(0, 0)], neighbors, |&pos| pos == target)
+        .expect("the bottom right is always reachable")
+        .cost
+}
+
+fn part_two(grid: &Grid<i32>) -> i32 {
+    let enlarged_grid = enlarged_grid(grid, 5);
+    part_one(&enlarged_grid)
+}
@@ -1,21 +1,11 @@
+use anyhow::Result;
+use aoc_core::{Answer, ParseError, Solution};
+use log::trace;
 use std::collections::VecDeque;
-use std::io::{self, Read};
-
-fn main() {
-    let mut stdin = io::stdin();
-    let mut buffer = String::new();
-    stdin
-        .read_to_string(&mut buffer)
-        .expect("failed to read file");
-    let input = parse_buffer(&buffer);
-    println!("{:?}", input);
-    println!("Part 1: {}", part_one(&input));
-    println!("Part 2: {}", part_two(&input));
-}
 
 // there is probably a function for this but I couldn't find one fast enough so here is my thingy
-fn hex_to_binary(c: char) -> String {
-    String::from(match c {
+fn hex_to_binary(c: char) -> Option<&'static str> {
+    Some(match c {
         '0' => "0000",
         '1' => "0001",
         '2' => "0010",
@@ -32,38 +22,49 @@ fn hex_to_binary(c: char) -> String {
         'D' => "1101",
         'E' => "1110",
         'F' => "1111",
-        _ => "",
+        _ => return None,
     })
 }
 
 struct PacketReader<'a> {
     // returns the packet and the amount of bits it contained
     buffer: &'a str,
+    bits: usize,
+    // the transmission as hex digits within the whole input, for errors
+    input: &'a str,
+    hex: &'a str,
 }
 
 impl<'a> PacketReader<'a> {
-    fn new(buffer: &'a str) -> Self {
-        PacketReader { buffer }
+    fn new(buffer: &'a str, input: &'a str, hex: &'a str) -> Self {
+        PacketReader {
+            buffer,
+            bits: buffer.len(),
+            input,
+            hex,
+        }
     }
-    fn read_packet(&mut self) -> (usize, Packet) {
+    fn read_packet(&mut self) -> Result<(usize, Packet), ParseError> {
         let mut total_read = 0;
-        let version = u8::from_str_radix(self.read(3, &mut total_read), 2).unwrap();
-        let type_id = u8::from_str_radix(self.read(3, &mut total_read), 2).unwrap();
+        let version = u8::from_str_radix(self.read(3, &mut total_read)?, 2).unwrap();
+        let type_id = u8::from_str_radix(self.read(3, &mut total_read)?, 2).unwrap();
 
         if type_id == 4 {
             // literal packet
+            let literal_start = self.position();
             let mut binary_literal = String::new();
             loop {
-                let part = self.read(5, &mut total_read);
-                println!("{:?}", part);
+                let part = self.read(5, &mut total_read)?;
+                trace!("literal group {:?}", part);
                 let (leading, content) = part.split_at(1);
                 binary_literal.push_str(content);
                 if leading == "0" {
                     break;
                 }
             }
-            let literal = u128::from_str_radix(&binary_literal, 2).unwrap();
-            (
+            let literal = u128::from_str_radix(&binary_literal, 2)
+                .map_err(|_| self.error_at(literal_start, "a literal of at most 128 bits"))?;
+            Ok((
                 total_read,
                 Packet {
                     version,
@@ -71,28 +72,29 @@ impl<'a> PacketReader<'a> {
                     literal,
                     packets: Vec::new(),
                 },
-            )
+            ))
         } else {
             let mut packets = Vec::new();
-            let length_type_id = self.read(1, &mut total_read);
+            let length_type_id = self.read(1, &mut total_read)?;
             if length_type_id == "0" {
-                let length = usize::from_str_radix(self.read(15, &mut total_read), 2).unwrap();
+                let length = usize::from_str_radix(self.read(15, &mut total_read)?, 2).unwrap();
                 let mut sub_read = 0;
                 while sub_read < length {
-                    let (sub_read_inc, packet) = self.read_packet();
+                    let (sub_read_inc, packet) = self.read_packet()?;
                     sub_read += sub_read_inc;
                     packets.push(packet);
                 }
                 total_read += sub_read
             } else {
-                let num_packets = usize::from_str_radix(self.read(11, &mut total_read), 2).unwrap();
+                let num_packets =
+                    usize::from_str_radix(self.read(11, &mut total_read)?, 2).unwrap();
                 for _ in 0..num_packets {
-                    let (read_inc, packet) = self.read_packet();
+                    let (read_inc, packet) = self.read_packet()?;
                     total_read += read_inc;
                     packets.push(packet);
                 }
             }
-            (
+            Ok((
                 total_read,
                 Packet {
                     version,
@@ -100,20 +102,33 @@ impl<'a> PacketReader<'a> {
                     literal: 0,
                     packets,
                 },
-            )
+            ))
         }
     }
 
-    fn read(&mut self, amount: usize, total: &mut usize) -> &str {
+    fn read(&mut self, amount: usize, total: &mut usize) -> Result<&'a str, ParseError> {
+        if amount > self.buffer.len() {
+            return Err(self.error_at(self.bits, format!("{} more bits", amount)));
+        }
         let (read, rest) = self.buffer.split_at(amount);
         self.buffer = rest;
         *total += amount;
-        read
+        Ok(read)
+    }
+
+    fn position(&self) -> usize {
+        self.bits - self.buffer.len()
+    }
+
+    // points at the hex digit that holds the given bit
+    fn error_at(&self, bit: usize, expected: impl Into<String>) -> ParseError {
+        let found = self.hex.get(bit / 4..).unwrap_or("");
+        ParseError::at(self.input, found, expected)
     }
 }
 
 #[derive(Debug)]
-struct Packet {
+pub struct Packet {
     version: u8,
     type_id: u8,
     literal: u128,
@@ -185,20 +200,43 @@ impl Packet {
     }
 }
 
-fn parse_buffer(buffer: &str) -> Packet {
+pub struct PacketDecoder;
+
+impl Solution for PacketDecoder {
+    type Input = Packet;
+
+    fn parse_input(input: &str) -> Result<Packet, ParseError> {
+        parse_buffer(input)
+    }
+
+    fn part_one(packet: Self::Input) -> Result<Answer> {
+        Ok(Answer::from(part_one(&packet)))
+    }
+
+    fn part_two(packet: Self::Input) -> Result<Answer> {
+        Ok(Answer::from(i128::try_from(part_two(&packet))?))
+    }
+}
+
+fn parse_buffer(buffer: &str) -> Result<Packet, ParseError> {
+    let hex = buffer.trim_end();
+    if hex.is_empty() {
+        return Err(ParseError::missing(buffer, "a hexadecimal transmission"));
+    }
     // convert hex to binary
-    let binary: String = buffer
-        .chars()
-        .flat_map(|c| hex_to_binary(c).chars().collect::<Vec<_>>())
-        .collect();
-
-    let mut reader = PacketReader::new(&binary[..]);
-    let (_, top_level_packet) = reader.read_packet();
-    top_level_packet
+    let mut binary = String::new();
+    for (i, c) in hex.char_indices() {
+        let bits = hex_to_binary(c)
+            .ok_or_else(|| ParseError::at(buffer, &hex[i..], "a hexadecimal digit"))?;
+        binary.push_str(bits);
+    }
+
+    let mut reader = PacketReader::new(&binary[..], buffer, hex);
+    let (_, top_level_packet) = reader.read_packet()?;
+    Ok(top_level_packet)
 }
 
 fn part_one(top_level_packet: &Packet) -> u32 {
-    println!("{:?}", parse_buffer("D2FE28"));
     let mut version_sum: u32 = 0;
     let mut packet_queue: VecDeque<&Packet> = VecDeque::new();
     packet_queue.push_back(top_level_packet);
@@ -222,84 +260,94 @@ mod tests {
     #[test]
     fn operator_operator_operator_literal() {
         let packet = "8A004A801A8002F478";
-        let input = parse_buffer(packet);
+        let input = parse_buffer(packet).unwrap();
         assert_eq!(part_one(&input), 16);
     }
 
     #[test]
     fn operator_2operator_4literal_example_one() {
         let packet = "620080001611562C8802118E34";
-        let input = parse_buffer(packet);
+        let input = parse_buffer(packet).unwrap();
         assert_eq!(part_one(&input), 12);
     }
 
     #[test]
     fn operator_2operator_4literal_example_two() {
         let packet = "C0015000016115A2E0802F182340";
-        let input = parse_buffer(packet);
+        let input = parse_buffer(packet).unwrap();
         assert_eq!(part_one(&input), 23);
     }
 
     #[test]
     fn operator_operator_operator_5literal() {
         let packet = "A0016C880162017C3686B18A3D4780";
-        let input = parse_buffer(packet);
+        let input = parse_buffer(packet).unwrap();
         assert_eq!(part_one(&input), 31);
     }
 
     #[test]
     fn evaluate_sum() {
         let packet = "C200B40A82";
-        let input = parse_buffer(packet);
+        let input = parse_buffer(packet).unwrap();
         assert_eq!(part_two(&input), 3);
     }
 
     #[test]
     fn evaluate_product() {
         let packet = "04005AC33890";
-        let input = parse_buffer(packet);
+        let input = parse_buffer(packet).unwrap();
         assert_eq!(part_two(&input), 54);
     }
 
     #[test]
     fn evaluate_minimum() {
         let packet = "880086C3E88112";
-        let input = parse_buffer(packet);
+        let input = parse_buffer(packet).unwrap();
         assert_eq!(part_two(&input), 7);
     }
 
     #[test]
     fn evaluate_maximum() {
         let packet = "CE00C43D881120";
-        let input = parse_buffer(packet);
+        let input = parse_buffer(packet).unwrap();
         assert_eq!(part_two(&input), 9);
     }
 
     #[test]
     fn evaluate_less_than() {
         let packet = "D8005AC2A8F0";
-        let input = parse_buffer(packet);
+        let input = parse_buffer(packet).unwrap();
         assert_eq!(part_two(&input), 1);
     }
 
     #[test]
     fn evaluate_greater_than() {
         let packet = "F600BC2D8F";
-        let input = parse_buffer(packet);
+        let input = parse_buffer(packet).unwrap();
         assert_eq!(part_two(&input), 0);
     }
 
     #[test]
     fn evaluate_equal() {
         let packet = "9C005AC2F8F0";
-        let input = parse_buffer(packet);
+        let input = parse_buffer(packet).unwrap();
         assert_eq!(part_two(&input), 0);
     }
 
     #[test]
     fn evaluate_sum_equal_product() {
         let packet = "9C0141080250320F1802104A08";
-        let input = parse_buffer(packet);
+        let input = parse_buffer(packet).unwrap();
         assert_eq!(part_two(&input), 1);
     }
+
+    #[test]
+    fn invalid_and_truncated_transmissions() {
+        let err = parse_buffer("D2FG28").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 4));
+        assert_eq!(err.expected, "a hexadecimal digit");
+        // the literal of D2FE28 needs all of its last three groups
+        let err = parse_buffer("D2FE\n").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 5));
+    }
 }
@@ -0,0 +1,281 @@
+use anyhow::{bail, Result};
+use aoc_core::{input, parse, Answer, ParseError, Solution};
+use lazy_static::lazy_static;
+use log::debug;
+use regex::Regex;
+use std::collections::BTreeMap;
+
+// Every step moves the probe by its velocity, then drag takes one off the x
+// velocity until it is 0 and gravity one off the y velocity. Launched with
+// v, the probe is at the triangular number difference v*t - t*(t-1)/2 after
+// t steps, horizontally until it stops after |v| steps. So the steps in
+// which a vx is within the target come from binary searches over that
+// formula and the vy within it after a step from solving it for v, instead
+// of flying every velocity. The vx are counted together by the steps they
+// are within the target.
+
+/// The target area, both ranges inclusive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Target {
+    x: (i128, i128),
+    y: (i128, i128),
+}
+
+// the first and the last step a probe is within the target along an axis
+type Steps = (i128, i128);
+
+// where a probe launched up with `v` is after `t` steps
+fn height(v: i128, t: i128) -> i128 {
+    v * t - t * (t - 1) / 2
+}
+
+// the first t in lo..=hi at which the nondecreasing `f` reaches `c`, hi + 1
+// if it doesn't
+fn first_reaching(f: impl Fn(i128) -> i128, c: i128, mut lo: i128, hi: i128) -> i128 {
+    let mut end = hi + 1;
+    while lo < end {
+        let mid = lo + (end - lo) / 2;
+        if f(mid) >= c {
+            end = mid;
+        } else {
+            lo = mid + 1;
+        }
+    }
+    lo
+}
+
+/// The steps after which a probe launched with `vx` is within `a..=b`
+/// horizontally, up to `i128::MAX` if it stops there.
+fn x_steps(vx: i128, (a, b): (i128, i128)) -> Option<Steps> {
+    // to the left is to the right of the mirrored target
+    let (v, a, b) = if vx < 0 { (-vx, -b, -a) } else { (vx, a, b) };
+    let x = |t: i128| height(v, t.min(v));
+    let rest = x(v);
+    if rest < a {
+        return None;
+    }
+    let lo = first_reaching(x, a, 1, v);
+    let hi = if rest <= b {
+        i128::MAX
+    } else {
+        first_reaching(x, b + 1, 1, v) - 1
+    };
+    (lo <= hi).then_some((lo, hi))
+}
+
+// the vy that put a probe within `y1..=y2` after step `t`, solving the
+// formula for v
+fn vys_at(t: i128, (y1, y2): (i128, i128)) -> (i128, i128) {
+    let fallen = t * (t - 1) / 2;
+    (-(-(y1 + fallen)).div_euclid(t), (y2 + fallen).div_euclid(t))
+}
+
+impl Target {
+    pub fn new(x: (i128, i128), y: (i128, i128)) -> Self {
+        Target {
+            x: (x.0.min(x.1), x.0.max(x.1)),
+            y: (y.0.min(y.1), y.0.max(y.1)),
+        }
+    }
+
+    // the steps within the target horizontally with how many vx get there
+    // in them, up to the last step any probe can be within the target
+    fn windows(&self) -> Result<BTreeMap<Steps, usize>> {
+        let ((x1, x2), (y1, y2)) = (self.x, self.y);
+        // faster than the far edge passes the target in the first step
+        let xs: Vec<_> = (x1.min(0)..=x2.max(0))
+            .filter_map(|vx| x_steps(vx, self.x))
+            .collect();
+        let stops_inside = xs.iter().any(|&(_, hi)| hi == i128::MAX);
+        if stops_inside && y1 <= 0 && 0 <= y2 {
+            // every probe launched up falls through height 0 again
+            bail!("infinitely many velocities hit {:?}", self);
+        }
+        // slower downwards misses the target in the first step, faster
+        // upwards falls from 0 to below it in one step or, with a target
+        // around 0, only reaches 0 again after step 2 * vy + 1
+        let last_step = xs
+            .iter()
+            .map(|&(_, hi)| hi)
+            .filter(|&hi| hi != i128::MAX)
+            .max()
+            .unwrap_or(0);
+        let vy_max = y1.abs().max(y2.abs()).max(last_step / 2);
+        // the fastest probe is back at 0 after step 2 * vy_max + 1 and falls
+        // at least one further each step
+        let last_step = 2 * vy_max + y1.abs() + 2;
+        let mut windows = BTreeMap::new();
+        for (lo, hi) in xs {
+            *windows.entry((lo, hi.min(last_step))).or_insert(0) += 1;
+        }
+        Ok(windows)
+    }
+
+    // the vy that put a probe within the target in one of the steps `lo..=hi`,
+    // as disjoint ranges in ascending order
+    fn vys(&self, (lo, hi): Steps) -> Vec<(i128, i128)> {
+        let mut vys: Vec<_> = (lo..=hi)
+            .map(|t| vys_at(t, self.y))
+            .filter(|(a, b)| a <= b)
+            .collect();
+        vys.sort_unstable();
+        let mut merged: Vec<(i128, i128)> = Vec::new();
+        for (a, b) in vys {
+            match merged.last_mut() {
+                Some((_, end)) if a <= *end + 1 => *end = b.max(*end),
+                _ => merged.push((a, b)),
+            }
+        }
+        merged
+    }
+
+    /// How many initial velocities put the probe within the target after
+    /// some step.
+    pub fn hits(&self) -> Result<usize> {
+        let hits = self
+            .windows()?
+            .into_iter()
+            .map(|(steps, vxs)| {
+                let vys: i128 = self.vys(steps).iter().map(|(a, b)| b - a + 1).sum();
+                vxs * vys as usize
+            })
+            .sum();
+        debug!("{} velocities hit {:?}", hits, self);
+        Ok(hits)
+    }
+
+    /// The highest position on the trajectories that hit the target.
+    pub fn highest_apex(&self) -> Result<i128> {
+        let vy = self
+            .windows()?
+            .into_keys()
+            .filter_map(|steps| self.vys(steps).last().map(|&(_, b)| b))
+            .max();
+        match vy {
+            Some(vy) => Ok(height(vy, vy.max(0))),
+            None => bail!("no velocity hits {:?}", self),
+        }
+    }
+}
+
+fn parse_input(input: &str) -> Result<Target, ParseError> {
+    lazy_static! {
+        static ref RE: Regex =
+            Regex::new(r"^target area: x=(-?\d+)\.\.(-?\d+), y=(-?\d+)\.\.(-?\d+)$").unwrap();
+    }
+    let line = input::lines(input)
+        .next()
+        .ok_or_else(|| ParseError::missing(input, "the target area"))?;
+    let caps = parse::captures(&RE, input, line)?;
+    let value = |i: usize| parse::value(input, &caps[i], "a coordinate");
+    Ok(Target::new((value(1)?, value(2)?), (value(3)?, value(4)?)))
+}
+
+pub struct TrickShot;
+
+impl Solution for TrickShot {
+    type Input = Target;
+
+    fn parse_input(input: &str) -> Result<Target, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(target: Self::Input) -> Result<Answer> {
+        Ok(Answer::from(target.highest_apex()?))
+    }
+
+    fn part_two(target: Self::Input) -> Result<Answer> {
+        Ok(Answer::from(target.hits()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::differential::{assert_agree, proptest::prelude::*};
+
+    // flies every velocity that could hit targets within 30 of the origin
+    fn simulate(target: &Target) -> (usize, Option<i128>) {
+        let (mut hits, mut apex) = (0, None);
+        for vx in -40..=40i128 {
+            for vy in -40..=40 {
+                let (mut x, mut y, mut dx, mut dy, mut top) = (0, 0, vx, vy, 0);
+                for _ in 0..200 {
+                    x += dx;
+                    y += dy;
+                    dx -= dx.signum();
+                    dy -= 1;
+                    top = y.max(top);
+                    if (target.x.0..=target.x.1).contains(&x)
+                        && (target.y.0..=target.y.1).contains(&y)
+                    {
+                        // it may hit a target above on the way up
+                        while dy > 0 {
+                            y += dy;
+                            dy -= 1;
+                            top = y.max(top);
+                        }
+                        hits += 1;
+                        apex = apex.max(Some(top));
+                        break;
+                    }
+                }
+            }
+        }
+        (hits, apex)
+    }
+
+    // targets on either side, above or below the launcher but not around
+    // it, which infinitely many velocities would hit
+    fn targets() -> impl Strategy<Value = String> {
+        let x = (-20..=20i128, 0..8i128);
+        let y = (1..=20i128, 0..8i128, any::<bool>());
+        (x, y).prop_map(|((x1, width), (y1, height, below))| {
+            let (y1, y2) = if below {
+                (-y1 - height, -y1)
+            } else {
+                (y1, y1 + height)
+            };
+            format!("target area: x={}..{}, y={}..{}", x1, x1 + width, y1, y2)
+        })
+    }
+
+    #[test]
+    fn agrees_with_flying_every_velocity() {
+        assert_agree(
+            targets(),
+            |input| {
+                let target = parse_input(input).unwrap();
+                (target.hits().unwrap(), target.highest_apex().ok())
+            },
+            |input| simulate(&parse_input(input).unwrap()),
+        );
+    }
+
+    #[test]
+    fn targets_around_the_launcher() {
+        // a probe that stops in the target and falls through it again
+        let stops_above = parse_input("target area: x=10..15, y=-2..2").unwrap();
+        assert!(stops_above.hits().is_err());
+        let straight_up = parse_input("target area: x=-5..5, y=-3..3").unwrap();
+        assert!(straight_up.hits().is_err());
+        // gone past the target before it falls back through height 0
+        let passes = parse_input("target area: x=12..14, y=-1..1").unwrap();
+        let (hits, apex) = simulate(&passes);
+        assert_eq!(passes.hits().unwrap(), hits);
+        assert_eq!(passes.highest_apex().ok(), apex);
+    }
+
+    #[test]
+    fn deep_targets() {
+        // a probe stopped above the target by then falls onto its bottom edge
+        let deep = parse_input("target area: x=20..30, y=-10000..-9990").unwrap();
+        assert_eq!(deep.highest_apex().unwrap(), 9999 * 10000 / 2);
+        // every probe that stops does so too early, the fastest launch
+        // passes the target first
+        let passed = parse_input("target area: x=11..14, y=-30..-20").unwrap();
+        let (hits, apex) = simulate(&passed);
+        assert_eq!(passed.hits().unwrap(), hits);
+        assert_eq!(passed.highest_apex().ok(), apex);
+    }
+}
@@ -0,0 +1,401 @@
+use anyhow::{Context, Result};
+use aoc_core::{input, parse, Answer, ParseError, Solution};
+use log::trace;
+use std::fmt;
+use std::ops::Add;
+
+/// A regular number or a pair of snailfish numbers, written like
+/// `[[1,2],3]`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SnailfishNumber {
+    Regular(u32),
+    Pair(Box<SnailfishNumber>, Box<SnailfishNumber>),
+}
+
+use SnailfishNumber::{Pair, Regular};
+
+impl SnailfishNumber {
+    fn pair(left: SnailfishNumber, right: SnailfishNumber) -> Self {
+        Pair(Box::new(left), Box::new(right))
+    }
+
+    pub fn parse(input: &str, line: &str) -> Result<Self, ParseError> {
+        // only ASCII is valid, so everything up to pos is a char boundary
+        let bytes = line.as_bytes();
+        let mut pos = 0;
+        // the pairs that are still open with the numbers they have so far
+        let mut stack: Vec<Vec<SnailfishNumber>> = Vec::new();
+        let finish = |number: SnailfishNumber, pos: usize| {
+            if pos < bytes.len() {
+                Err(ParseError::at(input, &line[pos..], "the end of the number"))
+            } else {
+                Ok(number)
+            }
+        };
+        while pos < bytes.len() {
+            if matches!(bytes[pos], b'[' | b'0'..=b'9') {
+                // the second of a pair comes after a comma, there is no third
+                let expected = match stack.last().map(Vec::len) {
+                    Some(1) if bytes[pos - 1] != b',' => Some("a comma"),
+                    Some(2) => Some("]"),
+                    _ => None,
+                };
+                if let Some(expected) = expected {
+                    return Err(ParseError::at(input, &line[pos..], expected));
+                }
+            }
+            let number = match bytes[pos] {
+                b'[' => {
+                    stack.push(Vec::new());
+                    pos += 1;
+                    continue;
+                }
+                b',' if stack.last().is_some_and(|pair| pair.len() == 1)
+                    && bytes[pos - 1] != b',' =>
+                {
+                    pos += 1;
+                    continue;
+                }
+                b'0'..=b'9' => {
+                    let len = bytes[pos..]
+                        .iter()
+                        .take_while(|b| b.is_ascii_digit())
+                        .count();
+                    let value = parse::value(input, &line[pos..pos + len], "a regular number")?;
+                    pos += len;
+                    Regular(value)
+                }
+                b']' => match stack.pop().as_deref_mut() {
+                    Some([left, right]) => {
+                        let left = std::mem::replace(left, Regular(0));
+                        let right = std::mem::replace(right, Regular(0));
+                        pos += 1;
+                        SnailfishNumber::pair(left, right)
+                    }
+                    _ => return Err(ParseError::at(input, &line[pos..], "a pair of two")),
+                },
+                _ => {
+                    return Err(ParseError::at(
+                        input,
+                        &line[pos..],
+                        "a bracket, a comma or a regular number",
+                    ))
+                }
+            };
+            match stack.last_mut() {
+                Some(pair) => pair.push(number),
+                None => return finish(number, pos),
+            }
+        }
+        Err(ParseError::at(input, &line[pos..], "]"))
+    }
+
+    pub fn magnitude(&self) -> u64 {
+        match self {
+            Regular(value) => u64::from(*value),
+            Pair(left, right) => 3 * left.magnitude() + 2 * right.magnitude(),
+        }
+    }
+
+    fn add_leftmost(&mut self, value: u32) {
+        match self {
+            Regular(regular) => *regular += value,
+            Pair(left, _) => left.add_leftmost(value),
+        }
+    }
+
+    fn add_rightmost(&mut self, value: u32) {
+        match self {
+            Regular(regular) => *regular += value,
+            Pair(_, right) => right.add_rightmost(value),
+        }
+    }
+
+    // replaces the leftmost pair of regular numbers nested `depth` or more
+    // pairs deep with 0, returning its numbers that are still to be added
+    // to the regular numbers left and right of it
+    fn explode_below(&mut self, depth: usize) -> Option<(u32, u32)> {
+        let Pair(left, right) = self else {
+            return None;
+        };
+        if depth == 0 {
+            if let (Regular(l), Regular(r)) = (left.as_ref(), right.as_ref()) {
+                let exploded = (*l, *r);
+                *self = Regular(0);
+                return Some(exploded);
+            }
+        }
+        let depth = depth.saturating_sub(1);
+        if let Some((l, r)) = left.explode_below(depth) {
+            right.add_leftmost(r);
+            return Some((l, 0));
+        }
+        if let Some((l, r)) = right.explode_below(depth) {
+            left.add_rightmost(l);
+            return Some((0, r));
+        }
+        None
+    }
+
+    /// Explodes the leftmost pair nested inside four pairs, false if there
+    /// is none.
+    pub fn explode(&mut self) -> bool {
+        self.explode_below(4).is_some()
+    }
+
+    /// Splits the leftmost regular number of 10 or more, false if there is
+    /// none.
+    pub fn split(&mut self) -> bool {
+        match self {
+            Regular(value) if *value >= 10 => {
+                let half = *value / 2;
+                *self = SnailfishNumber::pair(Regular(half), Regular(*value - half));
+                true
+            }
+            Regular(_) => false,
+            Pair(left, right) => left.split() || right.split(),
+        }
+    }
+
+    /// Explodes and splits until neither is possible, exploding first.
+    pub fn reduce(&mut self) {
+        loop {
+            trace!("{}", self);
+            if !self.explode() && !self.split() {
+                return;
+            }
+        }
+    }
+}
+
+impl fmt::Display for SnailfishNumber {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Regular(value) => write!(f, "{}", value),
+            Pair(left, right) => write!(f, "[{},{}]", left, right),
+        }
+    }
+}
+
+/// The reduced pair of both.
+impl Add for SnailfishNumber {
+    type Output = SnailfishNumber;
+
+    fn add(self, other: SnailfishNumber) -> SnailfishNumber {
+        let mut sum = SnailfishNumber::pair(self, other);
+        sum.reduce();
+        sum
+    }
+}
+
+/// The sum of the numbers in order, none if there are none.
+pub fn sum(numbers: &[SnailfishNumber]) -> Option<SnailfishNumber> {
+    numbers.iter().cloned().reduce(Add::add)
+}
+
+/// The largest magnitude of the sum of two different numbers, in either
+/// order since snailfish addition doesn't commute.
+pub fn largest_pair_magnitude(numbers: &[SnailfishNumber]) -> Option<u64> {
+    let mut largest = None;
+    for (i, a) in numbers.iter().enumerate() {
+        for (j, b) in numbers.iter().enumerate() {
+            if i != j {
+                let magnitude = (a.clone() + b.clone()).magnitude();
+                largest = largest.max(Some(magnitude));
+            }
+        }
+    }
+    largest
+}
+
+fn parse_input(input: &str) -> Result<Vec<SnailfishNumber>, ParseError> {
+    input::lines(input)
+        .map(|line| SnailfishNumber::parse(input, line))
+        .collect()
+}
+
+pub struct Snailfish;
+
+impl Solution for Snailfish {
+    type Input = Vec<SnailfishNumber>;
+
+    fn parse_input(input: &str) -> Result<Vec<SnailfishNumber>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(numbers: Self::Input) -> Result<Answer> {
+        let sum = sum(&numbers).context("there are no numbers to add")?;
+        Ok(Answer::from(sum.magnitude()))
+    }
+
+    fn part_two(numbers: Self::Input) -> Result<Answer> {
+        let largest = largest_pair_magnitude(&numbers).context("there are no two numbers")?;
+        Ok(Answer::from(largest))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use lazy_static::lazy_static;
+    use std::fs;
+
+    lazy_static! {
+        static ref TEST: String = read_from_file("Day18_Snailfish_Rust/test.txt");
+    }
+
+    fn read_from_file(filename: &str) -> String {
+        fs::read_to_string(filename)
+            .unwrap_or_else(|msg| panic!("error reading {}: {}", filename, msg))
+    }
+
+    fn number(line: &str) -> SnailfishNumber {
+        SnailfishNumber::parse(line, line).unwrap()
+    }
+
+    fn numbers(lines: &[&str]) -> Vec<SnailfishNumber> {
+        lines.iter().map(|line| number(line)).collect()
+    }
+
+    #[test]
+    fn display_round_trips() {
+        for line in input::lines(&TEST).chain(["[[[[0,7],4],[15,[0,13]]],[1,1]]"]) {
+            assert_eq!(number(line).to_string(), line);
+        }
+    }
+
+    #[test]
+    fn parse_errors() {
+        for line in [
+            "[1,2", "[1,2]]", "[1,2,3]", "[1]", "[[1,2]3]", "[1,,2]", "[1,a]",
+        ] {
+            assert!(SnailfishNumber::parse(line, line).is_err(), "{}", line);
+        }
+    }
+
+    #[test]
+    fn single_explodes() {
+        for (before, after) in [
+            ("[[[[[9,8],1],2],3],4]", "[[[[0,9],2],3],4]"),
+            ("[7,[6,[5,[4,[3,2]]]]]", "[7,[6,[5,[7,0]]]]"),
+            ("[[6,[5,[4,[3,2]]]],1]", "[[6,[5,[7,0]]],3]"),
+            (
+                "[[3,[2,[1,[7,3]]]],[6,[5,[4,[3,2]]]]]",
+                "[[3,[2,[8,0]]],[9,[5,[4,[3,2]]]]]",
+            ),
+            (
+                "[[3,[2,[8,0]]],[9,[5,[4,[3,2]]]]]",
+                "[[3,[2,[8,0]]],[9,[5,[7,0]]]]",
+            ),
+        ] {
+            let mut number = number(before);
+            assert!(number.explode());
+            assert_eq!(number.to_string(), after);
+        }
+    }
+
+    #[test]
+    fn reduction_explodes_before_it_splits() {
+        let mut sum =
+            SnailfishNumber::pair(number("[[[[4,3],4],4],[7,[[8,4],9]]]"), number("[1,1]"));
+        assert_eq!(sum.to_string(), "[[[[[4,3],4],4],[7,[[8,4],9]]],[1,1]]");
+        assert!(sum.explode());
+        assert_eq!(sum.to_string(), "[[[[0,7],4],[7,[[8,4],9]]],[1,1]]");
+        assert!(sum.explode());
+        assert_eq!(sum.to_string(), "[[[[0,7],4],[15,[0,13]]],[1,1]]");
+        assert!(!sum.explode());
+        assert!(sum.split());
+        assert_eq!(sum.to_string(), "[[[[0,7],4],[[7,8],[0,13]]],[1,1]]");
+        assert!(sum.split());
+        assert_eq!(sum.to_string(), "[[[[0,7],4],[[7,8],[0,[6,7]]]],[1,1]]");
+        assert!(sum.explode());
+        assert_eq!(sum.to_string(), "[[[[0,7],4],[[7,8],[6,0]]],[8,1]]");
+        assert!(!sum.explode() && !sum.split());
+
+        let added = number("[[[[4,3],4],4],[7,[[8,4],9]]]") + number("[1,1]");
+        assert_eq!(added, sum);
+    }
+
+    #[test]
+    fn sums_of_lists() {
+        let mut list = numbers(&["[1,1]", "[2,2]", "[3,3]", "[4,4]"]);
+        assert_eq!(
+            sum(&list).unwrap().to_string(),
+            "[[[[1,1],[2,2]],[3,3]],[4,4]]"
+        );
+        list.push(number("[5,5]"));
+        assert_eq!(
+            sum(&list).unwrap().to_string(),
+            "[[[[3,0],[5,3]],[4,4]],[5,5]]"
+        );
+        list.push(number("[6,6]"));
+        assert_eq!(
+            sum(&list).unwrap().to_string(),
+            "[[[[5,0],[7,4]],[5,5]],[6,6]]"
+        );
+        assert_eq!(sum(&[]), None);
+    }
+
+    #[test]
+    fn slightly_larger_sum_step_by_step() {
+        let list = numbers(&[
+            "[[[0,[4,5]],[0,0]],[[[4,5],[2,6]],[9,5]]]",
+            "[7,[[[3,7],[4,3]],[[6,3],[8,8]]]]",
+            "[[2,[[0,8],[3,4]]],[[[6,7],1],[7,[1,6]]]]",
+            "[[[[2,4],7],[6,[0,5]]],[[[6,8],[2,8]],[[2,1],[4,5]]]]",
+            "[7,[5,[[3,8],[1,4]]]]",
+            "[[2,[2,2]],[8,[8,1]]]",
+            "[2,9]",
+            "[1,[[[9,3],9],[[9,0],[0,7]]]]",
+            "[[[5,[7,4]],7],1]",
+            "[[[[4,2],2],6],[8,7]]",
+        ]);
+        let sums = [
+            "[[[[4,0],[5,4]],[[7,7],[6,0]]],[[8,[7,7]],[[7,9],[5,0]]]]",
+            "[[[[6,7],[6,7]],[[7,7],[0,7]]],[[[8,7],[7,7]],[[8,8],[8,0]]]]",
+            "[[[[7,0],[7,7]],[[7,7],[7,8]]],[[[7,7],[8,8]],[[7,7],[8,7]]]]",
+            "[[[[7,7],[7,8]],[[9,5],[8,7]]],[[[6,8],[0,8]],[[9,9],[9,0]]]]",
+            "[[[[6,6],[6,6]],[[6,0],[6,7]]],[[[7,7],[8,9]],[8,[8,1]]]]",
+            "[[[[6,6],[7,7]],[[0,7],[7,7]]],[[[5,5],[5,6]],9]]",
+            "[[[[7,8],[6,7]],[[6,8],[0,8]]],[[[7,7],[5,0]],[[5,5],[5,6]]]]",
+            "[[[[7,7],[7,7]],[[8,7],[8,7]]],[[[7,0],[7,7]],9]]",
+            "[[[[8,7],[7,7]],[[8,6],[7,7]]],[[[0,7],[6,6]],[8,7]]]",
+        ];
+        for (n, expected) in sums.iter().enumerate() {
+            assert_eq!(&sum(&list[..n + 2]).unwrap().to_string(), expected);
+        }
+    }
+
+    #[test]
+    fn magnitudes() {
+        for (line, magnitude) in [
+            ("[[1,2],[[3,4],5]]", 143),
+            ("[[[[0,7],4],[[7,8],[6,0]]],[8,1]]", 1384),
+            ("[[[[1,1],[2,2]],[3,3]],[4,4]]", 445),
+            ("[[[[3,0],[5,3]],[4,4]],[5,5]]", 791),
+            ("[[[[5,0],[7,4]],[5,5]],[6,6]]", 1137),
+            (
+                "[[[[8,7],[7,7]],[[8,6],[7,7]]],[[[0,7],[6,6]],[8,7]]]",
+                3488,
+            ),
+        ] {
+            assert_eq!(number(line).magnitude(), magnitude);
+        }
+    }
+
+    #[test]
+    fn homework() {
+        let list = parse_input(&TEST).unwrap();
+        assert_eq!(
+            sum(&list).unwrap().to_string(),
+            "[[[[6,6],[7,6]],[[7,7],[7,0]]],[[[7,7],[7,7]],[[7,8],[9,9]]]]"
+        );
+        let best = list[8].clone() + list[0].clone();
+        assert_eq!(
+            best.to_string(),
+            "[[[[7,8],[6,6]],[[6,0],[7,7]]],[[[7,8],[8,8]],[[7,9],[0,6]]]]"
+        );
+        assert_eq!(largest_pair_magnitude(&list), Some(best.magnitude()));
+        assert_eq!(largest_pair_magnitude(&list[..1]), None);
+    }
+}
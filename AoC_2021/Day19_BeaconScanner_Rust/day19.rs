@@ -0,0 +1,450 @@
+use anyhow::{bail, Result};
+use aoc_core::noise::Noise;
+use aoc_core::{input, parse, Answer, ParseError, Solution, Vec3};
+use lazy_static::lazy_static;
+use log::debug;
+use regex::Regex;
+use std::collections::{BTreeSet, HashMap, HashSet, VecDeque};
+
+/// One of the 24 ways a scanner can be turned, as the matrix that turns
+/// what it reports into the coordinates of scanner 0.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Rotation {
+    rows: [[i128; 3]; 3],
+}
+
+impl Rotation {
+    pub const IDENTITY: Rotation = Rotation {
+        rows: [[1, 0, 0], [0, 1, 0], [0, 0, 1]],
+    };
+
+    fn determinant(&self) -> i128 {
+        let m = &self.rows;
+        m[0][0] * (m[1][1] * m[2][2] - m[1][2] * m[2][1])
+            - m[0][1] * (m[1][0] * m[2][2] - m[1][2] * m[2][0])
+            + m[0][2] * (m[1][0] * m[2][1] - m[1][1] * m[2][0])
+    }
+
+    /// All of them, the identity first.
+    pub fn all() -> Vec<Rotation> {
+        let mut all = Vec::with_capacity(24);
+        for axes in [
+            [0, 1, 2],
+            [0, 2, 1],
+            [1, 0, 2],
+            [1, 2, 0],
+            [2, 0, 1],
+            [2, 1, 0],
+        ] {
+            for signs in 0..8 {
+                let mut rows = [[0; 3]; 3];
+                for (row, &axis) in axes.iter().enumerate() {
+                    rows[row][axis] = if signs >> row & 1 == 1 { -1 } else { 1 };
+                }
+                let rotation = Rotation { rows };
+                // the other half mirrors
+                if rotation.determinant() == 1 {
+                    all.push(rotation);
+                }
+            }
+        }
+        all
+    }
+
+    /// The rows of the matrix, a signed unit vector each.
+    pub fn rows(&self) -> [[i128; 3]; 3] {
+        self.rows
+    }
+
+    pub fn apply(&self, v: Vec3) -> Vec3 {
+        let row = |r: [i128; 3]| r[0] * v.x + r[1] * v.y + r[2] * v.z;
+        Vec3::new(row(self.rows[0]), row(self.rows[1]), row(self.rows[2]))
+    }
+
+    /// Turns by `inner` first and then by this one.
+    pub fn after(&self, inner: &Rotation) -> Rotation {
+        let mut rows = [[0; 3]; 3];
+        for (r, row) in rows.iter_mut().enumerate() {
+            for (c, cell) in row.iter_mut().enumerate() {
+                *cell = (0..3).map(|k| self.rows[r][k] * inner.rows[k][c]).sum();
+            }
+        }
+        Rotation { rows }
+    }
+}
+
+lazy_static! {
+    static ref ROTATIONS: Vec<Rotation> = Rotation::all();
+}
+
+// the sorted absolute differences of the coordinates of two beacons, the
+// same no matter how the scanner is turned
+type Fingerprint = [i128; 3];
+
+fn fingerprint(a: Vec3, b: Vec3) -> Fingerprint {
+    let d = b - a;
+    let mut fingerprint = [d.x.abs(), d.y.abs(), d.z.abs()];
+    fingerprint.sort_unstable();
+    fingerprint
+}
+
+#[derive(Debug, Clone)]
+pub struct Scanner {
+    beacons: Vec<Vec3>,
+    // every pair of beacons by their fingerprint
+    fingerprints: HashMap<Fingerprint, Vec<(usize, usize)>>,
+}
+
+impl Scanner {
+    fn new(beacons: Vec<Vec3>) -> Self {
+        let mut fingerprints: HashMap<_, Vec<_>> = HashMap::new();
+        for i in 0..beacons.len() {
+            for j in i + 1..beacons.len() {
+                fingerprints
+                    .entry(fingerprint(beacons[i], beacons[j]))
+                    .or_default()
+                    .push((i, j));
+            }
+        }
+        Scanner {
+            beacons,
+            fingerprints,
+        }
+    }
+
+    /// The beacons as the scanner reports them.
+    pub fn beacons(&self) -> &[Vec3] {
+        &self.beacons
+    }
+
+    // how to turn and move what `other` reports to get what this one
+    // reports of the same beacons, if at least `overlap` of them are the
+    // same. Only the pairs of beacons with the same fingerprint in both can
+    // be the same pairs, the turn is the one that fits such a pair and the
+    // move is the one that puts them onto each other.
+    fn align(&self, other: &Scanner, overlap: usize) -> Option<(Rotation, Vec3)> {
+        let shared: Vec<_> = self
+            .fingerprints
+            .iter()
+            .filter_map(|(fingerprint, pairs)| Some((pairs, other.fingerprints.get(fingerprint)?)))
+            .collect();
+        let shared_pairs: usize = shared.iter().map(|(a, b)| a.len().min(b.len())).sum();
+        if shared_pairs < overlap * overlap.saturating_sub(1) / 2 {
+            return None;
+        }
+        let known: HashSet<Vec3> = self.beacons.iter().copied().collect();
+        for (pairs, other_pairs) in shared {
+            for &(a1, a2) in pairs {
+                let da = self.beacons[a2] - self.beacons[a1];
+                for &(b1, b2) in other_pairs {
+                    for (b1, b2) in [(b1, b2), (b2, b1)] {
+                        let db = other.beacons[b2] - other.beacons[b1];
+                        for rotation in ROTATIONS.iter().filter(|r| r.apply(db) == da) {
+                            let offset = self.beacons[a1] - rotation.apply(other.beacons[b1]);
+                            let same = other
+                                .beacons
+                                .iter()
+                                .filter(|&&b| known.contains(&(rotation.apply(b) + offset)))
+                                .count();
+                            if same >= overlap {
+                                return Some((*rotation, offset));
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        None
+    }
+}
+
+/// Where a scanner is and how it is turned, in the coordinates of scanner
+/// 0.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Placement {
+    pub position: Vec3,
+    pub rotation: Rotation,
+}
+
+impl Placement {
+    /// Where a beacon the scanner reports at `v` is.
+    pub fn place(&self, v: Vec3) -> Vec3 {
+        self.rotation.apply(v) + self.position
+    }
+}
+
+/// The scanners placed around scanner 0 with every beacon any of them
+/// reports.
+#[derive(Debug, Clone)]
+pub struct Map {
+    placements: Vec<Placement>,
+    beacons: BTreeSet<Vec3>,
+}
+
+impl Map {
+    /// Places every scanner by way of scanners already placed that see at
+    /// least `overlap` of the same beacons.
+    pub fn align(scanners: &[Scanner], overlap: usize) -> Result<Map> {
+        let mut placements = vec![None; scanners.len()];
+        let mut queue = VecDeque::new();
+        if !scanners.is_empty() {
+            placements[0] = Some(Placement {
+                position: Vec3::default(),
+                rotation: Rotation::IDENTITY,
+            });
+            queue.push_back(0);
+        }
+        while let Some(placed) = queue.pop_front() {
+            let outer = placements[placed].unwrap();
+            for other in 0..scanners.len() {
+                if placements[other].is_some() {
+                    continue;
+                }
+                let Some((rotation, offset)) = scanners[placed].align(&scanners[other], overlap)
+                else {
+                    continue;
+                };
+                debug!("scanner {} aligns with scanner {}", other, placed);
+                placements[other] = Some(Placement {
+                    position: outer.place(offset),
+                    rotation: outer.rotation.after(&rotation),
+                });
+                queue.push_back(other);
+            }
+        }
+        let placements: Vec<Placement> = match placements.iter().position(Option::is_none) {
+            Some(lost) => bail!(
+                "scanner {} doesn't see {} of the same beacons as the others",
+                lost,
+                overlap
+            ),
+            None => placements.into_iter().flatten().collect(),
+        };
+        let beacons = scanners
+            .iter()
+            .zip(placements.iter())
+            .flat_map(|(scanner, placement)| scanner.beacons.iter().map(|&b| placement.place(b)))
+            .collect();
+        Ok(Map {
+            placements,
+            beacons,
+        })
+    }
+
+    /// Where each scanner is and how it is turned, by its number.
+    pub fn placements(&self) -> &[Placement] {
+        &self.placements
+    }
+
+    pub fn beacons(&self) -> &BTreeSet<Vec3> {
+        &self.beacons
+    }
+
+    pub fn largest_scanner_distance(&self) -> i128 {
+        let positions = self.placements.iter().map(|p| p.position);
+        positions
+            .clone()
+            .flat_map(|a| positions.clone().map(move |b| a.manhattan(&b)))
+            .max()
+            .unwrap_or(0)
+    }
+}
+
+fn parse_input(input: &str) -> Result<Vec<Scanner>, ParseError> {
+    lazy_static! {
+        static ref HEADER: Regex = Regex::new(r"^--- scanner (\d+) ---$").unwrap();
+        static ref BEACON: Regex = Regex::new(r"^(-?\d+),(-?\d+),(-?\d+)$").unwrap();
+    }
+    let mut scanners = Vec::new();
+    for block in input::blocks(input) {
+        let mut lines = input::lines(block);
+        let header = parse::next(&mut lines, input, "a scanner")?;
+        let caps = parse::captures(&HEADER, input, header)?;
+        let number: usize = parse::value(input, &caps[1], "a scanner number")?;
+        if number != scanners.len() {
+            let expected = format!("scanner {}", scanners.len());
+            return Err(ParseError::at(input, &caps[1], expected));
+        }
+        let mut beacons = Vec::new();
+        for line in lines {
+            let caps = parse::captures(&BEACON, input, line)?;
+            let coordinate = |i: usize| parse::value(input, &caps[i], "a coordinate");
+            beacons.push(Vec3::new(coordinate(1)?, coordinate(2)?, coordinate(3)?));
+        }
+        scanners.push(Scanner::new(beacons));
+    }
+    Ok(scanners)
+}
+
+aoc_core::params! {
+    pub struct Params {
+        overlap: usize = 12,
+    }
+}
+
+pub struct BeaconScanner;
+
+impl Solution for BeaconScanner {
+    type Input = Vec<Scanner>;
+
+    fn parse_input(input: &str) -> Result<Vec<Scanner>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(scanners: Self::Input) -> Result<Answer> {
+        let map = Map::align(&scanners, Params::get()?.overlap)?;
+        Ok(Answer::from(map.beacons().len()))
+    }
+
+    fn part_two(scanners: Self::Input) -> Result<Answer> {
+        let map = Map::align(&scanners, Params::get()?.overlap)?;
+        Ok(Answer::from(map.largest_scanner_distance()))
+    }
+
+    fn params() -> Vec<(&'static str, String)> {
+        Params::defaults()
+    }
+
+    fn worst_cases() -> Vec<(&'static str, String)> {
+        // a row of turned scanners that each only overlap their neighbours,
+        // so most pairs have to be tried before the last one is placed
+        let mut noise = Noise::new(19);
+        let count = 40;
+        let positions: Vec<_> = (0..count)
+            .map(|i| Vec3::new(1100 * i, noise.range(-100..=100), noise.range(-100..=100)))
+            .collect();
+        let mut beacons = Vec::new();
+        for (i, p) in positions.iter().enumerate() {
+            // only this scanner sees the beacons close to it
+            for _ in 0..12 {
+                let offset = |noise: &mut Noise| noise.range(-90..=90);
+                beacons.push(
+                    *p + Vec3::new(offset(&mut noise), offset(&mut noise), offset(&mut noise)),
+                );
+            }
+            let Some(q) = positions.get(i + 1) else {
+                continue;
+            };
+            for _ in 0..14 {
+                let shared = |noise: &mut Noise, a: i128, b: i128| {
+                    noise.range(a.max(b) - 1000..=a.min(b) + 1000)
+                };
+                beacons.push(Vec3::new(
+                    shared(&mut noise, p.x, q.x),
+                    shared(&mut noise, p.y, q.y),
+                    shared(&mut noise, p.z, q.z),
+                ));
+            }
+        }
+        let mut scanner_row = String::new();
+        for (i, &p) in positions.iter().enumerate() {
+            let rotation = if i == 0 {
+                Rotation::IDENTITY
+            } else {
+                *noise.pick(&ROTATIONS)
+            };
+            scanner_row.push_str(&format!("--- scanner {} ---\n", i));
+            for &b in beacons.iter() {
+                let d = b - p;
+                if d.x.abs().max(d.y.abs()).max(d.z.abs()) <= 1000 {
+                    let seen = rotation.apply(d);
+                    scanner_row.push_str(&format!("{},{},{}\n", seen.x, seen.y, seen.z));
+                }
+            }
+            scanner_row.push('\n');
+        }
+        vec![("scanner_row", scanner_row)]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use lazy_static::lazy_static;
+    use std::fs;
+
+    lazy_static! {
+        static ref TEST: String = read_from_file("Day19_BeaconScanner_Rust/test.txt");
+        static ref SYNTHETIC: String = read_from_file("Day19_BeaconScanner_Rust/synthetic.txt");
+    }
+
+    fn read_from_file(filename: &str) -> String {
+        fs::read_to_string(filename)
+            .unwrap_or_else(|msg| panic!("error reading {}: {}", filename, msg))
+    }
+
+    #[test]
+    fn twenty_four_rotations() {
+        let all = Rotation::all();
+        assert_eq!(all.len(), 24);
+        assert_eq!(all[0], Rotation::IDENTITY);
+        assert_eq!(all.iter().collect::<HashSet<_>>().len(), 24);
+        // every one turns x onto one of six axes with four turns about it
+        let v = Vec3::new(1, 2, 3);
+        let turned: HashSet<_> = all.iter().map(|r| r.apply(v)).collect();
+        assert_eq!(turned.len(), 24);
+        for a in all.iter() {
+            for b in all.iter() {
+                assert!(all.contains(&a.after(b)));
+                assert_eq!(a.after(b).apply(v), a.apply(b.apply(v)));
+            }
+        }
+    }
+
+    #[test]
+    fn example() {
+        let map = Map::align(&parse_input(&TEST).unwrap(), 12).unwrap();
+        let positions: Vec<_> = map.placements().iter().map(|p| p.position).collect();
+        assert_eq!(
+            positions,
+            [
+                Vec3::new(0, 0, 0),
+                Vec3::new(68, -1246, -43),
+                Vec3::new(1105, -1205, 1229),
+                Vec3::new(-92, -2380, -20),
+                Vec3::new(-20, -1133, 1061),
+            ]
+        );
+        assert_eq!(map.beacons().len(), 79);
+        assert_eq!(map.largest_scanner_distance(), 3621);
+    }
+
+    #[test]
+    fn recovers_the_placements() {
+        // made up with known positions and turns, unlike the example
+        let scanners = parse_input(&SYNTHETIC).unwrap();
+        let map = Map::align(&scanners, 12).unwrap();
+        let positions: Vec<_> = map.placements().iter().map(|p| p.position).collect();
+        assert_eq!(
+            positions,
+            [
+                Vec3::new(0, 0, 0),
+                Vec3::new(1105, -68, 42),
+                Vec3::new(2190, 95, -120),
+                Vec3::new(1160, 1210, 75),
+                Vec3::new(-40, -1130, -95),
+            ]
+        );
+        let rows: Vec<_> = map.placements().iter().map(|p| p.rotation.rows()).collect();
+        assert_eq!(rows[1], [[0, 1, 0], [1, 0, 0], [0, 0, -1]]);
+        assert_eq!(rows[4], [[0, 1, 0], [-1, 0, 0], [0, 0, 1]]);
+        assert_eq!(map.beacons().len(), 148);
+        assert_eq!(map.largest_scanner_distance(), 3710);
+
+        // scanners 2 and 3 only see 6 of the same beacons
+        let err = Map::align(&[scanners[2].clone(), scanners[3].clone()], 12).unwrap_err();
+        assert!(err.to_string().starts_with("scanner 1 doesn't see"));
+        assert!(Map::align(&scanners[2..4], 6).is_ok());
+    }
+
+    #[test]
+    fn places_the_whole_scanner_row() {
+        let [(_, scanner_row)] = &BeaconScanner::worst_cases()[..] else {
+            panic!("one worst case");
+        };
+        let map = Map::align(&parse_input(scanner_row).unwrap(), 12).unwrap();
+        let xs: Vec<_> = map.placements().iter().map(|p| p.position.x).collect();
+        assert_eq!(xs, (0..40).map(|i| 1100 * i).collect::<Vec<_>>());
+        assert_eq!(map.beacons().len(), 40 * 12 + 39 * 14);
+    }
+}
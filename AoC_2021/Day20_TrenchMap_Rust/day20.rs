@@ -0,0 +1,156 @@
+use anyhow::Result;
+use aoc_core::parse::{self, Within};
+use aoc_core::{input, record, Answer, Grid, ParseError, Solution};
+use log::{debug, trace};
+
+#[derive(Debug)]
+pub struct EnhanceableGrid {
+    enhanced_mapping: Vec<char>,
+    content: Grid<char>,
+    amount_enhanced: i32,
+    is_swapping: bool,
+}
+
+impl EnhanceableGrid {
+    fn parse(buffer: &str) -> Result<EnhanceableGrid, ParseError> {
+        let mut splitted = input::blocks(buffer).into_iter();
+
+        let mapping = parse::next(&mut splitted, buffer, "the enhancement algorithm")?;
+        let enhanced_mapping: Vec<char> = mapping.chars().collect();
+        if let Some(i) = mapping.find(|c| !matches!(c, '#' | '.')) {
+            return Err(ParseError::at(buffer, &mapping[i..], "'#' or '.'"));
+        }
+        if enhanced_mapping.len() != 512 {
+            return Err(ParseError::at(
+                buffer,
+                mapping,
+                "an enhancement algorithm of 512 pixels",
+            ));
+        }
+
+        let image = parse::next(&mut splitted, buffer, "the input image")?;
+        let content = Grid::parse(image, "'#' or '.'", |c| matches!(c, '#' | '.').then_some(c))
+            .within(buffer, image)?;
+
+        let is_swapping = matches!(
+            (enhanced_mapping.first(), enhanced_mapping.last()),
+            (Some('#'), Some('.'))
+        );
+
+        Ok(EnhanceableGrid {
+            enhanced_mapping,
+            content,
+            amount_enhanced: 0,
+            is_swapping,
+        })
+    }
+}
+
+impl EnhanceableGrid {
+    fn rows(&self) -> usize {
+        self.content.rows()
+    }
+    fn cols(&self) -> usize {
+        self.content.cols()
+    }
+    fn get_at(&self, row: i64, col: i64) -> char {
+        if let Some(pos) = self.content.checked_pos(row, col) {
+            self.content[pos]
+        } else if self.is_swapping && self.amount_enhanced % 2 == 1 {
+            '#'
+        } else {
+            '.'
+        }
+    }
+
+    fn kernel_replacement(&self, kernel: &[char]) -> char {
+        let mut index = 0;
+        let mut value = 1;
+        for elem in kernel.iter().rev() {
+            if *elem == '#' {
+                index += value;
+            }
+            value *= 2;
+        }
+        self.enhanced_mapping[index]
+    }
+
+    fn enhance(&mut self) {
+        let new_rows = self.rows() + 2;
+        let new_cols = self.cols() + 2;
+        let delta = vec![
+            (-1, -1),
+            (-1, 0),
+            (-1, 1),
+            (0, -1),
+            (0, 0),
+            (0, 1),
+            (1, -1),
+            (1, 0),
+            (1, 1),
+        ];
+        self.content = Grid::from_fn(new_rows, new_cols, |(row, col)| {
+            let mut kernel = Vec::new();
+            for (drow, dcol) in delta.iter() {
+                kernel.push(self.get_at(row as i64 - 1 + drow, col as i64 - 1 + dcol));
+            }
+            self.kernel_replacement(&kernel)
+        });
+        self.amount_enhanced += 1;
+        record::frame(|| self.content.to_string());
+    }
+
+    fn count_hashes(&self) -> i64 {
+        self.content.iter().filter(|&&c| c == '#').count() as i64
+    }
+
+    fn trace_content(&self) {
+        trace!(
+            "after {} enhancements\n{}",
+            self.amount_enhanced,
+            self.content
+        );
+    }
+}
+
+pub struct TrenchMap;
+
+impl Solution for TrenchMap {
+    type Input = EnhanceableGrid;
+
+    fn parse_input(input: &str) -> Result<EnhanceableGrid, ParseError> {
+        parse_buffer(input)
+    }
+
+    fn part_one(mut grid: Self::Input) -> Result<Answer> {
+        Ok(Answer::from(part_one(&mut grid)))
+    }
+
+    // part two continues enhancing the grid where part one left off
+    fn part_two(mut grid: Self::Input) -> Result<Answer> {
+        part_one(&mut grid);
+        Ok(Answer::from(part_two(&mut grid)))
+    }
+}
+
+fn parse_buffer(buffer: &str) -> Result<EnhanceableGrid, ParseError> {
+    EnhanceableGrid::parse(buffer)
+}
+
+fn part_one(grid: &mut EnhanceableGrid) -> i64 {
+    debug!("{} x {}", grid.rows(), grid.cols());
+    grid.trace_content();
+    grid.enhance();
+    grid.trace_content();
+    grid.enhance();
+    grid.trace_content();
+    debug!("{} x {}", grid.rows(), grid.cols());
+    grid.count_hashes()
+}
+
+fn part_two(grid: &mut EnhanceableGrid) -> i64 {
+    for _ in 0..48 {
+        grid.enhance();
+    }
+    grid.count_hashes()
+}
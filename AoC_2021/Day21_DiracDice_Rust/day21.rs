@@ -0,0 +1,348 @@
+use anyhow::{anyhow, bail, Result};
+use aoc_core::{input, parse, Answer, ParseError, Solution};
+use lazy_static::lazy_static;
+use log::debug;
+use regex::Regex;
+use std::collections::BTreeMap;
+
+// every turn the player rolls the die this often and moves by the sum
+const ROLLS: usize = 3;
+
+/// Where both players start, 1 up to the size of the board.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Game {
+    start: [usize; 2],
+}
+
+fn advance(position: usize, steps: usize, board: usize) -> usize {
+    (position - 1 + steps) % board + 1
+}
+
+impl Game {
+    fn check(&self, board: usize) -> Result<()> {
+        if board == 0 {
+            bail!("there is no board without spaces");
+        }
+        if let Some(start) = self
+            .start
+            .iter()
+            .find(|&&start| start == 0 || start > board)
+        {
+            bail!("space {} is not on a board of {}", start, board);
+        }
+        Ok(())
+    }
+
+    /// The score of the loser and the number of rolls of a die that rolls
+    /// 1, 2 and so on up to `faces`, then 1 again.
+    pub fn play_deterministic(
+        &self,
+        board: usize,
+        faces: usize,
+        winning: usize,
+    ) -> Result<(usize, usize)> {
+        self.check(board)?;
+        if faces == 0 {
+            bail!("there is no die without faces");
+        }
+        let (mut positions, mut scores) = (self.start, [0; 2]);
+        let mut rolls = 0;
+        for turn in (0..2).cycle() {
+            let steps: usize = (0..ROLLS).map(|i| (rolls + i) % faces + 1).sum();
+            rolls += ROLLS;
+            positions[turn] = advance(positions[turn], steps, board);
+            scores[turn] += positions[turn];
+            if scores[turn] >= winning {
+                break;
+            }
+        }
+        debug!("scores {:?} after {} rolls", scores, rolls);
+        Ok((scores[0].min(scores[1]), rolls))
+    }
+
+    /// The universes each player wins in with a Dirac die of `faces`.
+    pub fn play_dirac(&self, board: usize, faces: usize, winning: usize) -> Result<[i128; 2]> {
+        self.check(board)?;
+        if faces == 0 {
+            bail!("there is no die without faces");
+        }
+        let multiverse = Multiverse::new(board, faces, winning)?;
+        let wins = multiverse.wins(self.start, [0; 2], 0)?;
+        debug!("{:?} from {} states", wins, multiverse.states());
+        Ok(wins)
+    }
+}
+
+/// The sums of the rolls of a turn with a die of `faces` and in how many
+/// universes each comes up, 27 universes for 3 faces.
+pub fn roll_distribution(faces: usize) -> BTreeMap<usize, i128> {
+    let mut sums = BTreeMap::from([(0, 1)]);
+    for _ in 0..ROLLS {
+        let mut next = BTreeMap::new();
+        for (&sum, &universes) in sums.iter() {
+            for face in 1..=faces {
+                *next.entry(sum + face).or_insert(0) += universes;
+            }
+        }
+        sums = next;
+    }
+    sums
+}
+
+// every state a game can be in gets a row in the table
+const MAX_STATES: usize = 1 << 22;
+
+/// Counts in how many universes each player wins with a Dirac die, the
+/// universes of games in the same state counted once.
+pub struct Multiverse {
+    board: usize,
+    winning: usize,
+    rolls: Vec<(usize, i128)>,
+    // by positions, scores and whose turn it is, for the scores below
+    // `winning`
+    wins: Vec<[i128; 2]>,
+}
+
+impl Multiverse {
+    /// Fails for boards and winning scores with more than 4194304 states
+    /// a game can be in, or more universes than an `i128` counts.
+    pub fn new(board: usize, faces: usize, winning: usize) -> Result<Self> {
+        let states = [board, board, winning, winning, 2]
+            .into_iter()
+            .try_fold(1, usize::checked_mul)
+            .filter(|&states| states <= MAX_STATES);
+        let Some(states) = states else {
+            bail!(
+                "a board of {} played to {} has more than {} states",
+                board,
+                winning,
+                MAX_STATES
+            );
+        };
+        let mut multiverse = Multiverse {
+            board,
+            winning,
+            rolls: roll_distribution(faces).into_iter().collect(),
+            wins: vec![[0; 2]; states],
+        };
+        // a turn only raises the scores, so the states after it are counted
+        // before it
+        for total in (0..(2 * winning).saturating_sub(1)).rev() {
+            for first in total.saturating_sub(winning - 1)..=total.min(winning - 1) {
+                let scores = [first, total - first];
+                for i in 0..board * board * 2 {
+                    let positions = [i / 2 / board + 1, i / 2 % board + 1];
+                    let wins = multiverse.wins(positions, scores, i % 2)?;
+                    let index = multiverse.index(positions, scores, i % 2);
+                    multiverse.wins[index] = wins;
+                }
+            }
+        }
+        Ok(multiverse)
+    }
+
+    fn index(&self, positions: [usize; 2], scores: [usize; 2], turn: usize) -> usize {
+        let positions = (positions[0] - 1) * self.board + positions[1] - 1;
+        ((positions * self.winning + scores[0]) * self.winning + scores[1]) * 2 + turn
+    }
+
+    /// The universes each player wins in from `positions` and `scores` with
+    /// `turn` to move.
+    pub fn wins(
+        &self,
+        positions: [usize; 2],
+        scores: [usize; 2],
+        turn: usize,
+    ) -> Result<[i128; 2]> {
+        let mut wins = [0i128; 2];
+        for &(steps, universes) in self.rolls.iter() {
+            let (mut positions, mut scores) = (positions, scores);
+            positions[turn] = advance(positions[turn], steps, self.board);
+            scores[turn] += positions[turn];
+            let mut later = [0; 2];
+            if scores[turn] >= self.winning {
+                later[turn] = 1;
+            } else {
+                later = self.wins[self.index(positions, scores, 1 - turn)];
+            }
+            for (wins, later) in wins.iter_mut().zip(later) {
+                *wins = later
+                    .checked_mul(universes)
+                    .and_then(|later| (*wins).checked_add(later))
+                    .ok_or_else(|| anyhow!("more universes than an i128 counts"))?;
+            }
+        }
+        Ok(wins)
+    }
+
+    pub fn states(&self) -> usize {
+        self.wins.len()
+    }
+}
+
+fn parse_input(input: &str) -> Result<Game, ParseError> {
+    lazy_static! {
+        static ref RE: Regex = Regex::new(r"^Player (\d+) starting position: (\d+)$").unwrap();
+    }
+    let mut lines = input::lines(input);
+    let mut start = [0; 2];
+    for (i, start) in start.iter_mut().enumerate() {
+        let line = parse::next(&mut lines, input, format!("player {}", i + 1).as_str())?;
+        let caps = parse::captures(&RE, input, line)?;
+        if caps[1] != (i + 1).to_string() {
+            return Err(ParseError::at(input, &caps[1], format!("player {}", i + 1)));
+        }
+        *start = parse::value(input, &caps[2], "a starting position")?;
+    }
+    Ok(Game { start })
+}
+
+aoc_core::params! {
+    pub struct Params {
+        board: usize = 10,
+        faces_one: usize = 100,
+        winning_one: usize = 1000,
+        faces_two: usize = 3,
+        winning_two: usize = 21,
+    }
+}
+
+pub struct DiracDice;
+
+impl Solution for DiracDice {
+    type Input = Game;
+
+    fn parse_input(input: &str) -> Result<Game, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(game: Self::Input) -> Result<Answer> {
+        let params = Params::get()?;
+        let (losing, rolls) =
+            game.play_deterministic(params.board, params.faces_one, params.winning_one)?;
+        Ok(Answer::from(losing * rolls))
+    }
+
+    fn part_two(game: Self::Input) -> Result<Answer> {
+        let params = Params::get()?;
+        let wins = game.play_dirac(params.board, params.faces_two, params.winning_two)?;
+        Ok(Answer::from(wins[0].max(wins[1])))
+    }
+
+    fn params() -> Vec<(&'static str, String)> {
+        Params::defaults()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Rules {
+        board: usize,
+        faces: usize,
+        winning: usize,
+    }
+
+    // plays every universe to the end, one roll at a time
+    fn brute_force(
+        rules: &Rules,
+        positions: [usize; 2],
+        scores: [usize; 2],
+        turn: usize,
+        rolled: usize,
+        steps: usize,
+    ) -> [i128; 2] {
+        if rolled == ROLLS {
+            let (mut positions, mut scores) = (positions, scores);
+            positions[turn] = advance(positions[turn], steps, rules.board);
+            scores[turn] += positions[turn];
+            if scores[turn] >= rules.winning {
+                let mut wins = [0; 2];
+                wins[turn] = 1;
+                return wins;
+            }
+            return brute_force(rules, positions, scores, 1 - turn, 0, 0);
+        }
+        let mut wins = [0; 2];
+        for face in 1..=rules.faces {
+            let later = brute_force(rules, positions, scores, turn, rolled + 1, steps + face);
+            wins[0] += later[0];
+            wins[1] += later[1];
+        }
+        wins
+    }
+
+    #[test]
+    fn twenty_seven_universes_per_turn() {
+        assert_eq!(
+            roll_distribution(3),
+            BTreeMap::from([(3, 1), (4, 3), (5, 6), (6, 7), (7, 6), (8, 3), (9, 1)])
+        );
+        assert_eq!(roll_distribution(2).values().sum::<i128>(), 8);
+    }
+
+    #[test]
+    fn memo_agrees_with_brute_force_on_small_boards() {
+        let mut games = 0;
+        for board in 1..=6 {
+            for faces in 1..=3 {
+                for winning in 1..=10 {
+                    for a in 1..=board {
+                        for b in 1..=board {
+                            let game = Game { start: [a, b] };
+                            let rules = Rules {
+                                board,
+                                faces,
+                                winning,
+                            };
+                            let wins = game.play_dirac(board, faces, winning).unwrap();
+                            // the brute force visits every universe
+                            if wins[0] + wins[1] > 100_000 {
+                                continue;
+                            }
+                            games += 1;
+                            assert_eq!(
+                                wins,
+                                brute_force(&rules, [a, b], [0, 0], 0, 0, 0),
+                                "board {} faces {} winning {} from {:?}",
+                                board,
+                                faces,
+                                winning,
+                                [a, b]
+                            );
+                        }
+                    }
+                }
+            }
+        }
+        assert!(games > 1000, "only {} games", games);
+    }
+
+    #[test]
+    fn deterministic_die() {
+        let game =
+            parse_input("Player 1 starting position: 4\nPlayer 2 starting position: 8\n").unwrap();
+        assert_eq!(game.play_deterministic(10, 100, 1000).unwrap(), (745, 993));
+        assert!(game.play_deterministic(6, 100, 1000).is_err());
+        assert!(
+            parse_input("Player 2 starting position: 4\nPlayer 1 starting position: 8\n").is_err()
+        );
+    }
+
+    #[test]
+    fn games_beyond_the_table() {
+        let off_board = Game { start: [0, 8] };
+        assert!(off_board.play_deterministic(10, 100, 1000).is_err());
+        assert!(off_board.play_dirac(10, 3, 21).is_err());
+        let game = Game { start: [4, 8] };
+        let err = game.play_dirac(10, 3, 100_000).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "a board of 10 played to 100000 has more than 4194304 states"
+        );
+        // one space scores 1 a turn, 27 universes a turn for 80 turns
+        let err = Game { start: [1, 1] }.play_dirac(1, 3, 40).unwrap_err();
+        assert_eq!(err.to_string(), "more universes than an i128 counts");
+    }
+}
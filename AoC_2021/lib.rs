@@ -0,0 +1,129 @@
+//! Advent of Code 2021, one module per `Day<dd>_<Title>_Rust` directory.
+
+use aoc_core::{day, Day};
+
+#[path = "Day01_SonarSweep_Rust/day01.rs"]
+pub mod day01;
+#[path = "Day02_Dive_Rust/day02.rs"]
+pub mod day02;
+#[path = "Day03_BinaryDiagnostic_Rust/day03.rs"]
+pub mod day03;
+#[path = "Day04_GiantSquid_Rust/day04.rs"]
+pub mod day04;
+#[path = "Day05_HydrothermalVenture_Rust/day05.rs"]
+pub mod day05;
+#[path = "Day06_Lanternfish_Rust/day06.rs"]
+pub mod day06;
+#[path = "Day07_TheTreacheryOfWhales_Rust/day07.rs"]
+pub mod day07;
+#[path = "Day08_SevenSegmentSearch_Rust/day08.rs"]
+pub mod day08;
+#[path = "Day09_SmokeBasin_Rust/day09.rs"]
+pub mod day09;
+#[path = "Day10_SyntaxScoring_Rust/day10.rs"]
+pub mod day10;
+#[path = "Day11_DumboOctopus_Rust/day11.rs"]
+pub mod day11;
+#[path = "Day12_PassagePathing_Rust/day12.rs"]
+pub mod day12;
+#[path = "Day13_TransparentOrigami_Rust/day13.rs"]
+pub mod day13;
+#[path = "Day14_ExtendedPolymerization_Rust/day14.rs"]
+pub mod day14;
+#[path = "Day15_Chiton_Rust/day15.rs"]
+pub mod day15;
+#[path = "Day16_PacketDecoder_Rust/day16.rs"]
+pub mod day16;
+#[path = "Day17_TrickShot_Rust/day17.rs"]
+pub mod day17;
+#[path = "Day18_Snailfish_Rust/day18.rs"]
+pub mod day18;
+#[path = "Day19_BeaconScanner_Rust/day19.rs"]
+pub mod day19;
+#[path = "Day20_TrenchMap_Rust/day20.rs"]
+pub mod day20;
+#[path = "Day21_DiracDice_Rust/day21.rs"]
+pub mod day21;
+
+pub const YEAR: u16 = 2021;
+
+pub fn days() -> Vec<Day> {
+    vec![
+        day!(YEAR, 1, "Day01_SonarSweep_Rust", day01::SonarSweep),
+        day!(YEAR, 2, "Day02_Dive_Rust", day02::Dive),
+        day!(
+            YEAR,
+            3,
+            "Day03_BinaryDiagnostic_Rust",
+            day03::BinaryDiagnostic
+        ),
+        day!(YEAR, 4, "Day04_GiantSquid_Rust", day04::GiantSquid),
+        day!(
+            YEAR,
+            5,
+            "Day05_HydrothermalVenture_Rust",
+            day05::HydrothermalVenture
+        ),
+        day!(YEAR, 6, "Day06_Lanternfish_Rust", day06::Lanternfish),
+        day!(
+            YEAR,
+            7,
+            "Day07_TheTreacheryOfWhales_Rust",
+            day07::TheTreacheryOfWhales
+        ),
+        day!(
+            YEAR,
+            8,
+            "Day08_SevenSegmentSearch_Rust",
+            day08::SevenSegmentSearch
+        ),
+        day!(YEAR, 9, "Day09_SmokeBasin_Rust", day09::SmokeBasin),
+        day!(YEAR, 10, "Day10_SyntaxScoring_Rust", day10::SyntaxScoring),
+        day!(YEAR, 11, "Day11_DumboOctopus_Rust", day11::DumboOctopus),
+        day!(YEAR, 12, "Day12_PassagePathing_Rust", day12::PassagePathing),
+        day!(
+            YEAR,
+            13,
+            "Day13_TransparentOrigami_Rust",
+            day13::TransparentOrigami
+        ),
+        day!(
+            YEAR,
+            14,
+            "Day14_ExtendedPolymerization_Rust",
+            day14::ExtendedPolymerization
+        ),
+        day!(YEAR, 15, "Day15_Chiton_Rust", day15::Chiton),
+        day!(YEAR, 16, "Day16_PacketDecoder_Rust", day16::PacketDecoder),
+        day!(YEAR, 17, "Day17_TrickShot_Rust", day17::TrickShot),
+        day!(YEAR, 18, "Day18_Snailfish_Rust", day18::Snailfish),
+        day!(YEAR, 19, "Day19_BeaconScanner_Rust", day19::BeaconScanner),
+        day!(YEAR, 20, "Day20_TrenchMap_Rust", day20::TrenchMap),
+        day!(YEAR, 21, "Day21_DiracDice_Rust", day21::DiracDice),
+    ]
+}
+
+aoc_core::answer_tests!(
+    "answers.toml",
+    day01,
+    day02,
+    day03,
+    day04,
+    day05,
+    day06,
+    day07,
+    day08,
+    day09,
+    day10,
+    day11,
+    day12,
+    day13,
+    day14,
+    day15,
+    day16,
+    day17,
+    day18,
+    day19,
+    day20,
+    day21,
+);
@@ -0,0 +1,60 @@
+use anyhow::{anyhow, Result};
+use aoc_core::{input, parse, Answer, ParseError, Solution};
+
+pub struct Elves {
+    elf_vec: Vec<Elf>,
+}
+
+struct Elf {
+    calories: Vec<i128>,
+}
+
+impl Elf {
+    fn sum_calories(&self) -> i128 {
+        self.calories.iter().sum()
+    }
+}
+
+fn parse_input(buffer: &str) -> Result<Elves, ParseError> {
+    let mut elf_vec: Vec<Elf> = Vec::new();
+    for elf_calories in input::blocks(buffer) {
+        let calories = input::lines(elf_calories)
+            .map(|l| parse::value(buffer, l, "calories"))
+            .collect::<Result<Vec<_>, _>>()?;
+        elf_vec.push(Elf { calories });
+    }
+
+    Ok(Elves { elf_vec })
+}
+
+pub struct CalorieCounting;
+
+impl Solution for CalorieCounting {
+    type Input = Elves;
+
+    fn parse_input(input: &str) -> Result<Elves, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(elves: Self::Input) -> Result<Answer> {
+        let total_calories = elves
+            .elf_vec
+            .iter()
+            .map(|e| e.sum_calories())
+            .collect::<Vec<_>>();
+
+        if let Some(&maximum) = total_calories.iter().max() {
+            Ok(Answer::from(maximum))
+        } else {
+            Err(anyhow!("no elements for maximum!"))
+        }
+    }
+
+    fn part_two(elves: Self::Input) -> Result<Answer> {
+        let mut total_calories: Vec<i128> =
+            elves.elf_vec.iter().map(|e| e.sum_calories()).collect();
+        total_calories.sort();
+        let top3: i128 = total_calories.iter().rev().take(3).sum();
+        Ok(Answer::from(top3))
+    }
+}
@@ -0,0 +1,108 @@
+use anyhow::Result;
+use aoc_core::{input, parse, Answer, ParseError, Solution};
+use lazy_static::lazy_static;
+use regex::Regex;
+
+#[derive(Copy, Clone)]
+enum Choice {
+    Rock,
+    Paper,
+    Scissor,
+}
+
+fn char_to_choice(c: char) -> Choice {
+    use Choice::*;
+    match c {
+        'A' | 'X' => Rock,
+        'B' | 'Y' => Paper,
+        'C' | 'Z' => Scissor,
+        _ => panic!("invalid choice"),
+    }
+}
+
+fn chars_to_choices(match_up: &(char, char)) -> (Choice, Choice) {
+    // X lose, Y draw, Z win
+    use Choice::*;
+    match *match_up {
+        ('A', 'X') => (Rock, Scissor),
+        ('A', 'Y') => (Rock, Rock),
+        ('A', 'Z') => (Rock, Paper),
+        ('B', 'X') => (Paper, Rock),
+        ('B', 'Y') => (Paper, Paper),
+        ('B', 'Z') => (Paper, Scissor),
+        ('C', 'X') => (Scissor, Paper),
+        ('C', 'Y') => (Scissor, Scissor),
+        ('C', 'Z') => (Scissor, Rock),
+        _ => panic!("invalid choice"),
+    }
+}
+
+fn get_your_score(opponent: Choice, you: Choice) -> i128 {
+    use Choice::*;
+    let match_score = match (opponent, you) {
+        (Rock, Paper) | (Paper, Scissor) | (Scissor, Rock) => 6,
+        (Rock, Rock) | (Paper, Paper) | (Scissor, Scissor) => 3,
+        _ => 0,
+    };
+    let choice_score = match you {
+        Rock => 1,
+        Paper => 2,
+        Scissor => 3,
+    };
+    match_score + choice_score
+}
+
+pub struct TaskData {
+    matches: Vec<(char, char)>,
+}
+
+impl TaskData {
+    fn get_final_score(&self) -> i128 {
+        self.matches
+            .iter()
+            .map(|m| get_your_score(char_to_choice(m.0), char_to_choice(m.1)))
+            .sum()
+    }
+    fn get_final_score_part_two(&self) -> i128 {
+        self.matches
+            .iter()
+            .map(|m| {
+                let (opponent, you) = chars_to_choices(m);
+                get_your_score(opponent, you)
+            })
+            .sum()
+    }
+}
+
+fn parse_input(buffer: &str) -> Result<TaskData, ParseError> {
+    lazy_static! {
+        static ref RE: Regex = Regex::new(r"^([ABC]) ([XYZ])$").unwrap();
+    }
+    let matches = input::lines(buffer)
+        .map(|l| {
+            let caps = parse::captures(&RE, buffer, l)?;
+            // the regex only matches single ASCII letters
+            Ok((caps[1].as_bytes()[0] as char, caps[2].as_bytes()[0] as char))
+        })
+        .collect::<Result<Vec<(char, char)>, ParseError>>()?;
+
+    Ok(TaskData { matches })
+}
+
+pub struct RockPaperScissors;
+
+impl Solution for RockPaperScissors {
+    type Input = TaskData;
+
+    fn parse_input(input: &str) -> Result<TaskData, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(data: Self::Input) -> Result<Answer> {
+        Ok(Answer::from(data.get_final_score()))
+    }
+
+    fn part_two(data: Self::Input) -> Result<Answer> {
+        Ok(Answer::from(data.get_final_score_part_two()))
+    }
+}
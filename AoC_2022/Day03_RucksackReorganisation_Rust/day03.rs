@@ -0,0 +1,97 @@
+use anyhow::Result;
+use aoc_core::{input, Answer, ParseError, Solution};
+use std::collections::HashSet;
+
+struct Rucksack {
+    content: Vec<char>,
+}
+
+impl<'a> From<&'a Rucksack> for HashSet<&'a char> {
+    fn from(sack: &'a Rucksack) -> Self {
+        sack.content.iter().collect()
+    }
+}
+
+impl Rucksack {
+    fn split_sack(&self) -> [Self; 2] {
+        let middle = self.content.len() / 2;
+        let (first, second) = self.content.split_at(middle);
+        [
+            Self {
+                content: first.to_vec(),
+            },
+            Self {
+                content: second.to_vec(),
+            },
+        ]
+    }
+    fn intersect_sacks(sacks: &[Self]) -> HashSet<&char> {
+        let mut sacks_iter = sacks.iter();
+        let mut accu: HashSet<&char> = if let Some(rucksack) = sacks_iter.next() {
+            HashSet::from(rucksack)
+        } else {
+            return HashSet::new();
+        };
+        for sack in sacks_iter {
+            accu = accu.intersection(&HashSet::from(sack)).cloned().collect();
+        }
+        accu
+    }
+    fn to_priority(c: char) -> i128 {
+        let offset = if c.is_ascii_lowercase() {
+            b'a'
+        } else {
+            b'A' - 26
+        };
+        (c as u8 - offset + 1) as i128
+    }
+    fn priority_of_sacks(sacks: &[Self]) -> i128 {
+        Self::intersect_sacks(sacks)
+            .into_iter()
+            .map(|&c| Self::to_priority(c))
+            .sum::<i128>()
+    }
+}
+
+pub struct TaskData {
+    rucksacks: Vec<Rucksack>,
+}
+
+fn parse_input(buffer: &str) -> Result<TaskData, ParseError> {
+    let rucksacks = input::lines(buffer)
+        .map(|l| {
+            if let Some(i) = l.find(|c: char| !c.is_ascii_alphabetic()) {
+                return Err(ParseError::at(buffer, &l[i..], "an item letter"));
+            }
+            Ok(Rucksack {
+                content: l.chars().collect::<Vec<_>>(),
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(TaskData { rucksacks })
+}
+
+pub struct RucksackReorganisation;
+
+impl Solution for RucksackReorganisation {
+    type Input = TaskData;
+
+    fn parse_input(input: &str) -> Result<TaskData, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(TaskData { rucksacks }: Self::Input) -> Result<Answer> {
+        let priority_sum: i128 = rucksacks
+            .into_iter()
+            .map(|r| Rucksack::priority_of_sacks(&r.split_sack()))
+            .sum();
+
+        Ok(Answer::from(priority_sum))
+    }
+
+    fn part_two(TaskData { rucksacks }: Self::Input) -> Result<Answer> {
+        let priority_sum: i128 = rucksacks.chunks(3).map(Rucksack::priority_of_sacks).sum();
+
+        Ok(Answer::from(priority_sum))
+    }
+}
@@ -0,0 +1,76 @@
+use anyhow::Result;
+use aoc_core::{input, parse, Answer, ParseError, Solution};
+use lazy_static::lazy_static;
+use regex::Regex;
+
+struct Range {
+    begin: i128,
+    end: i128,
+}
+
+impl Range {
+    fn contains_range(&self, other: &Range) -> bool {
+        self.begin <= other.begin && self.end >= other.end
+    }
+    fn overlaps_range(&self, other: &Range) -> bool {
+        self.begin <= other.begin && other.begin <= self.end
+            || self.begin <= other.end && other.end <= self.end
+    }
+    fn some_range_contained(ranges: &(Range, Range)) -> bool {
+        ranges.0.contains_range(&ranges.1) || ranges.1.contains_range(&ranges.0)
+    }
+    fn some_range_overlapped(ranges: &(Range, Range)) -> bool {
+        ranges.0.overlaps_range(&ranges.1) || ranges.1.overlaps_range(&ranges.0)
+    }
+}
+
+pub struct TaskData {
+    elf_pairs: Vec<(Range, Range)>,
+}
+
+fn parse_input(input: &str) -> Result<TaskData, ParseError> {
+    // 1-2,3-4
+    lazy_static! {
+        static ref RE: Regex = Regex::new(r"^(\d+)-(\d+),(\d+)-(\d+)$").unwrap();
+    }
+
+    let range = |begin: &str, end: &str| -> Result<Range, ParseError> {
+        Ok(Range {
+            begin: parse::value(input, begin, "a section id")?,
+            end: parse::value(input, end, "a section id")?,
+        })
+    };
+    let elf_pairs = input::lines(input)
+        .map(|l| {
+            let caps = parse::captures(&RE, input, l)?;
+            Ok((range(&caps[1], &caps[2])?, range(&caps[3], &caps[4])?))
+        })
+        .collect::<Result<Vec<_>, ParseError>>()?;
+    Ok(TaskData { elf_pairs })
+}
+
+pub struct CampCleanup;
+
+impl Solution for CampCleanup {
+    type Input = TaskData;
+
+    fn parse_input(input: &str) -> Result<TaskData, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(TaskData { elf_pairs }: Self::Input) -> Result<Answer> {
+        let contained: i128 = elf_pairs
+            .iter()
+            .map(|p| Range::some_range_contained(p) as i128)
+            .sum();
+        Ok(Answer::from(contained))
+    }
+
+    fn part_two(TaskData { elf_pairs }: Self::Input) -> Result<Answer> {
+        let contained: i128 = elf_pairs
+            .iter()
+            .map(|p| Range::some_range_overlapped(p) as i128)
+            .sum();
+        Ok(Answer::from(contained))
+    }
+}
@@ -0,0 +1,129 @@
+use anyhow::Result;
+use aoc_core::{input, parse, Answer, ParseError, Solution};
+use lazy_static::lazy_static;
+use log::trace;
+use regex::Regex;
+use std::collections::VecDeque;
+
+#[derive(Debug)]
+pub struct TaskData {
+    stacks: Vec<VecDeque<char>>,
+    operations: Vec<Operation>,
+}
+
+impl TaskData {
+    fn execute_operations(&mut self) {
+        for op in self.operations.iter() {
+            op.execute(&mut self.stacks);
+        }
+    }
+    fn execute_operations_9001(&mut self) {
+        for op in self.operations.iter() {
+            op.execute_9001(&mut self.stacks);
+        }
+    }
+    fn stack_top_str(&self) -> String {
+        let mut tops = String::new();
+        for stack in self.stacks.iter() {
+            let &c = stack.front().unwrap_or(&' ');
+            tops.push(c);
+        }
+        tops
+    }
+}
+
+#[derive(Debug)]
+struct Operation {
+    amount: usize,
+    from: usize,
+    to: usize,
+}
+
+impl Operation {
+    fn execute(&self, stacks: &mut [VecDeque<char>]) {
+        trace!("{:?}", stacks);
+        let mut amount = self.amount;
+        while amount > 0 {
+            if let Some(c) = stacks[self.from].pop_front() {
+                stacks[self.to].push_front(c);
+            }
+            amount -= 1;
+        }
+    }
+    fn execute_9001(&self, stacks: &mut [VecDeque<char>]) {
+        trace!("{:?}", stacks);
+        let to_push: Vec<char> = stacks[self.from].drain(..self.amount).rev().collect();
+        for p in to_push {
+            stacks[self.to].push_front(p);
+        }
+    }
+}
+
+fn parse_input(input: &str) -> Result<TaskData, ParseError> {
+    let mut blocks = input::blocks(input).into_iter();
+    let stacks_str = parse::next(&mut blocks, input, "the stacks")?;
+    let operations_str = parse::next(&mut blocks, input, "the rearrangement procedure")?;
+
+    let mut stacks: Vec<VecDeque<char>> = Vec::new();
+    for line in input::lines(stacks_str) {
+        let v: Vec<char> = line.chars().collect();
+        // [A] [B] [C]
+        //  1   5   9  ... -> index = (i - 1) / 4
+        let mut i = 1;
+        while let Some(&c) = v.get(i) {
+            if c != ' ' {
+                let target_stack = (i - 1) / 4;
+                while stacks.len() <= target_stack {
+                    stacks.push(VecDeque::new());
+                }
+                stacks[target_stack].push_back(c);
+            }
+            i += 4;
+        }
+    }
+
+    let mut operations = Vec::new();
+    lazy_static! {
+        static ref RE: Regex = Regex::new(r"^move (\d+) from (\d+) to (\d+)$").unwrap();
+    }
+    let stack = |s: &str| -> Result<usize, ParseError> {
+        match parse::value::<usize>(input, s, "a stack number")? {
+            n @ 1.. if n <= stacks.len() => Ok(n - 1),
+            _ => Err(ParseError::at(
+                input,
+                s,
+                format!("a stack number from 1 to {}", stacks.len()),
+            )),
+        }
+    };
+    for line in input::lines(operations_str) {
+        let captures = parse::captures(&RE, input, line)?;
+        operations.push(Operation {
+            amount: parse::value(input, &captures[1], "an amount of crates")?,
+            from: stack(&captures[2])?,
+            to: stack(&captures[3])?,
+        })
+    }
+
+    Ok(TaskData { stacks, operations })
+}
+
+pub struct SupplyStacks;
+
+impl Solution for SupplyStacks {
+    type Input = TaskData;
+
+    fn parse_input(input: &str) -> Result<TaskData, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(mut data: Self::Input) -> Result<Answer> {
+        data.execute_operations();
+        Ok(Answer::from(data.stack_top_str()))
+    }
+
+    fn part_two(mut data: Self::Input) -> Result<Answer> {
+        data.execute_operations_9001();
+        Ok(Answer::from(data.stack_top_str()))
+    }
+}
@@ -1,14 +1,16 @@
-use anyhow::{anyhow, Context, Result};
+use anyhow::{anyhow, Result};
+use aoc_core::{input, Answer, ParseError, Solution};
 use std::collections::HashSet;
 //use std::collections::{HashMap, VecDeque};
-use std::io::{self, Read};
 
-struct TaskData {
+pub struct TaskData {
     signal: Vec<char>,
 }
 
-fn parse_input(input: &str) -> Result<TaskData> {
-    let first_line = input.lines().next().context("input had no lines")?;
+fn parse_input(input: &str) -> Result<TaskData, ParseError> {
+    let first_line = input::lines(input)
+        .next()
+        .ok_or_else(|| ParseError::missing(input, "a signal"))?;
     let signal: Vec<char> = first_line.chars().collect();
 
     Ok(TaskData { signal })
@@ -52,14 +54,29 @@ fn is_unique_chars(cs: &[char]) -> bool {
     true
 }
 
-fn part_one(input: &str) -> Result<usize> {
-    let TaskData { signal } = parse_input(input)?;
-    Ok(first_start_of_packet_marker(&signal)?)
-}
+pub struct TuningTrouble;
 
-fn part_two(input: &str) -> Result<usize> {
-    let TaskData { signal } = parse_input(input)?;
-    Ok(first_start_of_message_marker(&signal)?)
+impl Solution for TuningTrouble {
+    type Input = TaskData;
+
+    fn parse_input(input: &str) -> Result<TaskData, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(TaskData { signal }: Self::Input) -> Result<Answer> {
+        Ok(Answer::from(first_start_of_packet_marker(&signal)?))
+    }
+
+    fn part_two(TaskData { signal }: Self::Input) -> Result<Answer> {
+        Ok(Answer::from(first_start_of_message_marker(&signal)?))
+    }
+
+    fn worst_cases() -> Vec<(&'static str, String)> {
+        // the markers only show up at the very end of a long run of one character
+        let mut late_markers = "a".repeat(10_000_000);
+        late_markers.push_str("bcdefghijklmn");
+        vec![("late_markers", late_markers)]
+    }
 }
 
 fn find_marker_slow(marker_len: usize, signal: &[char]) -> Result<usize> {
@@ -114,44 +131,20 @@ fn is_unique_chars_slow(cs: &[char]) -> bool {
     set.len() == cs.len()
 }
 
-fn part_two_slow(input: &str) -> Result<usize> {
+pub fn part_two_slow(input: &str) -> Result<usize> {
     let TaskData { signal } = parse_input(input)?;
-    Ok(first_start_of_message_marker_slow(&signal)?)
-}
-
-fn main() -> Result<()> {
-    let mut input = String::new();
-    io::stdin().read_to_string(&mut input)?;
-    println!("Part one: {}", part_one(&input)?);
-    println!("Part two: {}", part_two(&input)?);
-
-    // worst case time test
-    let mut worst_case = vec!['a'; 10000000];
-    let mut end = vec![
-        'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'm', 'n',
-    ];
-    worst_case.append(&mut end);
-    let worst_case_str: String = worst_case.into_iter().collect();
-
-    let t = std::time::Instant::now();
-    let _ = part_two(&worst_case_str);
-    println!("Part two {:0.2?}", t.elapsed());
-
-    let t = std::time::Instant::now();
-    let _ = part_two_slow(&worst_case_str);
-    println!("Part two slow {:0.2?}", t.elapsed());
-    Ok(())
+    first_start_of_message_marker_slow(&signal)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_core::differential::{assert_agree, proptest::prelude::*};
     use lazy_static::lazy_static;
     use std::fs;
 
     lazy_static! {
-        static ref TEST: String = read_from_file("test.txt");
-        static ref INPUT: String = read_from_file("input.txt");
+        static ref TEST: String = read_from_file("Day06_TuningTrouble_Rust/test.txt");
     }
 
     fn read_from_file(filename: &str) -> String {
@@ -159,93 +152,89 @@ mod tests {
             .unwrap_or_else(|msg| panic!("error reading {}: {}", filename, msg))
     }
 
-    #[test]
-    fn test_one() -> Result<()> {
-        let answer = super::part_one(&TEST)?;
-        assert_eq!(answer, 7);
-        Ok(())
-    }
     #[test]
     fn test_one_1() -> Result<()> {
-        let answer = super::part_one("bvwbjplbgvbhsrlpgdmjqwftvncz")?;
+        let answer = TuningTrouble::solve_part_one("bvwbjplbgvbhsrlpgdmjqwftvncz")?;
         assert_eq!(answer, 5);
         Ok(())
     }
     #[test]
     fn test_one_2() -> Result<()> {
-        let answer = super::part_one("nppdvjthqldpwncqszvftbrmjlhg")?;
+        let answer = TuningTrouble::solve_part_one("nppdvjthqldpwncqszvftbrmjlhg")?;
         assert_eq!(answer, 6);
         Ok(())
     }
     #[test]
     fn test_one_3() -> Result<()> {
-        let answer = super::part_one("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg")?;
+        let answer = TuningTrouble::solve_part_one("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg")?;
         assert_eq!(answer, 10);
         Ok(())
     }
     #[test]
     fn test_one_4() -> Result<()> {
-        let answer = super::part_one("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw")?;
+        let answer = TuningTrouble::solve_part_one("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw")?;
         assert_eq!(answer, 11);
         Ok(())
     }
 
-    // Use "cargo test --release -- part_one --nocapture" to print the time
-    #[test]
-    fn part_one() -> Result<()> {
-        let t = std::time::Instant::now();
-        let answer = super::part_one(&INPUT)?;
-        eprintln!("Part one took {:0.2?}", t.elapsed());
-        assert_eq!(answer, 1198);
-        Ok(())
-    }
-
-    #[test]
-    fn test_two() -> Result<()> {
-        let answer = super::part_two(&TEST)?;
-        assert_eq!(answer, 19);
-        Ok(())
-    }
     #[test]
     fn test_two_1() -> Result<()> {
-        let answer = super::part_two("bvwbjplbgvbhsrlpgdmjqwftvncz")?;
+        let answer = TuningTrouble::solve_part_two("bvwbjplbgvbhsrlpgdmjqwftvncz")?;
         assert_eq!(answer, 23);
         Ok(())
     }
     #[test]
     fn test_two_2() -> Result<()> {
-        let answer = super::part_two("nppdvjthqldpwncqszvftbrmjlhg")?;
+        let answer = TuningTrouble::solve_part_two("nppdvjthqldpwncqszvftbrmjlhg")?;
         assert_eq!(answer, 23);
         Ok(())
     }
     #[test]
     fn test_two_3() -> Result<()> {
-        let answer = super::part_two("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg")?;
+        let answer = TuningTrouble::solve_part_two("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg")?;
         assert_eq!(answer, 29);
         Ok(())
     }
     #[test]
     fn test_two_4() -> Result<()> {
-        let answer = super::part_two("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw")?;
+        let answer = TuningTrouble::solve_part_two("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw")?;
         assert_eq!(answer, 26);
         Ok(())
     }
 
-    // Use "cargo test --release part_two -- --nocapture" to print the time
     #[test]
-    fn part_two() -> Result<()> {
-        let t = std::time::Instant::now();
-        let answer = super::part_two(&INPUT)?;
-        eprintln!("Part two took {:0.2?}", t.elapsed());
-        assert_eq!(answer, 3120);
+    fn part_two_slow() -> Result<()> {
+        let answer = super::part_two_slow(&TEST)?;
+        assert_eq!(answer, 19);
         Ok(())
     }
+
+    // signals from a few letters, so some have markers and some don't
+    fn signals() -> impl Strategy<Value = String> {
+        (4u8..=20).prop_flat_map(|letters| {
+            prop::collection::vec(0..letters, 0..80)
+                .prop_map(|signal| signal.into_iter().map(|c| (b'a' + c) as char).collect())
+        })
+    }
+
     #[test]
-    fn part_two_slow() -> Result<()> {
-        let t = std::time::Instant::now();
-        let answer = super::part_two_slow(&INPUT)?;
-        eprintln!("Part two slow took {:0.2?}", t.elapsed());
-        assert_eq!(answer, 3120);
+    fn find_marker_agrees_with_slow() {
+        let markers = |find: fn(usize, &[char]) -> Result<usize>| {
+            move |signal: &str| {
+                let signal: Vec<char> = signal.chars().collect();
+                [4, 14].map(|marker_len| find(marker_len, &signal).ok())
+            }
+        };
+        assert_agree(signals(), markers(find_marker), markers(find_marker_slow));
+    }
+
+    #[test]
+    fn worst_case() -> Result<()> {
+        for (_, worst_case) in TuningTrouble::worst_cases() {
+            let answer = TuningTrouble::solve_part_two(&worst_case)?;
+            let answer_slow = super::part_two_slow(&worst_case)?;
+            assert_eq!(answer, Answer::from(answer_slow));
+        }
         Ok(())
     }
 }
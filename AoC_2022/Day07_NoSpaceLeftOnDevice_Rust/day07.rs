@@ -0,0 +1,216 @@
+use anyhow::{Context, Result};
+use aoc_core::{input, parse, Answer, ParseError, Solution};
+use lazy_static::lazy_static;
+use log::{log_enabled, trace, Level};
+use regex::Regex;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::rc::{Rc, Weak};
+
+#[derive(Debug)]
+struct File {
+    name: String,
+    size: usize,
+}
+
+#[derive(Debug)]
+struct Directory {
+    name: String,
+    files: HashMap<String, File>,
+    parent_dir: Weak<RefCell<Directory>>,
+    sub_dirs: HashMap<String, Rc<RefCell<Directory>>>,
+}
+
+impl Directory {
+    fn new(name: String) -> Directory {
+        Directory {
+            name,
+            files: HashMap::new(),
+            parent_dir: Weak::new(),
+            sub_dirs: HashMap::new(),
+        }
+    }
+    fn total_size(&self) -> usize {
+        let mut sum: usize = self.files.values().map(|f| f.size).sum();
+        for (_, dir) in self.sub_dirs.iter() {
+            sum += dir.borrow().total_size();
+        }
+        sum
+    }
+    fn render(&self, prefix_spaces: usize, out: &mut String) {
+        let prefix = " ".repeat(prefix_spaces);
+        let prefix_contained = " ".repeat(prefix_spaces + 2);
+        let _ = writeln!(out, "{}{} (dir)", &prefix, self.name);
+        for (_, file) in self.files.iter() {
+            let _ = writeln!(
+                out,
+                "{}{} (file, size={})",
+                &prefix_contained, file.name, file.size
+            );
+        }
+        for (_, dir) in self.sub_dirs.iter() {
+            dir.borrow().render(prefix_spaces + 2, out);
+        }
+    }
+    fn sum_of_smaller_dirs(&self, inclusive_threshold: usize) -> usize {
+        let mut sum = 0;
+        for (_, subdir) in self.sub_dirs.iter() {
+            let subdir_size = subdir.borrow().total_size();
+            if subdir_size <= inclusive_threshold {
+                sum += subdir_size;
+            }
+            sum += subdir.borrow().sum_of_smaller_dirs(inclusive_threshold);
+        }
+        sum
+    }
+    fn get_dir_sizes_suitable_for_deletion(&self, min_to_del: usize, vec_to_fill: &mut Vec<usize>) {
+        let size = self.total_size();
+        if size >= min_to_del {
+            vec_to_fill.push(size);
+        }
+        for (_, subdir) in self.sub_dirs.iter() {
+            subdir
+                .borrow()
+                .get_dir_sizes_suitable_for_deletion(min_to_del, vec_to_fill);
+        }
+    }
+    // fn get_foo(w: Weak<RefCell<Directory>>) -> Directory {
+    // }
+}
+
+pub struct TaskData {
+    root: Rc<RefCell<Directory>>,
+}
+
+fn parse_input(input: &str) -> Result<TaskData, ParseError> {
+    lazy_static! {
+        static ref CD_RE: Regex = Regex::new(r"^\$ cd (.+)$").unwrap();
+        static ref LS_RE: Regex = Regex::new(r"^\$ ls$").unwrap();
+        static ref DIR_RE: Regex = Regex::new(r"^dir (.+)$").unwrap();
+        static ref FILE_RE: Regex = Regex::new(r"^(\d+) (.+)$").unwrap();
+    }
+    let root = Rc::new(RefCell::new(Directory::new(String::from("/"))));
+    let mut current = Rc::downgrade(&root);
+    let mut lines = input::lines(input);
+    let first = parse::next(&mut lines, input, "$ cd /")?;
+    if first != "$ cd /" {
+        return Err(ParseError::at(input, first, "$ cd /"));
+    }
+    for line in lines {
+        // every directory but the root is owned by its parent
+        let rc_current = current.upgrade().unwrap();
+        if let Some(caps) = CD_RE.captures(line) {
+            // change current
+            match &caps[1] {
+                ".." => {
+                    let parent = &rc_current.borrow().parent_dir;
+                    if parent.upgrade().is_none() {
+                        return Err(ParseError::at(input, &caps[1], "a directory below /"));
+                    }
+                    current = Weak::clone(parent);
+                }
+                name => {
+                    let subdir = rc_current.borrow().sub_dirs.get(name).map(Rc::downgrade);
+                    current = subdir
+                        .ok_or_else(|| ParseError::at(input, name, "a directory listed before"))?;
+                }
+            }
+        } else if LS_RE.is_match(line) {
+            // do nothing
+        } else if let Some(caps) = DIR_RE.captures(line) {
+            // add dir to current
+            let name = String::from(&caps[1]);
+            let new_dir = Rc::new(RefCell::new(Directory::new(name.clone())));
+            new_dir.borrow_mut().parent_dir = Rc::downgrade(&rc_current);
+            rc_current
+                .borrow_mut()
+                .sub_dirs
+                .insert(name.clone(), new_dir);
+        } else if let Some(caps) = FILE_RE.captures(line) {
+            // add file to current
+            let size = parse::value(input, &caps[1], "a file size")?;
+            let name = String::from(&caps[2]);
+            rc_current
+                .borrow_mut()
+                .files
+                .insert(name.clone(), File { name, size });
+        } else {
+            return Err(ParseError::at(
+                input,
+                line,
+                "a command, a directory or a file",
+            ));
+        }
+    }
+    Ok(TaskData { root })
+}
+
+aoc_core::params! {
+    pub struct Params {
+        /// The size up to which part one sums the directories
+        at_most: usize = 100_000,
+        disk: usize = 70_000_000,
+        /// The free space the update needs
+        need_free: usize = 30_000_000,
+    }
+}
+
+pub struct NoSpaceLeftOnDevice;
+
+impl Solution for NoSpaceLeftOnDevice {
+    type Input = TaskData;
+
+    fn parse_input(input: &str) -> Result<TaskData, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(TaskData { root }: Self::Input) -> Result<Answer> {
+        if log_enabled!(Level::Trace) {
+            let mut tree = String::new();
+            root.borrow().render(0, &mut tree);
+            trace!("\n{}", tree);
+        }
+        let answer = root.borrow().sum_of_smaller_dirs(Params::get()?.at_most);
+        Ok(Answer::from(answer))
+    }
+
+    fn part_two(TaskData { root }: Self::Input) -> Result<Answer> {
+        let Params {
+            disk, need_free, ..
+        } = Params::get()?;
+        let total_size = root.borrow().total_size();
+        let current_free = disk.saturating_sub(total_size);
+        let min_space_to_free = need_free.saturating_sub(current_free);
+        let mut suitable_for_deletion = Vec::new();
+        root.borrow()
+            .get_dir_sizes_suitable_for_deletion(min_space_to_free, &mut suitable_for_deletion);
+        let smallest = suitable_for_deletion
+            .into_iter()
+            .min()
+            .context("not even deleting everything frees enough space")?;
+        Ok(Answer::from(smallest))
+    }
+
+    fn params() -> Vec<(&'static str, String)> {
+        Params::defaults()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_error_points_at_the_line() {
+        let input = "$ cd /\r\n$ ls\r\ndir a\r\n$ cd b\r\n";
+        let err = NoSpaceLeftOnDevice::parse_input(input).err().unwrap();
+        assert_eq!((err.line, err.column), (4, 6));
+        assert_eq!(err.found, "b");
+        let err = NoSpaceLeftOnDevice::parse_input("$ cd /\n$ ls\n12ab c\n")
+            .err()
+            .unwrap();
+        assert_eq!((err.line, err.column), (3, 1));
+        assert_eq!(err.expected, "a command, a directory or a file");
+    }
+}
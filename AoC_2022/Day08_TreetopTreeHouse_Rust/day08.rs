@@ -1,12 +1,10 @@
 use anyhow::Result;
+use aoc_core::{Answer, Grid, ParseError, Solution};
 use std::cmp;
 use std::collections::HashSet;
-use std::io::{self, Read};
 
-struct TaskData {
-    tree_grid: Vec<Vec<i128>>,
-    rows: usize,
-    cols: usize,
+pub struct TaskData {
+    tree_grid: Grid<i128>,
 }
 
 enum Direction {
@@ -34,12 +32,12 @@ impl TaskData {
     ) -> i128 {
         use Direction::*;
         let (treehouse_row, treehouse_col) = treehouse;
-        let treehouse_height = self.tree_grid[treehouse_row][treehouse_col];
+        let treehouse_height = self.tree_grid[(treehouse_row, treehouse_col)];
         let range_vec: Vec<usize> = match direction {
             Up => (0..treehouse_row).rev().collect(),
-            Down => (treehouse_row + 1..self.rows).collect(),
+            Down => (treehouse_row + 1..self.tree_grid.rows()).collect(),
             Left => ((0..treehouse_col).rev()).collect(),
-            Right => (treehouse_col + 1..self.cols).collect(),
+            Right => (treehouse_col + 1..self.tree_grid.cols()).collect(),
         };
         let mut visible_trees = 0;
         for i in range_vec.into_iter() {
@@ -47,7 +45,7 @@ impl TaskData {
                 Up | Down => (i, treehouse_col),
                 Left | Right => (treehouse_row, i),
             };
-            let current_tree = self.tree_grid[index.0][index.1];
+            let current_tree = self.tree_grid[index];
             visible_trees += 1;
             if current_tree >= treehouse_height {
                 break;
@@ -65,8 +63,8 @@ impl TaskData {
     }
     fn best_scenic_score(&self) -> i128 {
         let mut best_score = -1;
-        for row in 1..self.rows - 1 {
-            for col in 1..self.cols - 1 {
+        for row in 1..self.tree_grid.rows() - 1 {
+            for col in 1..self.tree_grid.cols() - 1 {
                 best_score = cmp::max(best_score, self.get_scenic_score((row, col)));
             }
         }
@@ -85,7 +83,7 @@ impl TaskData {
             let mut min_size = -1;
             for y in inner_iter.clone() {
                 let (row, col) = direction.to_row_col((x, y));
-                let current_tree = self.tree_grid[row][col];
+                let current_tree = self.tree_grid[(row, col)];
                 if counted.contains(&(row, col)) {
                     min_size = cmp::max(min_size, current_tree); // tree can be smaller!
                     continue;
@@ -101,7 +99,7 @@ impl TaskData {
     }
 
     fn count_visible_from_borders(&self) -> i128 {
-        let (rows, cols) = (self.rows, self.cols);
+        let (rows, cols) = (self.tree_grid.rows(), self.tree_grid.cols());
 
         // count from each side, don't count twice
         let mut counted: HashSet<(usize, usize)> = HashSet::new();
@@ -115,89 +113,25 @@ impl TaskData {
     }
 }
 
-fn parse_input(input: &str) -> Result<TaskData> {
-    let mut tree_grid = Vec::new();
-    for line in input.lines() {
-        let line_heights: Vec<i128> = line
-            .chars()
-            .map(|c| (c as u8 - '0' as u8) as i128)
-            .collect();
-        tree_grid.push(line_heights);
-    }
-    let rows = tree_grid.len();
-    let cols = tree_grid[0].len();
-    Ok(TaskData {
-        tree_grid,
-        rows,
-        cols,
-    })
-}
-
-fn part_one(input: &str) -> Result<i128> {
-    let data = parse_input(input)?;
-    Ok(data.count_visible_from_borders())
-}
-
-fn part_two(input: &str) -> Result<i128> {
-    let data = parse_input(input)?;
-    Ok(data.best_scenic_score())
-}
-
-fn main() -> Result<()> {
-    let mut input = String::new();
-    io::stdin().read_to_string(&mut input)?;
-    println!("Part one: {}", part_one(&input)?);
-    println!("Part two: {}", part_two(&input)?);
-    Ok(())
+fn parse_input(input: &str) -> Result<TaskData, ParseError> {
+    let tree_grid = Grid::parse(input, "a tree height", |c| c.to_digit(10).map(i128::from))?;
+    Ok(TaskData { tree_grid })
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use lazy_static::lazy_static;
-    use std::fs;
-
-    lazy_static! {
-        static ref TEST: String = read_from_file("test.txt");
-        static ref INPUT: String = read_from_file("input.txt");
-    }
-
-    fn read_from_file(filename: &str) -> String {
-        fs::read_to_string(filename)
-            .unwrap_or_else(|msg| panic!("error reading {}: {}", filename, msg))
-    }
+pub struct TreetopTreeHouse;
 
-    #[test]
-    fn test_one() -> Result<()> {
-        let answer = super::part_one(&TEST)?;
-        assert_eq!(answer, 21);
-        Ok(())
-    }
+impl Solution for TreetopTreeHouse {
+    type Input = TaskData;
 
-    // Use "cargo test --release -- part_one --nocapture" to print the time
-    #[test]
-    fn part_one() -> Result<()> {
-        let t = std::time::Instant::now();
-        let answer = super::part_one(&INPUT)?;
-        eprintln!("Part one took {:0.2?}", t.elapsed());
-        assert_eq!(answer, 1690);
-        Ok(())
+    fn parse_input(input: &str) -> Result<TaskData, ParseError> {
+        parse_input(input)
     }
 
-    #[test]
-    fn test_two() -> Result<()> {
-        let answer = super::part_two(&TEST)?;
-        assert_eq!(answer, 8);
-        Ok(())
+    fn part_one(data: Self::Input) -> Result<Answer> {
+        Ok(Answer::from(data.count_visible_from_borders()))
     }
 
-    // Use "cargo test --release -- part_two --nocapture" to print the time
-    #[test]
-    fn part_two() -> Result<()> {
-        let t = std::time::Instant::now();
-        let answer = super::part_two(&INPUT)?;
-        eprintln!("Part two took {:0.2?}", t.elapsed());
-        assert_eq!(answer, 535680);
-        Ok(())
+    fn part_two(data: Self::Input) -> Result<Answer> {
+        Ok(Answer::from(data.best_scenic_score()))
     }
 }
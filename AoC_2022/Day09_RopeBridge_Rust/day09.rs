@@ -0,0 +1,155 @@
+use anyhow::Result;
+use aoc_core::parse::{self, Within};
+use aoc_core::{input, record, Answer, Direction, ParseError, Solution, Vec2};
+use log::{log, log_enabled, Level};
+use std::collections::HashSet;
+
+#[derive(Copy, Clone)]
+struct RopeMove {
+    direction: Direction,
+    amount: i128,
+}
+
+impl RopeMove {
+    fn parse(input: &str, line: &str) -> Result<Self, ParseError> {
+        let mut splits = line.split(' ');
+        let direction = parse::next(&mut splits, line, "a direction").within(input, line)?;
+        let amount = parse::next(&mut splits, line, "an amount").within(input, line)?;
+        if let Some(rest) = splits.next() {
+            return Err(ParseError::at(input, rest, "the end of the line"));
+        }
+        let amount = parse::value(input, amount, "an amount")?;
+        let direction = match direction {
+            "U" => Direction::Up,
+            "D" => Direction::Down,
+            "L" => Direction::Left,
+            "R" => Direction::Right,
+            _ => return Err(ParseError::at(input, direction, "one of U, D, L or R")),
+        };
+        Ok(RopeMove { direction, amount })
+    }
+}
+
+pub struct TaskData {
+    rope_moves: Vec<RopeMove>,
+    rope_knots: Vec<Vec2>,
+    set_tail_pos: HashSet<Vec2>,
+}
+
+impl TaskData {
+    // the visited tail positions with the rope on top
+    fn render(&self) -> String {
+        let points = self
+            .rope_knots
+            .iter()
+            .chain(self.set_tail_pos.iter())
+            .copied();
+        let (min, max) = Vec2::bounding_box(points.chain([Vec2::default()])).unwrap_or_default();
+        let mut out = String::new();
+        for y in min.y..=max.y {
+            for x in min.x..=max.x {
+                let pos = Vec2::new(x, y);
+                match self.rope_knots.iter().position(|knot| *knot == pos) {
+                    Some(0) => out.push('H'),
+                    Some(i) => out.push_str(&i.to_string()),
+                    None if pos == Vec2::default() => out.push('s'),
+                    None if self.set_tail_pos.contains(&pos) => out.push('#'),
+                    None => out.push('.'),
+                }
+            }
+            out.push('\n');
+        }
+        out
+    }
+
+    fn log_knots(&self, level: Level) {
+        if log_enabled!(level) {
+            log!(level, "{:?}\n{}", self.rope_knots, self.render());
+        }
+    }
+
+    fn count_tail_pos(&self) -> i128 {
+        self.set_tail_pos.len() as i128
+    }
+
+    fn setup_knots(&mut self, amount: i128) {
+        for _ in 0..amount {
+            self.rope_knots.push(Vec2::default());
+        }
+    }
+
+    // a knot that isn't touching its predecessor anymore takes one step
+    // towards it, diagonally if they aren't in the same row or column
+    fn update_knot(current: Vec2, target: Vec2) -> Vec2 {
+        if current.chebyshev(&target) > 1 {
+            current + (target - current).signum()
+        } else {
+            current
+        }
+    }
+
+    fn update_tail(&mut self) {
+        let last_i = self.rope_knots.len() - 1;
+        for i in 1..=last_i {
+            let target = self.rope_knots[i - 1];
+            let current = self.rope_knots[i];
+            self.rope_knots[i] = Self::update_knot(current, target);
+            if i == last_i {
+                self.set_tail_pos.insert(self.rope_knots[i]);
+            }
+        }
+    }
+    fn execute_move(&mut self, rope_move: &RopeMove) {
+        for _ in 0..rope_move.amount {
+            self.rope_knots[0] += rope_move.direction.offset();
+            self.update_tail();
+            self.log_knots(Level::Trace);
+            record::frame(|| self.render());
+        }
+    }
+    fn execute_all(&mut self) {
+        self.log_knots(Level::Trace);
+        let rope_moves = self.rope_moves.clone();
+        for rope_move in rope_moves.iter() {
+            self.execute_move(rope_move);
+        }
+    }
+}
+
+fn parse_input(input: &str) -> Result<TaskData, ParseError> {
+    let rope_moves = input::lines(input)
+        .map(|l| RopeMove::parse(input, l))
+        .collect::<Result<Vec<_>, _>>()?;
+    let mut set_tail_pos: HashSet<Vec2> = HashSet::new();
+    let rope_knots = Vec::new();
+    set_tail_pos.insert(Vec2::default());
+    Ok(TaskData {
+        rope_moves,
+        rope_knots,
+        set_tail_pos,
+    })
+}
+
+pub struct RopeBridge;
+
+impl Solution for RopeBridge {
+    type Input = TaskData;
+
+    fn parse_input(input: &str) -> Result<TaskData, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(mut data: Self::Input) -> Result<Answer> {
+        data.setup_knots(2);
+        data.execute_all();
+        data.log_knots(Level::Debug);
+        Ok(Answer::from(data.count_tail_pos()))
+    }
+
+    fn part_two(mut data: Self::Input) -> Result<Answer> {
+        data.setup_knots(10);
+        data.execute_all();
+        data.log_knots(Level::Debug);
+        Ok(Answer::from(data.count_tail_pos()))
+    }
+}
@@ -0,0 +1,149 @@
+use anyhow::Result;
+use aoc_core::{input, ocr, parse, Answer, ParseError, Solution};
+use lazy_static::lazy_static;
+use log::{debug, trace};
+use regex::Regex;
+
+#[derive(Debug, Copy, Clone)]
+enum Instruction {
+    AddX(i128),
+    Noop,
+}
+
+impl Instruction {
+    fn parse(input: &str, line: &str) -> Result<Self, ParseError> {
+        lazy_static! {
+            static ref ADDX: Regex = Regex::new(r"^addx (-?\d+)$").unwrap();
+            static ref NOOP: Regex = Regex::new(r"^noop$").unwrap();
+        }
+        if NOOP.is_match(line) {
+            Ok(Self::Noop)
+        } else {
+            let caps = parse::captures(&ADDX, input, line)?;
+            let amount = parse::value(input, &caps[1], "an amount")?;
+            Ok(Self::AddX(amount))
+        }
+    }
+    fn modify(&self, x: i128) -> i128 {
+        match self {
+            Self::AddX(amount) => x + amount,
+            Self::Noop => x,
+        }
+    }
+}
+
+pub struct TaskData {
+    completed_cycles: i128,
+    current_x: i128,
+    instructions: Vec<Instruction>,
+    target_cycles: Vec<i128>,
+    target_x: Vec<i128>,
+    render_pos: i128,
+    crt: Vec<String>,
+}
+
+impl TaskData {
+    fn cycles(instruction: &Instruction) -> i128 {
+        match instruction {
+            &Instruction::AddX(_) => 2,
+            _ => 1,
+        }
+    }
+    fn sum_targets(&self) -> i128 {
+        self.target_x.iter().sum()
+    }
+    fn is_target_cycle(&self, cycle_before: i128, cycle_after: i128) -> (bool, i128) {
+        for &target in self.target_cycles.iter() {
+            if cycle_before == target || cycle_before < target && cycle_after > target {
+                return (true, target);
+            }
+        }
+        (false, 0)
+    }
+    fn execute_instruction(&mut self, instruction: &Instruction) {
+        trace!(
+            "{:?} before cycles: {}, x: {}",
+            instruction,
+            self.completed_cycles,
+            self.current_x
+        );
+        let new_completed_cycles = self.completed_cycles + Self::cycles(instruction);
+        let (cond, i) = self.is_target_cycle(self.completed_cycles, new_completed_cycles);
+        if cond {
+            self.target_x.push(self.current_x * i);
+        }
+        for _ in self.completed_cycles..new_completed_cycles {
+            if self.render_pos == 0 {
+                self.crt.push(String::new());
+            }
+            let pixel = if (self.render_pos - self.current_x).abs() <= 1 {
+                '#'
+            } else {
+                '.'
+            };
+            if let Some(row) = self.crt.last_mut() {
+                row.push(pixel);
+            }
+            self.render_pos += 1;
+            if self.render_pos % 40 == 0 {
+                self.render_pos = 0;
+            }
+        }
+        self.current_x = instruction.modify(self.current_x);
+        self.completed_cycles = new_completed_cycles;
+    }
+    fn execute(&mut self) {
+        let instructions = self.instructions.clone();
+        for instruction in instructions.iter() {
+            self.execute_instruction(instruction);
+        }
+    }
+}
+
+fn parse_input(input: &str) -> Result<TaskData, ParseError> {
+    let instructions = input::lines(input)
+        .map(|l| Instruction::parse(input, l))
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(TaskData {
+        completed_cycles: 1,
+        current_x: 1,
+        instructions,
+        target_cycles: Vec::new(),
+        target_x: Vec::new(),
+        render_pos: 0,
+        crt: Vec::new(),
+    })
+}
+
+aoc_core::params! {
+    pub struct Params {
+        /// The cycles whose signal strengths part one sums
+        cycles: Vec<i128> = vec![20, 60, 100, 140, 180, 220],
+    }
+}
+
+pub struct CathodeRayTube;
+
+impl Solution for CathodeRayTube {
+    type Input = TaskData;
+
+    fn parse_input(input: &str) -> Result<TaskData, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(mut data: Self::Input) -> Result<Answer> {
+        data.target_cycles = Params::get()?.cycles;
+        data.execute();
+        debug!("x at the target cycles: {:?}", data.target_x);
+        Ok(Answer::from(data.sum_targets()))
+    }
+
+    fn part_two(mut data: Self::Input) -> Result<Answer> {
+        data.execute();
+        Ok(ocr::answer(data.crt))
+    }
+
+    fn params() -> Vec<(&'static str, String)> {
+        Params::defaults()
+    }
+}
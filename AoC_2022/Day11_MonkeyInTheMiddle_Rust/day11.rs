@@ -0,0 +1,200 @@
+use anyhow::Result;
+use aoc_core::parse::{self, Within};
+use aoc_core::{input, Answer, ParseError, Solution};
+use lazy_static::lazy_static;
+use log::debug;
+use regex::Regex;
+use std::collections::VecDeque;
+
+struct Monkey {
+    num: usize,
+    items: VecDeque<i128>,
+    worry_fn: Box<dyn Fn(i128) -> i128>,
+    divisor_for_test: i128,
+    throw_true: usize,
+    throw_false: usize,
+    inspection_count: i128,
+}
+
+impl Monkey {
+    fn debug(&self) {
+        debug!(
+            "Monkey {}: items {:?}, test {}, true {}, false {}",
+            self.num, self.items, self.divisor_for_test, self.throw_true, self.throw_false
+        );
+    }
+}
+
+impl Monkey {
+    fn parse(input: &str, monkey_lines: &str) -> Result<Self, ParseError> {
+        // Monkey 0:
+        //   Starting items: 79, 98
+        //   Operation: new = old * 19
+        //   Test: divisible by 23
+        //     If true: throw to monkey 2
+        //     If false: throw to monkey 3
+        lazy_static! {
+            static ref MONKEY: Regex = Regex::new(r"^Monkey (\d+):$").unwrap();
+            static ref ITEMS: Regex = Regex::new(r"^  Starting items:((?: \d+,?)*)$").unwrap();
+            static ref ITEM: Regex = Regex::new(r"(\d+)").unwrap();
+            static ref OP: Regex =
+                Regex::new(r"^  Operation: new = (old|\d+) ([+*]) (old|\d+)$").unwrap();
+            static ref TEST: Regex = Regex::new(r"^  Test: divisible by (\d+)$").unwrap();
+            static ref TRUE: Regex = Regex::new(r"^    If true: throw to monkey (\d+)$").unwrap();
+            static ref FALSE: Regex = Regex::new(r"^    If false: throw to monkey (\d+)$").unwrap();
+        }
+        let mut line_it = input::lines(monkey_lines);
+        let mut next_line = |re: &Regex| {
+            let line = parse::next(&mut line_it, monkey_lines, "another monkey line")
+                .within(input, monkey_lines)?;
+            parse::captures(re, input, line)
+        };
+
+        // Name
+        let caps = next_line(&MONKEY)?;
+        let monkey_num = parse::value(input, &caps[1], "a monkey number")?;
+
+        // Items
+        let caps = next_line(&ITEMS)?;
+        let mut monkey_items: VecDeque<i128> = VecDeque::new();
+        for item in ITEM.find_iter(caps.get(1).map_or("", |m| m.as_str())) {
+            monkey_items.push_back(parse::value(input, item.as_str(), "a worry level")?);
+        }
+
+        // OP
+        let caps = next_line(&OP)?;
+        let op1 = &caps[1];
+        let op = &caps[2];
+        let op2 = &caps[3];
+        let worry_fn: Box<dyn Fn(i128) -> i128> = match (op1, op, op2) {
+            ("old", "+", "old") => Box::new(move |x| x + x),
+            ("old", "*", "old") => Box::new(move |x| x * x),
+            ("old", "+", s) => {
+                let op2 = parse::value::<i128>(input, s, "a number")?;
+                Box::new(move |x| x + op2)
+            }
+            ("old", "*", s) => {
+                let op2 = parse::value::<i128>(input, s, "a number")?;
+                Box::new(move |x| x * op2)
+            }
+            _ => return Err(ParseError::at(input, op1, "old")),
+        };
+
+        // TEST
+        let caps = next_line(&TEST)?;
+        let divisor_for_test = parse::value(input, &caps[1], "a divisor")?;
+        let caps = next_line(&TRUE)?;
+        let throw_true = parse::value(input, &caps[1], "a monkey number")?;
+        let caps = next_line(&FALSE)?;
+        let throw_false = parse::value(input, &caps[1], "a monkey number")?;
+
+        Ok(Monkey {
+            num: monkey_num,
+            items: monkey_items,
+            worry_fn,
+            divisor_for_test,
+            throw_true,
+            throw_false,
+            inspection_count: 0,
+        })
+    }
+}
+
+pub struct TaskData {
+    current_monkey: usize,
+    monkeys: Vec<Monkey>,
+    round: usize,
+}
+
+impl TaskData {
+    fn get_worry_modulus(&self) -> i128 {
+        self.monkeys.iter().map(|m| m.divisor_for_test).product()
+    }
+    fn inspect_items_until_rounds(&mut self, rounds: usize, part2: bool) {
+        let worry_modulus = self.get_worry_modulus();
+        loop {
+            let current = self.current_monkey;
+            while let Some(item) = self.monkeys[current].items.pop_front() {
+                let func = self.monkeys[current].worry_fn.as_ref();
+                let mut new_worry = func(item);
+                if part2 {
+                    new_worry %= worry_modulus; // multiplied the divtests together manually
+                } else {
+                    new_worry /= 3;
+                }
+
+                let throw_to = if new_worry % self.monkeys[current].divisor_for_test == 0 {
+                    self.monkeys[current].throw_true
+                } else {
+                    self.monkeys[current].throw_false
+                };
+                self.monkeys[throw_to].items.push_back(new_worry);
+                self.monkeys[current].inspection_count += 1;
+            }
+            self.current_monkey += 1;
+            if self.current_monkey.is_multiple_of(self.monkeys.len()) {
+                self.current_monkey = 0;
+                self.round += 1;
+                if self.round == rounds {
+                    return;
+                }
+            }
+        }
+    }
+}
+
+fn parse_input(input: &str) -> Result<TaskData, ParseError> {
+    let monkeys = input::blocks(input)
+        .into_iter()
+        .map(|block| Monkey::parse(input, block))
+        .collect::<Result<_, _>>()?;
+    Ok(TaskData {
+        current_monkey: 0,
+        monkeys,
+        round: 0,
+    })
+}
+
+aoc_core::params! {
+    pub struct Params {
+        /// The rounds of part one, worrying less after every inspection
+        rounds_one: usize = 20,
+        rounds_two: usize = 10_000,
+    }
+}
+
+pub struct MonkeyInTheMiddle;
+
+impl Solution for MonkeyInTheMiddle {
+    type Input = TaskData;
+
+    fn parse_input(input: &str) -> Result<TaskData, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(mut data: Self::Input) -> Result<Answer> {
+        for m in data.monkeys.iter() {
+            m.debug();
+        }
+        data.inspect_items_until_rounds(Params::get()?.rounds_one, false);
+        let mut r: Vec<i128> = data.monkeys.iter().map(|m| m.inspection_count).collect();
+        r.sort_by(|a, b| b.cmp(a));
+        debug!("inspection counts {:?}", r);
+        Ok(Answer::from(r[0] * r[1]))
+    }
+
+    fn part_two(mut data: Self::Input) -> Result<Answer> {
+        for m in data.monkeys.iter() {
+            m.debug();
+        }
+        data.inspect_items_until_rounds(Params::get()?.rounds_two, true);
+        let mut r: Vec<i128> = data.monkeys.iter().map(|m| m.inspection_count).collect();
+        r.sort_by(|a, b| b.cmp(a));
+        debug!("inspection counts {:?}", r);
+        Ok(Answer::from(r[0] * r[1]))
+    }
+
+    fn params() -> Vec<(&'static str, String)> {
+        Params::defaults()
+    }
+}
@@ -0,0 +1,91 @@
+use anyhow::{Context, Result};
+use aoc_core::grid::Pos;
+use aoc_core::{search, Answer, Grid, ParseError, Solution};
+use log::{debug, trace};
+
+pub struct TaskData {
+    field: Grid<char>,
+    start: Pos,
+    end: Pos,
+}
+
+impl TaskData {
+    fn step_possible_char(from: char, to: char) -> bool {
+        let f = from as u8;
+        let t = to as u8;
+        f >= t || t == f + 1
+    }
+    fn step_possible(&self, from: Pos, to: Pos) -> bool {
+        let mut from_field = self.field[from];
+        let mut to_field = self.field[to];
+        if from_field == 'S' {
+            from_field = 'a';
+        }
+        if to_field == 'E' {
+            to_field = 'z';
+        }
+
+        let ans = Self::step_possible_char(from_field, to_field);
+        trace!("{} -> {}: {}", from_field, to_field, ans);
+        ans
+    }
+    fn neighbors(&self, pos: Pos) -> Vec<Pos> {
+        self.field
+            .neighbors4(pos)
+            .filter(|&to| self.step_possible(pos, to))
+            .collect()
+    }
+    fn min_steps_to_best(&self, starts: impl IntoIterator<Item = Pos>) -> Option<i128> {
+        let path = search::bfs(starts, |&pos| self.neighbors(pos), |&pos| pos == self.end)?;
+        Some(path.cost as i128)
+    }
+    fn min_best_from_a_or_s(&self) -> Option<i128> {
+        let lowest = self
+            .field
+            .positions()
+            .filter(|&pos| self.field[pos] == 'a' || pos == self.start);
+        self.min_steps_to_best(lowest)
+    }
+}
+
+fn parse_input(input: &str) -> Result<TaskData, ParseError> {
+    let field = Grid::parse(input, "an elevation, S or E", |c| {
+        (c.is_ascii_lowercase() || c == 'S' || c == 'E').then_some(c)
+    })?;
+    let mut start = (0, 0);
+    let mut end = (0, 0);
+    for pos in field.positions() {
+        if field[pos] == 'S' {
+            start = pos;
+        }
+        if field[pos] == 'E' {
+            end = pos;
+        }
+    }
+    debug!("start {:?}, end {:?}", start, end);
+    Ok(TaskData { field, start, end })
+}
+
+pub struct HillClimbingAlgorithm;
+
+impl Solution for HillClimbingAlgorithm {
+    type Input = TaskData;
+
+    fn parse_input(input: &str) -> Result<TaskData, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(data: Self::Input) -> Result<Answer> {
+        let steps = data
+            .min_steps_to_best([data.start])
+            .context("there is no way to the best signal")?;
+        Ok(Answer::from(steps))
+    }
+
+    fn part_two(data: Self::Input) -> Result<Answer> {
+        let steps = data
+            .min_best_from_a_or_s()
+            .context("there is no way to the best signal")?;
+        Ok(Answer::from(steps))
+    }
+}
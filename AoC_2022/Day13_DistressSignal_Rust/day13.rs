@@ -0,0 +1,193 @@
+use anyhow::Result;
+use aoc_core::parse::{self, Within};
+use aoc_core::{input, Answer, ParseError, Solution};
+use log::{debug, trace};
+use std::cmp::Ordering;
+//use std::collections::VecDeque;
+
+#[derive(Debug, PartialEq, Eq)]
+enum Packet {
+    Elem(i128),
+    List(Vec<Packet>),
+}
+
+fn cmp_slice(l: &[Packet], r: &[Packet]) -> Ordering {
+    let mut pos = 0;
+    loop {
+        let opl = l.get(pos);
+        let opr = r.get(pos);
+        match (opl, opr) {
+            (None, None) => return Ordering::Equal,
+            (Some(_), None) => return Ordering::Greater,
+            (None, Some(_)) => return Ordering::Less,
+            (Some(pl), Some(pr)) => match pl.cmp(pr) {
+                Ordering::Equal => pos += 1,
+                ord => return ord,
+            },
+        }
+    }
+}
+
+impl PartialOrd for Packet {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Packet {
+    fn cmp(&self, other: &Self) -> Ordering {
+        trace!("Compare: {:?}, {:?}", self, other);
+        match (self, other) {
+            (Packet::Elem(l), Packet::Elem(r)) => l.cmp(r),
+            (Packet::List(l), Packet::List(r)) => cmp_slice(l, r),
+            (Packet::Elem(l), r) => Packet::List(vec![Packet::Elem(*l)]).cmp(r),
+            (l, Packet::Elem(r)) => l.cmp(&Packet::List(vec![Packet::Elem(*r)])),
+        }
+    }
+}
+
+impl Packet {
+    fn parse(input: &str, packet_line: &str) -> Result<Self, ParseError> {
+        // only ASCII is valid, so everything up to pos is a char boundary
+        let bytes = packet_line.as_bytes();
+        let mut pos = 0;
+        let mut stack: Vec<Vec<Packet>> = Vec::new();
+        let finish = |packet: Packet, pos: usize| {
+            if pos < bytes.len() {
+                Err(ParseError::at(
+                    input,
+                    &packet_line[pos..],
+                    "the end of the packet",
+                ))
+            } else {
+                Ok(packet)
+            }
+        };
+        while pos < bytes.len() {
+            match bytes[pos] {
+                b'[' => {
+                    stack.push(Vec::new());
+                    pos += 1;
+                }
+                b'0'..=b'9' => {
+                    let len = bytes[pos..]
+                        .iter()
+                        .take_while(|b| b.is_ascii_digit())
+                        .count();
+                    let elem = &packet_line[pos..pos + len];
+                    let elem = Packet::Elem(parse::value(input, elem, "an integer")?);
+                    pos += len;
+                    match stack.last_mut() {
+                        Some(list) => list.push(elem),
+                        None => return finish(elem, pos),
+                    }
+                }
+                b']' => {
+                    let finished = stack
+                        .pop()
+                        .map(Packet::List)
+                        .ok_or_else(|| ParseError::at(input, &packet_line[pos..], "["))?;
+                    pos += 1;
+                    match stack.last_mut() {
+                        Some(list) => list.push(finished),
+                        None => return finish(finished, pos),
+                    }
+                }
+                b',' => pos += 1, // can happen after ]
+                _ => {
+                    return Err(ParseError::at(
+                        input,
+                        &packet_line[pos..],
+                        "a bracket, a comma or an integer",
+                    ))
+                }
+            };
+        }
+        Err(ParseError::at(input, &packet_line[pos..], "]"))
+        // [[4,[4]],4]
+        // [ -> Vec<Packet> 1
+        //  [ -> Vec<Packet> 2
+        //   4 -> PacketElem for 2
+        //    , -> finish PacketElem for 2
+        //     [ -> Vec<Packet> 3
+        //      4 -> PacketElem for 3
+        //       ] -> finish PacketElem for 3, finish Vec<Packet> 3
+
+        // [42,[]]
+        // [ -> Vec<Packet> 1
+        //  4 -> PacketElem for 1
+        //   2 -> PacketElem for 1
+        //    , -> finish PacketElem for 1
+    }
+}
+
+#[derive(Debug)]
+pub struct TaskData {
+    packet_pairs: Vec<(Packet, Packet)>,
+    debug_pairs: Vec<(String, String)>,
+}
+
+impl TaskData {}
+
+fn parse_input(input: &str) -> Result<TaskData, ParseError> {
+    let mut packet_pairs = Vec::new();
+    let mut debug_pairs = Vec::new();
+    for packet_pair in input::blocks(input) {
+        let mut split = input::lines(packet_pair);
+        let first = parse::next(&mut split, packet_pair, "a packet").within(input, packet_pair)?;
+        let second = parse::next(&mut split, packet_pair, "a packet").within(input, packet_pair)?;
+        packet_pairs.push((Packet::parse(input, first)?, Packet::parse(input, second)?));
+        debug_pairs.push((String::from(first), String::from(second)));
+    }
+    Ok(TaskData {
+        packet_pairs,
+        debug_pairs,
+    })
+}
+
+pub struct DistressSignal;
+
+impl Solution for DistressSignal {
+    type Input = TaskData;
+
+    fn parse_input(input: &str) -> Result<TaskData, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(
+        TaskData {
+            packet_pairs,
+            debug_pairs,
+        }: Self::Input,
+    ) -> Result<Answer> {
+        let mut i_sum = 0;
+        for (i, (l, r)) in packet_pairs.iter().enumerate() {
+            let comp = l < r;
+            debug!("{} == {} < {}", comp, debug_pairs[i].0, debug_pairs[i].1);
+            if comp {
+                i_sum += i + 1
+            }
+        }
+        Ok(Answer::from(i_sum as i128))
+    }
+
+    fn part_two(TaskData { packet_pairs, .. }: Self::Input) -> Result<Answer> {
+        let a_divider_packet = Packet::List(vec![(Packet::List(vec![Packet::Elem(2)]))]);
+        let b_divider_packet = Packet::List(vec![(Packet::List(vec![Packet::Elem(6)]))]);
+        let mut all_packets = Vec::new();
+        for (l, r) in packet_pairs.iter() {
+            all_packets.push(l);
+            all_packets.push(r);
+        }
+        all_packets.push(&a_divider_packet);
+        all_packets.push(&b_divider_packet);
+        all_packets.sort();
+        let mut distress = 1;
+        for (i, &p) in all_packets.iter().enumerate() {
+            if p == &a_divider_packet || p == &b_divider_packet {
+                distress *= i + 1;
+            }
+        }
+        Ok(Answer::from(distress as i128))
+    }
+}
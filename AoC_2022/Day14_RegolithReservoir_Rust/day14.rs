@@ -0,0 +1,156 @@
+use anyhow::Result;
+use aoc_core::parse::{self, Within};
+use aoc_core::{input, record, Answer, ParseError, Solution, Vec2};
+use std::collections::HashMap;
+
+pub struct TaskData {
+    grid: HashMap<Vec2, char>,
+    lowest_rock: i128,
+    sand_start: Vec2,
+}
+
+// straight down first, then diagonally to the left and to the right
+const SAND_MOVES: [Vec2; 3] = [Vec2::new(0, 1), Vec2::new(-1, 1), Vec2::new(1, 1)];
+
+impl TaskData {
+    fn fill_cave(&mut self) {
+        loop {
+            let mut pos = self.sand_start;
+            loop {
+                let mut moved = false;
+                for delta in SAND_MOVES {
+                    let new_pos = pos + delta;
+                    if !self.grid.contains_key(&new_pos) {
+                        // grid only ever contains blocking, either O or #
+                        pos = new_pos;
+                        moved = true;
+                        if new_pos.y > self.lowest_rock {
+                            return; // cave is filled completely
+                        }
+                        break;
+                    }
+                }
+                if !moved {
+                    self.grid.insert(pos, 'O');
+                    record::frame(|| self.render());
+                    break; // inner loop -> new sand start
+                }
+            }
+        }
+    }
+    fn fill_cave2(&mut self) {
+        let bottom_y = self.lowest_rock + 2;
+        loop {
+            let mut pos = self.sand_start;
+            loop {
+                let mut moved = false;
+                for delta in SAND_MOVES {
+                    let new_pos = pos + delta;
+                    if !self.grid.contains_key(&new_pos) && new_pos.y < bottom_y {
+                        // grid only ever contains blocking, either O or #
+                        pos = new_pos;
+                        moved = true;
+                        break;
+                    }
+                }
+                if !moved {
+                    self.grid.insert(pos, 'O');
+                    record::frame(|| self.render());
+                    if pos == self.sand_start {
+                        return; // cave filled
+                    }
+                    break; // inner loop -> new sand start
+                }
+            }
+        }
+    }
+    fn render(&self) -> String {
+        let points = self.grid.keys().copied().chain([self.sand_start]);
+        let (min, max) = Vec2::bounding_box(points).unwrap_or_default();
+        let mut out = String::new();
+        for y in min.y..=max.y {
+            for x in min.x..=max.x {
+                let pos = Vec2::new(x, y);
+                out.push(match self.grid.get(&pos) {
+                    Some(&c) => c,
+                    None if pos == self.sand_start => '+',
+                    None => '.',
+                });
+            }
+            out.push('\n');
+        }
+        out
+    }
+    fn count_sand(&self) -> i128 {
+        self.grid.iter().filter(|(_k, v)| **v == 'O').count() as i128
+    }
+}
+
+// x right
+// y down
+
+// sand pouring from 500,0, 1/t only when prev rests, fall down 1 if poss, tile below blocked(rock/sand) -> diagonal (downleft, downright)
+fn parse_input(input: &str) -> Result<TaskData, ParseError> {
+    let mut grid: HashMap<Vec2, char> = HashMap::new();
+    for line in input::lines(input) {
+        let dests = line.split(" -> ");
+        let mut dest_points = Vec::new();
+        let mut dest_strs = Vec::new();
+        for dest in dests {
+            let mut xy = dest.split(',');
+            let x = parse::next(&mut xy, dest, "an x coordinate").within(input, dest)?;
+            let y = parse::next(&mut xy, dest, "a y coordinate").within(input, dest)?;
+            let x = parse::value::<i128>(input, x, "an x coordinate")?;
+            let y = parse::value::<i128>(input, y, "a y coordinate")?;
+            dest_points.push(Vec2::new(x, y));
+            dest_strs.push(dest);
+        }
+        for (i, ab) in dest_points.windows(2).enumerate() {
+            let (a, b) = (ab[0], ab[1]);
+            if a.x != b.x && a.y != b.y {
+                return Err(ParseError::at(
+                    input,
+                    dest_strs[i + 1],
+                    "a point on a straight line from the previous one",
+                ));
+            }
+            let step = (b - a).signum();
+            let mut pos = a;
+            grid.insert(pos, '#');
+            while pos != b {
+                pos += step;
+                grid.insert(pos, '#');
+            }
+        }
+    }
+    let lowest_rock = grid
+        .iter()
+        .map(|(pos, &c)| if c == '#' { pos.y } else { 0 })
+        .max()
+        .ok_or_else(|| ParseError::missing(input, "a path of rock"))?;
+    Ok(TaskData {
+        grid,
+        lowest_rock,
+        sand_start: Vec2::new(500, 0),
+    })
+}
+
+pub struct RegolithReservoir;
+
+impl Solution for RegolithReservoir {
+    type Input = TaskData;
+
+    fn parse_input(input: &str) -> Result<TaskData, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(mut data: Self::Input) -> Result<Answer> {
+        data.fill_cave();
+        Ok(Answer::from(data.count_sand()))
+    }
+
+    fn part_two(mut data: Self::Input) -> Result<Answer> {
+        data.fill_cave2();
+        Ok(Answer::from(data.count_sand()))
+    }
+}
@@ -0,0 +1,190 @@
+use anyhow::Result;
+use aoc_core::{input, parse, Answer, ParseError, Solution, Vec2};
+use lazy_static::lazy_static;
+use log::debug;
+use regex::Regex;
+use std::collections::HashMap;
+
+#[derive(Debug)]
+struct Sensor {
+    position: Vec2,
+    closest_beacon: Vec2,
+}
+impl Sensor {
+    fn parse(input: &str, line: &str) -> Result<Self, ParseError> {
+        lazy_static! {
+            static ref RE: Regex = Regex::new(
+                r"^Sensor at x=(-?\d+), y=(-?\d+): closest beacon is at x=(-?\d+), y=(-?\d+)$"
+            )
+            .unwrap();
+        }
+        let caps = parse::captures(&RE, input, line)?;
+        let pos_x = parse::value(input, &caps[1], "a coordinate")?;
+        let pos_y = parse::value(input, &caps[2], "a coordinate")?;
+        let beacon_x = parse::value(input, &caps[3], "a coordinate")?;
+        let beacon_y = parse::value(input, &caps[4], "a coordinate")?;
+        Ok(Sensor {
+            position: Vec2::new(pos_x, pos_y),
+            closest_beacon: Vec2::new(beacon_x, beacon_y),
+        })
+    }
+    fn distance_to_closest(&self) -> i128 {
+        self.position.manhattan(&self.closest_beacon)
+    }
+    fn in_closest_beacon_range(&self, position: Vec2) -> bool {
+        let distance_to_closest: i128 = self.distance_to_closest();
+        let distance_to_position: i128 = self.position.manhattan(&position);
+        distance_to_position <= distance_to_closest
+    }
+    fn in_closest_beacon_range_next_x(&self, position: Vec2) -> (bool, i128) {
+        let distance_to_closest: i128 = self.distance_to_closest();
+        let dx = self.position.x - position.x;
+        let dy = (self.position.y - position.y).abs();
+        // 9
+        // dx neg on the right
+        // dx dy
+        // max_off = 2(9-7) + 1
+        // abzug (9 - y - x)
+        // 0 9 -> +1 =
+
+        // 1 8 -> +3 = 2dx + 8-9 + 1
+        // 0 8 -> +2
+        // -1 8 -> +1 = -2 -1 + 1
+
+        // 2 7 -> +5
+        // 1 7 -> +4   max_off = 2(9-7) + 1 = 5, abzug = (9 - 7 - 1) = 1 daher max_off - abzug = 4
+        // 0 7 -> +3
+        // -1 7 -> +2
+        // -2 7 -> +1
+        let max_off = 2 * (distance_to_closest - dy) + 1;
+        let abzug = distance_to_closest - dy - dx;
+        //             #
+        //            ###
+        //           #####
+        //          #######
+        // usw
+        let next = max_off - abzug;
+        let distance_to_position: i128 = self.position.manhattan(&position);
+        (distance_to_position <= distance_to_closest, next)
+    }
+    // brute force marking was too slow!
+    // fn mark_grid(&self, grid: &mut HashMap<(i128, i128), char>) {
+    //     let (pos_x, pos_y) = self.position;
+    //     let delta = self.distance_to_closest();
+    //     for x in pos_x - delta..=pos_x + delta {
+    //         for y in pos_y - delta..=pos_y + delta {
+    //             if !self.in_closest_beacon_range((x, y)) {
+    //                 continue;
+    //             }
+    //             if !grid.contains_key(&(x, y)) {
+    //                 grid.insert((x, y), '#');
+    //             }
+    //         }
+    //     }
+    //     grid.insert(self.position, 'S');
+    //     grid.insert(self.closest_beacon, 'B');
+    // }
+    fn mark_grid_only_relevant(&self, y: i128, grid: &mut HashMap<Vec2, char>) {
+        let pos_x = self.position.x;
+        let delta = self.distance_to_closest();
+        for x in pos_x - delta..=pos_x + delta {
+            let position = Vec2::new(x, y);
+            if !self.in_closest_beacon_range(position) {
+                continue;
+            }
+            grid.entry(position).or_insert('#');
+        }
+        grid.insert(self.position, 'S');
+        grid.insert(self.closest_beacon, 'B');
+    }
+}
+
+pub struct TaskData {
+    sensors: Vec<Sensor>,
+    grid: HashMap<Vec2, char>,
+}
+impl TaskData {
+    fn check_row_y(&mut self, y_check: i128) -> i128 {
+        for sensor in self.sensors.iter() {
+            debug!("checking {:?}", sensor);
+            sensor.mark_grid_only_relevant(y_check, &mut self.grid);
+        }
+        self.grid
+            .iter()
+            .filter(|(pos, c)| pos.y == y_check && **c == '#')
+            .count() as i128
+    }
+
+    fn find(&self, max: i128) -> Vec2 {
+        // let max = 4000000;
+        //let max = 20;
+        for y in 0..=max {
+            let mut x = 0;
+            while x <= max {
+                let fil: Vec<i128> = self
+                    .sensors
+                    .iter()
+                    .map(|s| s.in_closest_beacon_range_next_x(Vec2::new(x, y)))
+                    .filter(|(t, _off)| *t)
+                    .map(|(_, off)| off)
+                    .collect();
+                if fil.is_empty() {
+                    return Vec2::new(x, y);
+                } else {
+                    x += fil.iter().max().unwrap();
+                }
+            }
+        }
+        panic!("not found");
+    }
+}
+
+fn parse_input(input: &str) -> Result<TaskData, ParseError> {
+    let mut sensors = Vec::new();
+    let grid = HashMap::new();
+    for line in input::lines(input) {
+        sensors.push(Sensor::parse(input, line)?);
+    }
+    Ok(TaskData { sensors, grid })
+}
+
+fn part_one_at_row(mut data: TaskData, y: i128) -> Result<Answer> {
+    Ok(Answer::from(data.check_row_y(y)))
+}
+
+fn part_two_within(data: TaskData, max: i128) -> Result<Answer> {
+    let beacon = data.find(max);
+    Ok(Answer::from(beacon.x * 4000000 + beacon.y))
+}
+
+aoc_core::params! {
+    /// The example looks at row 10 and searches up to 20.
+    pub struct Params {
+        /// The row part one counts the positions without a beacon in
+        row: i128 = 2_000_000,
+        /// The largest coordinate of the distress beacon
+        max: i128 = 4_000_000,
+    }
+}
+
+pub struct BeaconExclusionZone;
+
+impl Solution for BeaconExclusionZone {
+    type Input = TaskData;
+
+    fn parse_input(input: &str) -> Result<TaskData, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(data: Self::Input) -> Result<Answer> {
+        part_one_at_row(data, Params::get()?.row)
+    }
+
+    fn part_two(data: Self::Input) -> Result<Answer> {
+        part_two_within(data, Params::get()?.max)
+    }
+
+    fn params() -> Vec<(&'static str, String)> {
+        Params::defaults()
+    }
+}
@@ -0,0 +1,267 @@
+use anyhow::{bail, Result};
+use aoc_core::noise::Noise;
+use aoc_core::{input, parse, search, Answer, ParseError, Solution};
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::collections::HashMap;
+
+#[derive(Debug)]
+pub struct TaskData {
+    node_flows: HashMap<String, i128>,
+    adjacency: HashMap<String, Vec<String>>,
+}
+
+// only the valves worth opening matter, walking between them is condensed
+// into the distances
+struct Valves {
+    flows: Vec<i128>,
+    // distances[from][to], the start is the last one
+    distances: Vec<Vec<i128>>,
+}
+
+impl Valves {
+    fn open(flow: i128, remaining: i128) -> i128 {
+        // opening takes one of the remaining minutes, the valve releases
+        // during the rest
+        flow * (remaining - 1)
+    }
+
+    // remembers the most pressure released for every set of opened valves
+    fn explore(
+        &self,
+        at: usize,
+        remaining_time: i128,
+        opened: u64,
+        released: i128,
+        best_for_opened: &mut HashMap<u64, i128>,
+    ) {
+        let best = best_for_opened.entry(opened).or_insert(0);
+        *best = (*best).max(released);
+        for (next, flow) in self.flows.iter().enumerate() {
+            let remaining = remaining_time - self.distances[at][next];
+            if opened & 1 << next != 0 || remaining <= 1 {
+                continue;
+            }
+            let released = released + Self::open(*flow, remaining);
+            self.explore(
+                next,
+                remaining - 1,
+                opened | 1 << next,
+                released,
+                best_for_opened,
+            );
+        }
+    }
+}
+
+impl TaskData {
+    fn valves(&self, start: &str) -> Result<Valves> {
+        if !self.adjacency.contains_key(start) {
+            bail!("there is no valve {} to start at", start);
+        }
+        let mut names: Vec<&String> = self
+            .node_flows
+            .iter()
+            .filter(|(_, flow)| **flow > 0)
+            .map(|(name, _)| name)
+            .collect();
+        names.sort();
+        if names.len() > u64::BITS as usize {
+            bail!(
+                "{} valves are worth opening, the opened ones only fit into a set of {}",
+                names.len(),
+                u64::BITS
+            );
+        }
+        let flows = names.iter().map(|name| self.node_flows[*name]).collect();
+        let distances = names
+            .iter()
+            .map(|name| name.as_str())
+            .chain([start])
+            .map(|from| {
+                let steps = search::bfs_distances([from], |node| {
+                    self.adjacency[*node].iter().map(String::as_str)
+                });
+                names
+                    .iter()
+                    .map(|to| steps.get(to.as_str()).map_or(i128::MAX / 2, |&d| d as i128))
+                    .collect()
+            })
+            .collect();
+        Ok(Valves { flows, distances })
+    }
+
+    fn release(&self, start: &str, initial_time: i128, use_second: bool) -> Result<i128> {
+        let valves = self.valves(start)?;
+        let mut best_for_opened = HashMap::new();
+        let start = valves.flows.len();
+        valves.explore(start, initial_time, 0, 0, &mut best_for_opened);
+        if !use_second {
+            return Ok(best_for_opened.values().copied().max().unwrap_or(0));
+        }
+        // the elephant opens other valves than we do
+        let mut best = 0;
+        for (mine, my_release) in best_for_opened.iter() {
+            for (elephants, elephants_release) in best_for_opened.iter() {
+                if mine & elephants == 0 {
+                    best = best.max(my_release + elephants_release);
+                }
+            }
+        }
+        Ok(best)
+    }
+}
+
+//           BB
+//           |
+//      CC---AA---DD
+//           |
+//           EE
+//
+// graph with costs between edges
+// each node has open(time) -> released
+// never open valves with flow 0 -> only walk between the others
+// the opened valves fit into a bitset, the best release per bitset is all
+// that is needed to pair our valves with the elephant's
+
+fn parse_input(input: &str) -> Result<TaskData, ParseError> {
+    lazy_static! {
+        static ref VALVE: Regex = Regex::new(
+            r"^Valve ([A-Z][A-Z]) has flow rate=(\d+); tunnels? leads? to valves? ([A-Z][A-Z](?:, [A-Z][A-Z])*)$"
+        )
+        .unwrap();
+    }
+    let mut nodes = HashMap::new();
+    let mut adjacency = HashMap::new();
+    let mut tunnels = Vec::new();
+
+    for line in input::lines(input) {
+        let caps = parse::captures(&VALVE, input, line)?;
+        let name = String::from(&caps[1]);
+        let flow = parse::value::<i128>(input, &caps[2], "a flow rate")?;
+        nodes.insert(name.clone(), flow);
+        let neighs = caps.get(3).unwrap().as_str().split(", ");
+        tunnels.extend(neighs.clone());
+        adjacency.insert(name.clone(), neighs.map(String::from).collect());
+    }
+    // the walks between the valves can't leave the scan
+    if let Some(unknown) = tunnels.into_iter().find(|to| !nodes.contains_key(*to)) {
+        return Err(ParseError::at(
+            input,
+            unknown,
+            "a valve with a line of its own",
+        ));
+    }
+    Ok(TaskData {
+        node_flows: nodes,
+        adjacency,
+    })
+}
+
+aoc_core::params! {
+    pub struct Params {
+        /// The valve everybody starts at
+        start: String = String::from("AA"),
+        minutes_one: i128 = 30,
+        /// The minutes left after teaching the elephant
+        minutes_two: i128 = 26,
+    }
+}
+
+pub struct ProboscideaVolcanium;
+
+impl Solution for ProboscideaVolcanium {
+    type Input = TaskData;
+
+    fn parse_input(input: &str) -> Result<TaskData, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(data: Self::Input) -> Result<Answer> {
+        let params = Params::get()?;
+        let answer = data.release(&params.start, params.minutes_one, false)?;
+        Ok(Answer::from(answer))
+    }
+
+    fn part_two(data: Self::Input) -> Result<Answer> {
+        let params = Params::get()?;
+        let answer = data.release(&params.start, params.minutes_two, true)?;
+        Ok(Answer::from(answer))
+    }
+
+    fn params() -> Vec<(&'static str, String)> {
+        Params::defaults()
+    }
+
+    fn worst_cases() -> Vec<(&'static str, String)> {
+        // as many valves worth opening as real scans have, two minutes apart
+        // on a grid of tunnels, so most orders still fit into the minutes
+        let side = 7;
+        let name = |row: usize, col: usize| {
+            let i = row * side + col;
+            let letter = |j: usize| char::from(b'A' + j as u8);
+            format!("{}{}", letter(i / 26), letter(i % 26))
+        };
+        let mut noise = Noise::new(16);
+        let mut close_valves = String::new();
+        for row in 0..side {
+            for col in 0..side {
+                let flow = if row % 2 == 0 && col % 2 == 0 && row + col > 0 {
+                    noise.range(1..=25)
+                } else {
+                    0
+                };
+                let mut tunnels = Vec::new();
+                if row > 0 {
+                    tunnels.push(name(row - 1, col));
+                }
+                if col > 0 {
+                    tunnels.push(name(row, col - 1));
+                }
+                if col + 1 < side {
+                    tunnels.push(name(row, col + 1));
+                }
+                if row + 1 < side {
+                    tunnels.push(name(row + 1, col));
+                }
+                close_valves.push_str(&format!(
+                    "Valve {} has flow rate={}; tunnels lead to valves {}\n",
+                    name(row, col),
+                    flow,
+                    tunnels.join(", ")
+                ));
+            }
+        }
+        vec![("close_valves", close_valves)]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tunnels_lead_to_valves_in_the_scan() {
+        let input = "Valve AA has flow rate=0; tunnels lead to valves BB, CC\n\
+                     Valve BB has flow rate=13; tunnel leads to valve AA\n";
+        let err = parse_input(input).unwrap_err();
+        assert_eq!((err.line, err.column), (1, 54));
+        assert_eq!(err.expected, "a valve with a line of its own");
+    }
+
+    #[test]
+    fn starts_at_a_valve_in_the_scan() {
+        let input = "Valve AA has flow rate=0; tunnel leads to valve BB\n\
+                     Valve BB has flow rate=13; tunnel leads to valve AA\n";
+        let overrides = [aoc_core::params::parse_override("start=ZZ").unwrap()];
+        let err = aoc_core::params::with(&overrides, || {
+            ProboscideaVolcanium::part_one(parse_input(input).unwrap())
+        })
+        .unwrap_err();
+        assert_eq!(err.to_string(), "there is no valve ZZ to start at");
+        assert_eq!(
+            ProboscideaVolcanium::part_one(parse_input(input).unwrap()).unwrap(),
+            Answer::from(13 * 28)
+        );
+    }
+}
@@ -1,7 +1,9 @@
 use anyhow::Result;
+use aoc_core::noise::Noise;
+use aoc_core::{cycle, input, record, Answer, ParseError, Solution};
+use log::debug;
 use std::cmp;
-use std::collections::{BTreeSet, HashMap};
-use std::io::{self, Read};
+use std::collections::BTreeSet;
 
 // 7 wide
 // left rock edge 2 away from left wall
@@ -49,29 +51,38 @@ impl Pos {
     }
 }
 
-struct RockTetris {
+pub struct RockTetris {
     rocks_index: usize,
     rocks: Vec<BTreeSet<Pos>>,
     jets_index: usize,
     jets: Vec<char>,
     // ground is always 0 in the coordinates however the real height is obtained by adding ground_offset
-    total_rocks_dropped: i128,
     highest: i128,
     ground_offset: i128,
     rested: BTreeSet<Pos>,
-    // store the encountered completions after the discard happened
-    // insert key: (rocks_index, jets_index, rested) value: (total_rocks_dropped, ground_offset)
-    encountered_completions: HashMap<(usize, usize, BTreeSet<Pos>), (i128, i128)>,
 }
 
 impl RockTetris {
-    fn run_n_rocks(&mut self, n: i128) {
-        while self.total_rocks_dropped < n {
-            let rock = self.next_rock();
-            self.total_rocks_dropped += 1;
-            self.handle_rock(&rock, n);
+    fn run_n_rocks(&mut self, n: usize) {
+        for _ in 0..n {
+            self.drop_rock();
         }
     }
+    // the rested rocks are trimmed whenever a line completes, so once the next rock, the next jet
+    // and the rested rocks repeat, the tower keeps growing by the same height
+    fn height_after(self, n: usize) -> i128 {
+        let cycle = cycle::find(
+            self,
+            Self::drop_rock,
+            |t| (t.rocks_index, t.jets_index, t.rested.clone()),
+            Self::get_heighest,
+        );
+        cycle.extrapolate(n)
+    }
+    fn drop_rock(&mut self) {
+        let rock = self.next_rock();
+        self.handle_rock(&rock);
+    }
     fn next_rock(&mut self) -> BTreeSet<Pos> {
         let initial = &self.rocks[self.rocks_index];
         self.rocks_index = (self.rocks_index + 1) % self.rocks.len();
@@ -85,7 +96,7 @@ impl RockTetris {
             })
             .collect()
     }
-    fn handle_rock(&mut self, rock: &BTreeSet<Pos>, n: i128) {
+    fn handle_rock(&mut self, rock: &BTreeSet<Pos>) {
         let mut current = rock.clone();
 
         loop {
@@ -95,11 +106,12 @@ impl RockTetris {
             if !self.intersect(&next) {
                 std::mem::swap(&mut current, &mut next);
             }
+            record::frame(|| self.render(Some(&current)));
             let mut next = Self::fall(&current);
             if self.intersect(&next) {
-                self.rested = self.rested.union(&current).map(|&p| p).collect();
+                self.rested = self.rested.union(&current).copied().collect();
                 self.highest = cmp::max(self.highest, current.iter().map(|p| p.y).max().unwrap());
-                self.check_line_completion(&current, n);
+                self.check_line_completion(&current);
                 return;
             } else {
                 std::mem::swap(&mut current, &mut next);
@@ -127,7 +139,7 @@ impl RockTetris {
         rock.iter().map(|p| p.fall()).collect()
     }
 
-    fn check_line_completion(&mut self, current: &BTreeSet<Pos>, n: i128) {
+    fn check_line_completion(&mut self, current: &BTreeSet<Pos>) {
         // check if any line where the tile landed is complete
         let mut lines_to_check = current.iter().map(|p| p.y).collect::<Vec<_>>();
         lines_to_check.sort_by(|a, b| b.cmp(a));
@@ -148,12 +160,12 @@ impl RockTetris {
             }
             if amount == 7 {
                 // line's full
-                self.handle_line_completion(y - 3, n);
+                self.handle_line_completion(y - 3);
                 return;
             }
         }
     }
-    fn handle_line_completion(&mut self, y: i128, n: i128) {
+    fn handle_line_completion(&mut self, y: i128) {
         self.highest -= y;
         self.ground_offset += y;
         self.rested = self
@@ -162,57 +174,29 @@ impl RockTetris {
             .filter(|p| p.y > y)
             .map(|p| Pos { x: p.x, y: p.y - y })
             .collect();
-        let completion_key = (self.rocks_index, self.jets_index, self.rested.clone());
-        if self.encountered_completions.contains_key(&completion_key) {
-            let ground_now = self.ground_offset;
-            let rocks_now = self.total_rocks_dropped;
-            let (rocks_before, ground_before) = self.encountered_completions[&completion_key];
-            let ground_delta = ground_now - ground_before;
-            let rocks_delta = rocks_now - rocks_before;
-            println!(
-                "ground now {} vs ground before {} delta: {}",
-                ground_now, ground_before, ground_delta
-            );
-            println!(
-                "rocks now {} vs rocks before {} delta: {}",
-                rocks_now, rocks_before, rocks_delta
-            );
-            // how much can be skipped
-            // rocks_now + n * rocks_delta == n_rocks
-            let delta = (n - rocks_now) / rocks_delta;
-            self.total_rocks_dropped += delta * rocks_delta;
-            self.ground_offset += delta * ground_delta;
-        }
-        self.encountered_completions.insert(
-            completion_key,
-            (self.total_rocks_dropped, self.ground_offset),
-        );
     }
-    fn debug(&self, current: Option<&BTreeSet<Pos>>) {
-        for y in (0..=20).rev() {
+    // the top of the chamber, where the rocks fall
+    fn render(&self, current: Option<&BTreeSet<Pos>>) -> String {
+        let mut out = String::new();
+        let top = cmp::max(20, self.highest + 7);
+        for y in (top - 20..=top).rev() {
             for x in 0..=8 {
-                if y == 0 {
-                    print!("-");
+                let pos = Pos { x, y };
+                out.push(if y == 0 {
+                    '-'
                 } else if x == 0 || x == 8 {
-                    print!("|");
+                    '|'
+                } else if self.rested.contains(&pos) {
+                    '#'
+                } else if current.is_some_and(|falling| falling.contains(&pos)) {
+                    '@'
                 } else {
-                    if self.rested.contains(&Pos { x, y }) {
-                        print!("#");
-                    } else {
-                        if let Some(falling) = current {
-                            if falling.contains(&Pos { x, y }) {
-                                print!("@");
-                            } else {
-                                print!(".");
-                            }
-                        } else {
-                            print!(".");
-                        }
-                    }
-                }
+                    '.'
+                });
             }
-            println!("");
+            out.push('\n');
         }
+        out
     }
 }
 
@@ -268,96 +252,62 @@ fn construct_rocks() -> Vec<BTreeSet<Pos>> {
     vec![line, cross, l_shape, down_line, block]
 }
 
-fn parse_input(input: &str) -> Result<RockTetris> {
-    let jets: Vec<char> = input.lines().nth(0).unwrap().chars().collect();
+fn parse_input(input: &str) -> Result<RockTetris, ParseError> {
+    let line = input::lines(input)
+        .next()
+        .filter(|l| !l.is_empty())
+        .ok_or_else(|| ParseError::missing(input, "a jet pattern"))?;
+    if let Some(i) = line.find(|c| c != '<' && c != '>') {
+        return Err(ParseError::at(input, &line[i..], "< or >"));
+    }
+    let jets: Vec<char> = line.chars().collect();
 
     Ok(RockTetris {
         rocks_index: 0,
         rocks: construct_rocks(),
         jets_index: 0,
         jets,
-        total_rocks_dropped: 0,
         highest: 0,
         ground_offset: 0,
         rested: BTreeSet::new(),
-        encountered_completions: HashMap::new(),
     })
 }
 
-fn part_one(input: &str) -> Result<i128> {
-    let mut data = parse_input(input)?;
-    data.run_n_rocks(2022);
-    data.debug(None);
-    Ok(data.get_heighest())
-}
-
-fn part_two(input: &str) -> Result<i128> {
-    let mut data = parse_input(input)?;
-    data.run_n_rocks(1_000_000_000_000);
-    Ok(data.get_heighest())
-}
-
-fn main() -> Result<()> {
-    let mut input = String::new();
-    io::stdin().read_to_string(&mut input)?;
-    let t = std::time::Instant::now();
-    let part_one = part_one(&input)?;
-    let elapsed = t.elapsed();
-    println!("Part one: {} in {:0.2?}", part_one, elapsed);
-    let t = std::time::Instant::now();
-    let part_two = part_two(&input)?;
-    let elapsed = t.elapsed();
-    println!("Part two: {} in {:0.2?}", part_two, elapsed);
-    Ok(())
+aoc_core::params! {
+    pub struct Params {
+        rocks_one: usize = 2022,
+        rocks_two: usize = 1_000_000_000_000,
+    }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use lazy_static::lazy_static;
-    use std::fs;
+pub struct PyroclasticFlow;
 
-    lazy_static! {
-        static ref TEST: String = read_from_file("test.txt");
-        static ref INPUT: String = read_from_file("input.txt");
-    }
+impl Solution for PyroclasticFlow {
+    type Input = RockTetris;
 
-    fn read_from_file(filename: &str) -> String {
-        fs::read_to_string(filename)
-            .unwrap_or_else(|msg| panic!("error reading {}: {}", filename, msg))
+    fn parse_input(input: &str) -> Result<RockTetris, ParseError> {
+        parse_input(input)
     }
 
-    #[test]
-    fn test_one() -> Result<()> {
-        let answer = super::part_one(&TEST)?;
-        assert_eq!(answer, 3068);
-        Ok(())
+    fn part_one(mut data: Self::Input) -> Result<Answer> {
+        data.run_n_rocks(Params::get()?.rocks_one);
+        debug!("\n{}", data.render(None));
+        Ok(Answer::from(data.get_heighest()))
     }
 
-    // Use "cargo test --release -- part_one --nocapture" to print the time
-    #[test]
-    fn part_one() -> Result<()> {
-        let t = std::time::Instant::now();
-        let answer = super::part_one(&INPUT)?;
-        eprintln!("Part one took {:0.2?}", t.elapsed());
-        assert_eq!(answer, 3130);
-        Ok(())
+    fn part_two(data: Self::Input) -> Result<Answer> {
+        Ok(Answer::from(data.height_after(Params::get()?.rocks_two)))
     }
 
-    #[test]
-    fn test_two() -> Result<()> {
-        let answer = super::part_two(&TEST)?; // still to slow for test!
-        assert_eq!(answer, 1514285714288);
-        Ok(())
+    fn params() -> Vec<(&'static str, String)> {
+        Params::defaults()
     }
 
-    // Use "cargo test --release -- part_two --nocapture" to print the time
-    #[test]
-    fn part_two() -> Result<()> {
-        let t = std::time::Instant::now();
-        let answer = super::part_two(&INPUT)?;
-        eprintln!("Part two took {:0.2?}", t.elapsed());
-        assert_eq!(answer, 1556521739139);
-        Ok(())
+    fn worst_cases() -> Vec<(&'static str, String)> {
+        // the length of real jet patterns without their repetitions, so the
+        // tower only repeats after many thousand rocks
+        let mut noise = Noise::new(17);
+        let random_jets = (0..10_091).map(|_| *noise.pick(&['<', '>'])).collect();
+        vec![("random_jets", random_jets)]
     }
 }
@@ -0,0 +1,191 @@
+use anyhow::Result;
+use aoc_core::parse::{self, Within};
+use aoc_core::{input, search, Answer, ParseError, Solution, Vec3};
+use log::{debug, trace};
+use std::collections::HashSet;
+
+pub struct TaskData {
+    blocks: Vec<Vec3>,
+    covered_map: HashSet<Vec3>,
+    visited: HashSet<Vec3>,
+}
+
+impl TaskData {
+    fn count_surface_stupid(&self) -> i128 {
+        let mut surface = 0;
+        for block in self.blocks.iter() {
+            for npos in block.neighbors6() {
+                if !self.covered_map.contains(&npos) {
+                    surface += 1;
+                }
+            }
+        }
+        surface
+    }
+
+    // one cube of air around the droplet so the outside is connected
+    fn get_ranges(&self) -> (Vec3, Vec3) {
+        let (min, max) = Vec3::bounding_box(self.blocks.iter().copied()).unwrap_or_default();
+        let one = Vec3::new(1, 1, 1);
+        (min - one, max + one)
+    }
+    fn count_surface_without_interior(&mut self) -> i128 {
+        let mut surface = 0;
+        self.bfs();
+        for block in self.blocks.iter() {
+            for npos in block.neighbors6() {
+                if self.covered_map.contains(&npos) {
+                    continue;
+                }
+                if !self.visited.contains(&npos) {
+                    trace!("check {:?} from block {:?} failed", npos, block);
+                    continue;
+                }
+                surface += 1;
+            }
+        }
+        surface
+    }
+    fn bfs(&mut self) {
+        let (min, max) = self.get_ranges();
+        debug!("x: {} - {}", min.x, max.x);
+        debug!("y: {} - {}", min.y, max.y);
+        debug!("z: {} - {}", min.z, max.z);
+        // start from all eight corners
+        let mut starts = Vec::new();
+        for x in [min.x, max.x] {
+            for y in [min.y, max.y] {
+                for z in [min.z, max.z] {
+                    starts.push(Vec3::new(x, y, z));
+                }
+            }
+        }
+        let covered_map = &self.covered_map;
+        let air = search::bfs_distances(starts, |block: &Vec3| {
+            block.neighbors6().filter(|npos| {
+                // only the air in the box around the droplet
+                let inside = npos.component_min(&min) == min && npos.component_max(&max) == max;
+                inside && !covered_map.contains(npos)
+            })
+        });
+        self.visited = air.into_keys().collect();
+    }
+}
+
+fn parse_input(input: &str) -> Result<TaskData, ParseError> {
+    let mut blocks = Vec::new();
+    let mut covered_map = HashSet::new();
+    for line in input::lines(input) {
+        let mut split = line.split(',');
+        let mut coordinate = || -> Result<i128, ParseError> {
+            let s = parse::next(&mut split, line, "a coordinate").within(input, line)?;
+            parse::value(input, s, "a coordinate")
+        };
+        let pos = Vec3::new(coordinate()?, coordinate()?, coordinate()?);
+        blocks.push(pos);
+        covered_map.insert(pos);
+    }
+    Ok(TaskData {
+        blocks,
+        covered_map,
+        visited: HashSet::new(),
+    })
+}
+
+pub struct BoilingBoulders;
+
+impl Solution for BoilingBoulders {
+    type Input = TaskData;
+
+    fn parse_input(input: &str) -> Result<TaskData, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(data: Self::Input) -> Result<Answer> {
+        Ok(Answer::from(data.count_surface_stupid()))
+    }
+
+    fn part_two(mut data: Self::Input) -> Result<Answer> {
+        Ok(Answer::from(data.count_surface_without_interior()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::differential::{assert_agree, proptest::prelude::*};
+
+    fn droplet(cubes: impl IntoIterator<Item = (i128, i128, i128)>) -> String {
+        cubes
+            .into_iter()
+            .map(|(x, y, z)| format!("{},{},{}\n", x, y, z))
+            .collect()
+    }
+
+    fn droplets() -> impl Strategy<Value = String> {
+        prop::collection::btree_set((0..6i128, 0..6i128, 0..6i128), 0..80).prop_map(droplet)
+    }
+
+    // columns standing on the floor, there is no air they could enclose
+    fn columns() -> impl Strategy<Value = String> {
+        prop::collection::vec(0..5i128, 1..=25).prop_map(|heights| {
+            droplet(heights.into_iter().enumerate().flat_map(|(i, height)| {
+                let (x, y) = (i as i128 % 5, i as i128 / 5);
+                (0..height).map(move |z| (x, y, z))
+            }))
+        })
+    }
+
+    // a walk through the air for every face, to see whether it gets out
+    fn count_surface_without_interior_slow(data: &TaskData) -> i128 {
+        let (min, max) = data.get_ranges();
+        let escapes = |start: Vec3| {
+            let mut seen = HashSet::from([start]);
+            let mut todo = vec![start];
+            while let Some(air) = todo.pop() {
+                if air.x == min.x
+                    || air.x == max.x
+                    || air.y == min.y
+                    || air.y == max.y
+                    || air.z == min.z
+                    || air.z == max.z
+                {
+                    return true;
+                }
+                for next in air.neighbors6() {
+                    if !data.covered_map.contains(&next) && seen.insert(next) {
+                        todo.push(next);
+                    }
+                }
+            }
+            false
+        };
+        data.blocks
+            .iter()
+            .flat_map(|block| block.neighbors6())
+            .filter(|&air| !data.covered_map.contains(&air) && escapes(air))
+            .count() as i128
+    }
+
+    fn parsed(input: &str) -> TaskData {
+        parse_input(input).unwrap()
+    }
+
+    #[test]
+    fn bfs_agrees_with_a_walk_per_face() {
+        assert_agree(
+            droplets(),
+            |input| parsed(input).count_surface_without_interior(),
+            |input| count_surface_without_interior_slow(&parsed(input)),
+        );
+    }
+
+    #[test]
+    fn bfs_agrees_with_all_faces_without_pockets() {
+        assert_agree(
+            columns(),
+            |input| parsed(input).count_surface_without_interior(),
+            |input| parsed(input).count_surface_stupid(),
+        );
+    }
+}
@@ -1,10 +1,9 @@
 use anyhow::Result;
+use aoc_core::{input, parse, Answer, ParseError, Solution};
 use lazy_static::lazy_static;
+use log::{debug, trace};
 use regex::Regex;
-use std::{
-    collections::{HashMap, HashSet, VecDeque},
-    io::{self, Read},
-};
+use std::collections::{HashMap, HashSet, VecDeque};
 
 #[derive(Debug, Clone)]
 struct Blueprint {
@@ -17,7 +16,7 @@ struct Blueprint {
 
 impl Blueprint {
     fn max_needed_ore_robots(&self) -> i128 {
-        let ore_costs = vec![
+        let ore_costs = [
             self.ore_robot_cost,
             self.clay_robot_cost,
             self.obsidian_robot_cost.0,
@@ -33,7 +32,7 @@ impl Blueprint {
     }
 }
 
-struct TaskData {
+pub struct TaskData {
     blueprints: Vec<Blueprint>,
 }
 
@@ -173,7 +172,7 @@ impl Simulation {
         for m in (0..=min).rev() {
             let check = states_at_min.get(&m).unwrap();
             for s in check {
-                if state.guaranteed_not_better(&s) {
+                if state.guaranteed_not_better(s) {
                     return true;
                 }
             }
@@ -193,7 +192,7 @@ impl Simulation {
         visited.insert(start.clone());
         queue.push_back(start.clone());
         while let Some(s) = queue.pop_front() {
-            // println!("{:?}", s);
+            trace!("{:?}", s);
             if s.minute == minutes {
                 found.push(s);
             } else {
@@ -217,24 +216,19 @@ impl Simulation {
     }
 }
 
-fn parse_input(input: &str) -> Result<TaskData> {
+fn parse_input(input: &str) -> Result<TaskData, ParseError> {
     lazy_static! {
-        static ref RE: Regex = Regex::new(r"Blueprint (\d+): Each ore robot costs (\d+) ore. Each clay robot costs (\d+) ore. Each obsidian robot costs (\d+) ore and (\d+) clay. Each geode robot costs (\d+) ore and (\d+) obsidian.").unwrap();
+        static ref RE: Regex = Regex::new(r"^Blueprint (\d+): Each ore robot costs (\d+) ore. Each clay robot costs (\d+) ore. Each obsidian robot costs (\d+) ore and (\d+) clay. Each geode robot costs (\d+) ore and (\d+) obsidian\.$").unwrap();
     }
     let mut blueprints = Vec::new();
-    for line in input.lines() {
-        let caps = RE.captures(line).unwrap();
-        let id = i128::from_str_radix(&caps[1], 10).unwrap();
-        let ore = i128::from_str_radix(&caps[2], 10).unwrap();
-        let clay = i128::from_str_radix(&caps[3], 10).unwrap();
-        let obsidian = (
-            i128::from_str_radix(&caps[4], 10).unwrap(),
-            i128::from_str_radix(&caps[5], 10).unwrap(),
-        );
-        let geode = (
-            i128::from_str_radix(&caps[6], 10).unwrap(),
-            i128::from_str_radix(&caps[7], 10).unwrap(),
-        );
+    for line in input::lines(input) {
+        let caps = parse::captures(&RE, input, line)?;
+        let amount = |i: usize| parse::value::<i128>(input, &caps[i], "an amount");
+        let id = parse::value(input, &caps[1], "a blueprint id")?;
+        let ore = amount(2)?;
+        let clay = amount(3)?;
+        let obsidian = (amount(4)?, amount(5)?);
+        let geode = (amount(6)?, amount(7)?);
         blueprints.push(Blueprint {
             id,
             ore_robot_cost: ore,
@@ -246,101 +240,59 @@ fn parse_input(input: &str) -> Result<TaskData> {
     Ok(TaskData { blueprints })
 }
 
-fn part_one(input: &str) -> Result<i128> {
-    let TaskData { blueprints } = parse_input(input)?;
-    let start = State::start();
-    let mut total = 0;
-    for (i, blueprint) in blueprints.iter().enumerate() {
-        print!("blueprint {}: ", i + 1);
-        let s = Simulation::new(blueprint.clone());
-        let t = std::time::Instant::now();
-        let q = s.quality_level(start.clone(), 24);
-        let elapsed = t.elapsed();
-        println!("{} ({}) in {:0.2?}", q, q / (i + 1) as i128, elapsed);
-        total += q;
-    }
-    Ok(total)
-}
-
-fn part_two(input: &str) -> Result<i128> {
-    let TaskData { blueprints } = parse_input(input)?;
-    let start = State::start();
-    let mut total = 1;
-    for blueprint in blueprints.iter().take(3) {
-        print!("blueprint {}: ", blueprint.id);
-        let s = Simulation::new(blueprint.clone());
-        let t = std::time::Instant::now();
-        let cracked_geodes = s.max_cracked_geode(start.clone(), 32);
-        let elapsed = t.elapsed();
-        println!("cracked geodes {} in {:0.2?}", cracked_geodes, elapsed);
-        total *= cracked_geodes;
-    }
-    Ok(total)
-}
-
-fn main() -> Result<()> {
-    let mut input = String::new();
-    io::stdin().read_to_string(&mut input)?;
-    let t = std::time::Instant::now();
-    let part_one = part_one(&input)?;
-    let elapsed = t.elapsed();
-    println!("Part one: {} in {:0.2?}", part_one, elapsed);
-    let t = std::time::Instant::now();
-    let part_two = part_two(&input)?;
-    let elapsed = t.elapsed();
-    println!("Part two: {} in {:0.2?}", part_two, elapsed);
-    Ok(())
-}
+pub struct NotEnoughMinerals;
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use lazy_static::lazy_static;
-    use std::fs;
+impl Solution for NotEnoughMinerals {
+    type Input = TaskData;
 
-    lazy_static! {
-        static ref TEST: String = read_from_file("test.txt");
-        static ref INPUT: String = read_from_file("input.txt");
-    }
-
-    fn read_from_file(filename: &str) -> String {
-        fs::read_to_string(filename)
-            .unwrap_or_else(|msg| panic!("error reading {}: {}", filename, msg))
+    fn parse_input(input: &str) -> Result<TaskData, ParseError> {
+        parse_input(input)
     }
 
-    #[test]
-    fn test_one() -> Result<()> {
-        let answer = super::part_one(&TEST)?;
-        assert_eq!(answer, 33);
-        Ok(())
+    fn part_one(TaskData { blueprints }: Self::Input) -> Result<Answer> {
+        let start = State::start();
+        let mut total = 0;
+        for (i, blueprint) in blueprints.iter().enumerate() {
+            let s = Simulation::new(blueprint.clone());
+            let t = std::time::Instant::now();
+            let q = s.quality_level(start.clone(), 24);
+            let elapsed = t.elapsed();
+            debug!(
+                "blueprint {}: {} ({}) in {:0.2?}",
+                i + 1,
+                q,
+                q / (i + 1) as i128,
+                elapsed
+            );
+            total += q;
+        }
+        Ok(Answer::from(total))
     }
 
-    // Use "cargo test --release -- part_one --nocapture" to print the time
-    #[test]
-    fn part_one() -> Result<()> {
-        let t = std::time::Instant::now();
-        let answer = super::part_one(&INPUT)?;
-        eprintln!("Part one took {:0.2?}", t.elapsed());
-        assert_eq!(answer, 1009);
-        Ok(())
+    fn part_two(TaskData { blueprints }: Self::Input) -> Result<Answer> {
+        let start = State::start();
+        let mut total = 1;
+        for blueprint in blueprints.iter().take(3) {
+            let s = Simulation::new(blueprint.clone());
+            let t = std::time::Instant::now();
+            let cracked_geodes = s.max_cracked_geode(start.clone(), 32);
+            let elapsed = t.elapsed();
+            debug!(
+                "blueprint {}: cracked geodes {} in {:0.2?}",
+                blueprint.id, cracked_geodes, elapsed
+            );
+            total *= cracked_geodes;
+        }
+        Ok(Answer::from(total))
     }
 
-    // TODO:
-    // Still needs optimization to run this test fast enough
-    // #[test]
-    // fn test_two() -> Result<()> {
-    //     let answer = super::part_two(&TEST)?;
-    //     assert_eq!(answer, 56 * 62);
-    //     Ok(())
-    // }
-
-    // Use "cargo test --release -- part_two --nocapture" to print the time
-    #[test]
-    fn part_two() -> Result<()> {
-        let t = std::time::Instant::now();
-        let answer = super::part_two(&INPUT)?;
-        eprintln!("Part two took {:0.2?}", t.elapsed());
-        assert_eq!(answer, 18816);
-        Ok(())
+    fn worst_cases() -> Vec<(&'static str, String)> {
+        // hardly any geodes after 24 minutes but dozens after 32, every
+        // robot built early branches the search of part two again
+        let many_geodes = "Blueprint 1: Each ore robot costs 2 ore. \
+                           Each clay robot costs 3 ore. \
+                           Each obsidian robot costs 3 ore and 16 clay. \
+                           Each geode robot costs 2 ore and 12 obsidian.\n";
+        vec![("many_geodes", String::from(many_geodes))]
     }
 }
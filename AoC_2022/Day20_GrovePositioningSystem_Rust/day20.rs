@@ -1,9 +1,10 @@
 use anyhow::Result;
+use aoc_core::{input, parse, Answer, ParseError, Solution};
+use log::{debug, trace};
 use std::collections::HashMap;
-use std::io::{self, Read};
 
 #[derive(Debug)]
-struct TaskData {
+pub struct TaskData {
     numbers: Vec<i128>,
     decrypt_numbers: Vec<i128>,
     amount_numbers: usize,
@@ -13,12 +14,11 @@ struct TaskData {
 }
 
 impl TaskData {
-    fn _debug(&self) {
-        println!("{:?}", self);
-        for i in 0..self.amount_numbers {
-            print!("{}, ", self.numbers[self.curr2orig[&i]]);
-        }
-        println!("");
+    // the numbers in their current order
+    fn arrangement(&self) -> Vec<i128> {
+        (0..self.amount_numbers)
+            .map(|i| self.numbers[self.curr2orig[&i]])
+            .collect()
     }
     fn circle_add(&self, index: usize, number: i128) -> usize {
         let mut ni = index as i128 + number;
@@ -92,8 +92,7 @@ impl TaskData {
     //
     fn mix(&mut self) {
         for (orig, num) in self.numbers.iter().enumerate() {
-            //self.debug();
-            //println!("moving {}", *num);
+            trace!("moving {}", *num);
             let curr = self.orig2curr[&orig];
             let new_curr_for_orig = self.circle_add(curr, *num);
             // (orig, new_curr_for_orig)
@@ -116,11 +115,11 @@ impl TaskData {
                 self.curr2orig.insert(curr_to_update, orig_from_left);
                 self.orig2curr.insert(orig_from_left, curr_to_update);
             }
-            // println!("insert {} <--> {}", orig, new_curr_for_orig);
+            trace!("insert {} <--> {}", orig, new_curr_for_orig);
             self.curr2orig.insert(new_curr_for_orig, orig);
             self.orig2curr.insert(orig, new_curr_for_orig);
         }
-        //self.debug();
+        trace!("{:?}", self.arrangement());
     }
     fn apply_encrypt(&mut self, encrypt: i128) {
         for (i, num) in self.numbers.iter_mut().enumerate() {
@@ -142,11 +141,10 @@ impl TaskData {
     }
 }
 
-fn parse_input(input: &str) -> Result<TaskData> {
-    let numbers: Vec<i128> = input
-        .lines()
-        .map(|l| i128::from_str_radix(l, 10).unwrap())
-        .collect();
+fn parse_input(input: &str) -> Result<TaskData, ParseError> {
+    let numbers = input::lines(input)
+        .map(|l| parse::value(input, l, "a number"))
+        .collect::<Result<Vec<i128>, _>>()?;
     let amount_numbers = numbers.len();
     let mut orig2curr = HashMap::new();
     let mut curr2orig = HashMap::new();
@@ -158,6 +156,9 @@ fn parse_input(input: &str) -> Result<TaskData> {
         orig2curr.insert(i, i);
         curr2orig.insert(i, i);
     }
+    if orig_zero == amount_numbers {
+        return Err(ParseError::missing(input, "a 0 somewhere in the file"));
+    }
     Ok(TaskData {
         numbers: numbers.clone(),
         decrypt_numbers: numbers.clone(),
@@ -168,87 +169,44 @@ fn parse_input(input: &str) -> Result<TaskData> {
     })
 }
 
-fn part_one(input: &str) -> Result<i128> {
-    let mut data = parse_input(input)?;
-    data.mix();
-    let (x, y, z) = data.get_coordinates();
-    println!("{} {} {}", x, y, z);
-    Ok(x + y + z)
-}
-
-fn part_two(input: &str) -> Result<i128> {
-    let mut data = parse_input(input)?;
-    data.apply_encrypt(811589153);
-    for i in 0..10 {
-        println!("{}", i);
-        data.mix();
+aoc_core::params! {
+    pub struct Params {
+        /// The decryption key of part two
+        key: i128 = 811_589_153,
+        /// How often part two mixes
+        mixes: usize = 10,
     }
-    let (x, y, z) = data.get_coordinates();
-    println!("{} {} {}", x, y, z);
-    Ok(x + y + z)
-}
-
-fn main() -> Result<()> {
-    let mut input = String::new();
-    io::stdin().read_to_string(&mut input)?;
-    let t = std::time::Instant::now();
-    let part_one = part_one(&input)?;
-    let elapsed = t.elapsed();
-    println!("Part one: {} in {:0.2?}", part_one, elapsed);
-    let t = std::time::Instant::now();
-    let part_two = part_two(&input)?;
-    let elapsed = t.elapsed();
-    println!("Part two: {} in {:0.2?}", part_two, elapsed);
-    Ok(())
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use lazy_static::lazy_static;
-    use std::fs;
-
-    lazy_static! {
-        static ref TEST: String = read_from_file("test.txt");
-        static ref INPUT: String = read_from_file("input.txt");
-    }
+pub struct GrovePositioningSystem;
 
-    fn read_from_file(filename: &str) -> String {
-        fs::read_to_string(filename)
-            .unwrap_or_else(|msg| panic!("error reading {}: {}", filename, msg))
-    }
+impl Solution for GrovePositioningSystem {
+    type Input = TaskData;
 
-    #[test]
-    fn test_one() -> Result<()> {
-        let answer = super::part_one(&TEST)?;
-        assert_eq!(answer, 3);
-        Ok(())
+    fn parse_input(input: &str) -> Result<TaskData, ParseError> {
+        parse_input(input)
     }
 
-    // Use "cargo test --release -- part_one --nocapture" to print the time
-    #[test]
-    fn part_one() -> Result<()> {
-        let t = std::time::Instant::now();
-        let answer = super::part_one(&INPUT)?;
-        eprintln!("Part one took {:0.2?}", t.elapsed());
-        assert_eq!(answer, 9687);
-        Ok(())
+    fn part_one(mut data: Self::Input) -> Result<Answer> {
+        data.mix();
+        let (x, y, z) = data.get_coordinates();
+        debug!("coordinates {} {} {}", x, y, z);
+        Ok(Answer::from(x + y + z))
     }
 
-    #[test]
-    fn test_two() -> Result<()> {
-        let answer = super::part_two(&TEST)?;
-        assert_eq!(answer, 1623178306);
-        Ok(())
+    fn part_two(mut data: Self::Input) -> Result<Answer> {
+        let params = Params::get()?;
+        data.apply_encrypt(params.key);
+        for i in 0..params.mixes {
+            debug!("mixing round {}", i);
+            data.mix();
+        }
+        let (x, y, z) = data.get_coordinates();
+        debug!("coordinates {} {} {}", x, y, z);
+        Ok(Answer::from(x + y + z))
     }
 
-    // Use "cargo test --release -- part_two --nocapture" to print the time
-    #[test]
-    fn part_two() -> Result<()> {
-        let t = std::time::Instant::now();
-        let answer = super::part_two(&INPUT)?;
-        eprintln!("Part two took {:0.2?}", t.elapsed());
-        assert_eq!(answer, 1338310513297);
-        Ok(())
+    fn params() -> Vec<(&'static str, String)> {
+        Params::defaults()
     }
 }
@@ -0,0 +1,335 @@
+use anyhow::{bail, Result};
+use aoc_core::{input, parse, Answer, ParseError, Solution};
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::collections::HashMap;
+
+#[derive(Debug)]
+enum MonkeyKind {
+    Num(i128),
+    Op(char),
+}
+
+#[derive(Debug)]
+struct Monkey {
+    name: String,
+    kind: MonkeyKind,
+    influenced_by_human: bool,
+}
+
+#[derive(Debug)]
+pub struct TaskData {
+    monkeys: HashMap<String, Monkey>,
+    monkey_connections: HashMap<String, Vec<String>>,
+}
+
+impl TaskData {
+    fn yell_from(&self, monkey_name: &str) -> i128 {
+        let monkey = &self.monkeys[monkey_name];
+        match monkey.kind {
+            MonkeyKind::Num(yell) => yell,
+            MonkeyKind::Op(op) => {
+                let monkeys = self.monkey_connections[monkey_name].clone();
+                let a = self.yell_from(&monkeys[0]);
+                let b = self.yell_from(&monkeys[1]);
+                match op {
+                    '*' => a * b,
+                    '/' => a / b,
+                    '+' => a + b,
+                    '-' => a - b,
+                    _ => panic!("unknown operand: {}", op),
+                }
+            }
+        }
+    }
+    fn update_influenced_by_human(&mut self, monkey_name: &str) -> bool {
+        if monkey_name == "humn" {
+            self.monkeys
+                .get_mut(monkey_name)
+                .unwrap()
+                .influenced_by_human = true;
+            return true;
+        }
+        let monkey = &self.monkeys[monkey_name];
+        if let MonkeyKind::Num(_) = monkey.kind {
+            return false;
+        }
+        let monkeys = self.monkey_connections[monkey_name].clone();
+        let a = self.update_influenced_by_human(&monkeys[0]);
+        let b = self.update_influenced_by_human(&monkeys[1]);
+        if a && b {
+            panic!("human influence to great");
+        }
+        let influenced = a || b;
+        let monkey = self.monkeys.get_mut(monkey_name).unwrap();
+        monkey.influenced_by_human = influenced;
+        influenced
+    }
+    fn solve_for_left(right: i128, op: char, target: i128) -> i128 {
+        // x 'op' right == target
+        // x - right == target => x == target + right
+        // x + right == target => x == target - right
+        // x * right == target => x == target / right
+        // x / right == target => x == target * right
+        match op {
+            '-' => target + right,
+            '+' => target - right,
+            '*' => target / right,
+            '/' => target * right,
+            _ => panic!("can't solve for unknown op"),
+        }
+    }
+    fn solve_for_right(left: i128, op: char, target: i128) -> i128 {
+        // left 'op' x == target
+        // left - x == target => x == left - target
+        // left + x == target => x == target - left
+        // left * x == target => x == target / left
+        // left / x == target => x == left / target
+        match op {
+            '-' => left - target,
+            '+' => target - left,
+            '*' => target / left,
+            '/' => left / target,
+            _ => panic!("can't solve for unknown op"),
+        }
+    }
+    fn find_human_yell(&self, monkey_name: &str, target: i128) -> i128 {
+        // root may ask the human directly
+        if monkey_name == "humn" {
+            return target;
+        }
+        let monkey = &self.monkeys[monkey_name];
+        let monkeys = self.monkey_connections[monkey_name].clone();
+        let (left_name, right_name) = (monkeys[0].clone(), monkeys[1].clone());
+        let left_monkey = &self.monkeys[&left_name];
+        let _right_monkey = &self.monkeys[&right_name];
+        let humn = String::from("humn");
+        let op = match monkey.kind {
+            MonkeyKind::Op(c) => c,
+            _ => panic!("find_human_yell called on leave"),
+        };
+        if left_name == humn {
+            let right_value = self.yell_from(&right_name);
+            Self::solve_for_left(right_value, op, target)
+        } else if right_name == humn {
+            let left_value = self.yell_from(&left_name);
+            Self::solve_for_right(left_value, op, target)
+        } else {
+            if left_monkey.influenced_by_human {
+                let right_val = self.yell_from(&right_name);
+                let next_target = Self::solve_for_left(right_val, op, target);
+                self.find_human_yell(&left_name, next_target)
+            } else {
+                let left_val = self.yell_from(&left_name);
+                let next_target = Self::solve_for_right(left_val, op, target);
+                self.find_human_yell(&right_name, next_target)
+            }
+        }
+    }
+    fn what_should_human_yell(&mut self) -> Result<i128> {
+        let Some(monkeys) = self.monkey_connections.get("root").cloned() else {
+            bail!("root yells a number instead of comparing two monkeys");
+        };
+        self.update_influenced_by_human("root");
+        let a = &self.monkeys[&monkeys[0]];
+        let b = &self.monkeys[&monkeys[1]];
+        if a.influenced_by_human && b.influenced_by_human {
+            panic!("too much human influence");
+        }
+        if a.influenced_by_human {
+            let target = self.yell_from(&b.name);
+            Ok(self.find_human_yell(&a.name, target))
+        } else {
+            let target = self.yell_from(&a.name);
+            Ok(self.find_human_yell(&b.name, target))
+        }
+    }
+}
+
+fn parse_input(input: &str) -> Result<TaskData, ParseError> {
+    lazy_static! {
+        static ref OP_MONKEY: Regex = Regex::new(r"^(\w+): (\w+) ([+\-*/]) (\w+)$").unwrap();
+        static ref NUM_MONKEY: Regex = Regex::new(r"^(\w+): (\d+)$").unwrap();
+    }
+    let mut monkeys = HashMap::new();
+    let mut monkey_connections = HashMap::new();
+    let mut operands = Vec::new();
+    for line in input::lines(input) {
+        if let Some(caps) = OP_MONKEY.captures(line) {
+            operands.extend([caps.get(2).unwrap().as_str(), caps.get(4).unwrap().as_str()]);
+            let name = String::from(&caps[1]);
+            let a = String::from(&caps[2]);
+            // the regex only matches a single ASCII operator
+            let op = caps[3].as_bytes()[0] as char;
+            let b = String::from(&caps[4]);
+            monkeys.insert(
+                name.clone(),
+                Monkey {
+                    name: name.clone(),
+                    kind: MonkeyKind::Op(op),
+                    influenced_by_human: false,
+                },
+            );
+            monkey_connections.insert(name, vec![a, b]);
+        } else {
+            let caps = parse::captures(&NUM_MONKEY, input, line)?;
+            let name = String::from(&caps[1]);
+            let num = parse::value(input, &caps[2], "a number")?;
+            monkeys.insert(
+                name.clone(),
+                Monkey {
+                    name: name.clone(),
+                    kind: MonkeyKind::Num(num),
+                    influenced_by_human: false,
+                },
+            );
+        }
+    }
+    // the yells can't depend on monkeys that aren't there
+    if let Some(unknown) = operands
+        .into_iter()
+        .find(|name| !monkeys.contains_key(*name))
+    {
+        return Err(ParseError::at(
+            input,
+            unknown,
+            "a monkey with a line of its own",
+        ));
+    }
+    for name in ["root", "humn"] {
+        if !monkeys.contains_key(name) {
+            return Err(ParseError::missing(
+                input,
+                format!("a monkey named {}", name),
+            ));
+        }
+    }
+    Ok(TaskData {
+        monkeys,
+        monkey_connections,
+    })
+}
+
+pub struct MonkeyMath;
+
+impl Solution for MonkeyMath {
+    type Input = TaskData;
+
+    fn parse_input(input: &str) -> Result<TaskData, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(data: Self::Input) -> Result<Answer> {
+        let answer = data.yell_from("root");
+        Ok(Answer::from(answer))
+    }
+
+    fn part_two(mut data: Self::Input) -> Result<Answer> {
+        let answer = data.what_should_human_yell()?;
+        Ok(Answer::from(answer))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::differential::{assert_agree, proptest::prelude::*};
+
+    // tries the yells until both sides of root are equal
+    fn brute_force_part_two(
+        input: &str,
+        yells: impl IntoIterator<Item = i128>,
+    ) -> Result<Option<i128>> {
+        let mut data = parse_input(input)?;
+        let root = data.monkeys.get_mut("root").unwrap();
+        root.kind = MonkeyKind::Op('-');
+        for try_yell in yells {
+            let human = data.monkeys.get_mut("humn").unwrap();
+            human.kind = MonkeyKind::Num(try_yell);
+            if data.yell_from("root") == 0 {
+                return Ok(Some(try_yell));
+            }
+        }
+        Ok(None)
+    }
+
+    struct Riddle {
+        lines: Vec<String>,
+    }
+
+    impl Riddle {
+        fn monkey(&mut self, job: String) -> String {
+            let name = format!("m{:03}", self.lines.len());
+            self.lines.push(format!("{}: {}", name, job));
+            name
+        }
+
+        // monkeys that yell `value` in the end, divided by `k` if it's not 1
+        fn constant(&mut self, value: i128, k: i128) -> String {
+            if value < 0 {
+                let zero = self.monkey(String::from("0"));
+                let minus = self.constant(-value, k);
+                return self.monkey(format!("{} - {}", zero, minus));
+            }
+            if k == 1 {
+                return self.monkey(value.to_string());
+            }
+            let dividend = self.monkey((value * k).to_string());
+            let divisor = self.monkey(k.to_string());
+            self.monkey(format!("{} / {}", dividend, divisor))
+        }
+    }
+
+    // one way from humn to root without divisions, so exactly one yell
+    // makes root's sides equal
+    fn riddles() -> impl Strategy<Value = String> {
+        let step = (0..3usize, any::<bool>(), 1..20i128, 1..4i128);
+        (0..1000i128, prop::collection::vec(step, 0..12)).prop_map(|(human, steps)| {
+            let mut riddle = Riddle {
+                lines: vec![String::from("humn: 5")],
+            };
+            let (mut path, mut value) = (String::from("humn"), human);
+            for (op, human_left, c, k) in steps {
+                let constant = riddle.constant(c, k);
+                let op = ['+', '-', '*'][op];
+                value = match (op, human_left) {
+                    ('+', _) => value + c,
+                    ('-', true) => value - c,
+                    ('-', false) => c - value,
+                    _ => value * c,
+                };
+                let (left, right) = if human_left {
+                    (path, constant)
+                } else {
+                    (constant, path)
+                };
+                path = riddle.monkey(format!("{} {} {}", left, op, right));
+            }
+            let other = riddle.constant(value, 1);
+            riddle.lines.push(format!("root: {} + {}", path, other));
+            riddle.lines.join("\n")
+        })
+    }
+
+    #[test]
+    fn monkeys_wait_for_monkeys_in_the_list() {
+        let input = "root: humn + pppw\n\
+                     humn: 5\n";
+        let err = parse_input(input).unwrap_err();
+        assert_eq!((err.line, err.column), (1, 14));
+        assert_eq!(err.expected, "a monkey with a line of its own");
+        let err = parse_input("root: aaaa + bbbb\naaaa: 1\nbbbb: 2\n").unwrap_err();
+        assert_eq!(err.expected, "a monkey named humn");
+        let mut data = parse_input("root: 5\nhumn: 5\n").unwrap();
+        assert!(data.what_should_human_yell().is_err());
+    }
+
+    #[test]
+    fn what_should_human_yell_agrees_with_brute_force() {
+        assert_agree(
+            riddles(),
+            |input| parse_input(input).unwrap().what_should_human_yell().ok(),
+            |input| brute_force_part_two(input, 0..1000).unwrap(),
+        );
+    }
+}
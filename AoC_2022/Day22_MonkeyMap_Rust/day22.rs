@@ -1,7 +1,8 @@
 use anyhow::Result;
+use aoc_core::{input, parse, record, Answer, Grid, ParseError, Solution};
 use lazy_static::lazy_static;
+use log::{debug, trace};
 use regex::Regex;
-use std::io::{self, Read};
 
 #[derive(Debug, Clone)]
 enum Command {
@@ -18,31 +19,29 @@ enum Command {
 // }
 
 #[derive(Debug)]
-struct TaskData {
+pub struct TaskData {
     position: ((i128, i128), usize), // (pos, facing)
     range_on_row: Vec<(i128, i128)>, // end exclusive
-    _rows: usize,
     range_on_col: Vec<(i128, i128)>, // end exclusive
-    _cols: usize,
-    the_map: Vec<Vec<char>>,
+    the_map: Grid<char>,
     commands: Vec<Command>,
     warping_cube: bool,
     invert_turns: bool,
 }
 impl TaskData {
-    fn _print(&self) {
-        //print!("\x1B[2J\x1B[1;1H");
-        //print!("{esc}c", esc = 27 as char);
-        for row in 0..self._rows {
-            for col in 0..self._cols {
+    fn render(&self) -> String {
+        let mut out = String::new();
+        for row in 0..self.the_map.rows() {
+            for col in 0..self.the_map.cols() {
                 if self.position.0 == (row as i128, col as i128) {
-                    print!("X");
+                    out.push('X');
                 } else {
-                    print!("{}", self.the_map[row][col]);
+                    out.push(self.the_map[(row, col)]);
                 }
             }
-            println!("");
+            out.push('\n');
         }
+        out
     }
 
     fn turn_right(&mut self) {
@@ -127,8 +126,7 @@ impl TaskData {
 
         if Self::in_area(pos, a_range_row, a_range_col) {
             if npos.1 < a_range_col.0 {
-                println!("a left");
-                println!("before {:?},{} -> {:?}", pos, facing, npos);
+                trace!("a left before {:?},{} -> {:?}", pos, facing, npos);
 
                 assert_eq!(facing, &2);
                 // a left: ar1 => cr4, new facing: right
@@ -137,12 +135,11 @@ impl TaskData {
                 npos.0 = c_row;
                 npos.1 = c_range_col.0;
                 *facing = 0;
-                println!("after {:?} -> {:?},{}", pos, npos, facing);
+                trace!("after {:?} -> {:?},{}", pos, npos, facing);
                 assert!(Self::in_area(*npos, c_range_row, c_range_col));
                 //self.invert_turns = !self.invert_turns;
             } else if npos.0 < a_range_row.0 {
-                println!("a up");
-                println!("before {:?},{} -> {:?}", pos, facing, npos);
+                trace!("a up before {:?},{} -> {:?}", pos, facing, npos);
 
                 assert_eq!(facing, &3);
                 // a up: ac1 => er1, new facing: right
@@ -151,14 +148,13 @@ impl TaskData {
                 npos.0 = e_row;
                 npos.1 = e_range_col.0;
                 *facing = 0;
-                println!("after {:?} -> {:?},{}", pos, npos, facing);
+                trace!("after {:?} -> {:?},{}", pos, npos, facing);
                 assert!(Self::in_area(*npos, e_range_row, e_range_col));
                 //self.invert_turns = !self.invert_turns;
             }
         } else if Self::in_area(pos, x_range_row, x_range_col) {
             if npos.1 > x_range_col.1 {
-                println!("x right");
-                println!("before {:?},{} -> {:?}", pos, facing, npos);
+                trace!("x right before {:?},{} -> {:?}", pos, facing, npos);
 
                 assert_eq!(facing, &0);
                 // x right: xr1 => dr4, new facing: left
@@ -167,12 +163,11 @@ impl TaskData {
                 npos.0 = d_row;
                 npos.1 = d_range_col.1;
                 *facing = 2;
-                println!("after {:?} -> {:?},{}", pos, npos, facing);
+                trace!("after {:?} -> {:?},{}", pos, npos, facing);
                 assert!(Self::in_area(*npos, d_range_row, d_range_col));
                 //self.invert_turns = !self.invert_turns;
             } else if npos.0 > x_range_row.1 {
-                println!("x down");
-                println!("before {:?},{} -> {:?}", pos, facing, npos);
+                trace!("x down before {:?},{} -> {:?}", pos, facing, npos);
 
                 assert_eq!(facing, &1);
                 // x down: xc1 => br1, new facing: left
@@ -181,12 +176,11 @@ impl TaskData {
                 npos.0 = b_row;
                 npos.1 = b_range_col.1;
                 *facing = 2;
-                println!("after {:?} -> {:?},{}", pos, npos, facing);
+                trace!("after {:?} -> {:?},{}", pos, npos, facing);
                 assert!(Self::in_area(*npos, b_range_row, b_range_col));
                 //self.invert_turns = !self.invert_turns;
             } else if npos.0 < x_range_row.0 {
-                println!("x up");
-                println!("before {:?},{} -> {:?}", pos, facing, npos);
+                trace!("x up before {:?},{} -> {:?}", pos, facing, npos);
 
                 assert_eq!(facing, &3);
                 // x up: xc1 => ec1, new facing: up
@@ -195,13 +189,12 @@ impl TaskData {
                 npos.0 = e_range_row.1;
                 npos.1 = e_col;
                 *facing = 3;
-                println!("after {:?} -> {:?},{}", pos, npos, facing);
+                trace!("after {:?} -> {:?},{}", pos, npos, facing);
                 assert!(Self::in_area(*npos, e_range_row, e_range_col));
             }
         } else if Self::in_area(pos, b_range_row, b_range_col) {
             if npos.1 < b_range_col.0 {
-                println!("b left");
-                println!("before {:?},{} -> {:?}", pos, facing, npos);
+                trace!("b left before {:?},{} -> {:?}", pos, facing, npos);
 
                 assert_eq!(facing, &2);
                 // b left: br1 => cc1, new facing: down
@@ -210,12 +203,11 @@ impl TaskData {
                 npos.0 = c_range_row.0;
                 npos.1 = c_col;
                 *facing = 1;
-                println!("after {:?} -> {:?},{}", pos, npos, facing);
+                trace!("after {:?} -> {:?},{}", pos, npos, facing);
                 assert!(Self::in_area(*npos, c_range_row, c_range_col));
                 //self.invert_turns = !self.invert_turns;
             } else if npos.1 > b_range_col.1 {
-                println!("b right");
-                println!("before {:?},{} -> {:?}", pos, facing, npos);
+                trace!("b right before {:?},{} -> {:?}", pos, facing, npos);
 
                 assert_eq!(facing, &0);
                 // b right: br1 => xc1, new facing: up
@@ -224,14 +216,13 @@ impl TaskData {
                 npos.0 = x_range_row.1;
                 npos.1 = x_col;
                 *facing = 3;
-                println!("after {:?} -> {:?},{}", pos, npos, facing);
+                trace!("after {:?} -> {:?},{}", pos, npos, facing);
                 assert!(Self::in_area(*npos, x_range_row, x_range_col));
                 //self.invert_turns = !self.invert_turns;
             }
         } else if Self::in_area(pos, c_range_row, c_range_col) {
             if npos.1 < c_range_col.0 {
-                println!("c left");
-                println!("before {:?},{} -> {:?}", pos, facing, npos);
+                trace!("c left before {:?},{} -> {:?}", pos, facing, npos);
 
                 assert_eq!(facing, &2);
                 // c left: cr1 => ar4, new facing: right
@@ -240,12 +231,11 @@ impl TaskData {
                 npos.0 = a_row;
                 npos.1 = a_range_col.0;
                 *facing = 0;
-                println!("after {:?} -> {:?},{}", pos, npos, facing);
+                trace!("after {:?} -> {:?},{}", pos, npos, facing);
                 assert!(Self::in_area(*npos, a_range_row, a_range_col));
                 //self.invert_turns = !self.invert_turns;
             } else if npos.0 < c_range_row.0 {
-                println!("c up");
-                println!("before {:?},{} -> {:?}", pos, facing, npos);
+                trace!("c up before {:?},{} -> {:?}", pos, facing, npos);
 
                 assert_eq!(facing, &3);
                 // c up: cc1 => br1, new facing: right
@@ -254,14 +244,13 @@ impl TaskData {
                 npos.0 = b_row;
                 npos.1 = b_range_col.0;
                 *facing = 0;
-                println!("after {:?} -> {:?},{}", pos, npos, facing);
+                trace!("after {:?} -> {:?},{}", pos, npos, facing);
                 assert!(Self::in_area(*npos, b_range_row, b_range_col));
                 //self.invert_turns = !self.invert_turns;
             }
         } else if Self::in_area(pos, d_range_row, d_range_col) {
             if npos.1 > d_range_col.1 {
-                println!("d right");
-                println!("before {:?},{} -> {:?}", pos, facing, npos);
+                trace!("d right before {:?},{} -> {:?}", pos, facing, npos);
 
                 assert_eq!(facing, &0);
                 // d right: dr1 => xr4, new facing: left
@@ -270,12 +259,11 @@ impl TaskData {
                 npos.0 = x_row;
                 npos.1 = x_range_col.1;
                 *facing = 2;
-                println!("after {:?} -> {:?},{}", pos, npos, facing);
+                trace!("after {:?} -> {:?},{}", pos, npos, facing);
                 assert!(Self::in_area(*npos, x_range_row, x_range_col));
                 //self.invert_turns = !self.invert_turns;
             } else if npos.0 > d_range_row.1 {
-                println!("d down");
-                println!("before {:?},{} -> {:?}", pos, facing, npos);
+                trace!("d down before {:?},{} -> {:?}", pos, facing, npos);
 
                 assert_eq!(facing, &1);
                 // d down: dc1 => er1, new facing: left
@@ -284,14 +272,13 @@ impl TaskData {
                 npos.0 = e_row;
                 npos.1 = e_range_col.1;
                 *facing = 2;
-                println!("after {:?} -> {:?},{}", pos, npos, facing);
+                trace!("after {:?} -> {:?},{}", pos, npos, facing);
                 assert!(Self::in_area(*npos, e_range_row, e_range_col));
                 //self.invert_turns = !self.invert_turns;
             }
         } else if Self::in_area(pos, e_range_row, e_range_col) {
             if npos.1 > e_range_col.1 {
-                println!("e right");
-                println!("before {:?},{} -> {:?}", pos, facing, npos);
+                trace!("e right before {:?},{} -> {:?}", pos, facing, npos);
 
                 assert_eq!(facing, &0);
                 // e right: er1 => dc1, new facing: up
@@ -300,12 +287,11 @@ impl TaskData {
                 npos.0 = d_range_row.1;
                 npos.1 = d_col;
                 *facing = 3;
-                println!("after {:?} -> {:?},{}", pos, npos, facing);
+                trace!("after {:?} -> {:?},{}", pos, npos, facing);
                 assert!(Self::in_area(*npos, d_range_row, d_range_col));
                 //self.invert_turns = !self.invert_turns;
             } else if npos.0 > e_range_row.1 {
-                println!("e down");
-                println!("before {:?},{} -> {:?}", pos, facing, npos);
+                trace!("e down before {:?},{} -> {:?}", pos, facing, npos);
 
                 assert_eq!(facing, &1);
                 // e down: ec1 => xc1, new facing down
@@ -314,11 +300,10 @@ impl TaskData {
                 npos.0 = x_range_row.0;
                 npos.1 = x_col;
                 *facing = 1;
-                println!("after {:?} -> {:?},{}", pos, npos, facing);
+                trace!("after {:?} -> {:?},{}", pos, npos, facing);
                 assert!(Self::in_area(*npos, x_range_row, x_range_col));
             } else if npos.1 < e_range_col.0 {
-                println!("e left");
-                println!("before {:?},{} -> {:?}", pos, facing, npos);
+                trace!("e left before {:?},{} -> {:?}", pos, facing, npos);
 
                 assert_eq!(facing, &2);
                 // e left: er1 => ac1, new facing down
@@ -327,7 +312,7 @@ impl TaskData {
                 npos.0 = a_range_row.0;
                 npos.1 = a_col;
                 *facing = 1;
-                println!("after {:?} -> {:?},{}", pos, npos, facing);
+                trace!("after {:?} -> {:?},{}", pos, npos, facing);
                 assert!(Self::in_area(*npos, a_range_row, a_range_col));
                 //self.invert_turns = !self.invert_turns;
             }
@@ -336,12 +321,11 @@ impl TaskData {
         }
     }
     fn forward(&mut self, amount: i128) {
-        let drow = vec![0, 1, 0, -1];
-        let dcol = vec![1, 0, -1, 0];
+        let drow = [0, 1, 0, -1];
+        let dcol = [1, 0, -1, 0];
 
         for _step in 0..amount {
-            // self.print();
-            // std::thread::sleep(Duration::from_millis(200));
+            record::frame(|| self.render());
             let pos = self.position.0;
             let mut facing = self.position.1;
             let mut npos = (pos.0 + drow[facing], pos.1 + dcol[facing]);
@@ -350,12 +334,12 @@ impl TaskData {
             } else {
                 self.determine_warping_cube(pos, &mut npos, &mut facing);
             }
-            let check = self.the_map[npos.0 as usize][npos.1 as usize];
+            let check = self.the_map[(npos.0 as usize, npos.1 as usize)];
             if check != '#' {
                 assert_ne!(check, ' ');
                 self.position = (npos, facing);
                 // only for rendering
-                self.the_map[pos.0 as usize][pos.1 as usize] = match facing {
+                self.the_map[(pos.0 as usize, pos.1 as usize)] = match facing {
                     0 => '>',
                     1 => 'v',
                     2 => '<',
@@ -370,7 +354,7 @@ impl TaskData {
     fn execute_commands(&mut self) {
         let commands = self.commands.clone();
         for command in commands {
-            //println!("{:?}", command);
+            trace!("{:?}", command);
             match command {
                 Command::Forward(amount) => self.forward(amount),
                 Command::Turn(dir) => match dir {
@@ -401,32 +385,40 @@ impl TaskData {
     }
 }
 
-fn parse_input(input: &str) -> Result<TaskData> {
-    let mut split = input.split("\r\n\r\n");
-    let map_input = split.next().unwrap();
-    let commands_input = split.next().unwrap();
-    let commands = parse_commands(commands_input);
+fn parse_input(input: &str) -> Result<TaskData, ParseError> {
+    let mut blocks = input::blocks(input).into_iter();
+    let map_input = parse::next(&mut blocks, input, "the map")?;
+    let commands_input = parse::next(&mut blocks, input, "the path")?;
+    if let Some(i) = map_input.find(|c| !" .#\r\n".contains(c)) {
+        return Err(ParseError::at(
+            input,
+            &map_input[i..],
+            "a tile, a wall or a space",
+        ));
+    }
+    let commands = parse_commands(input, commands_input)?;
 
     let mut range_on_row: Vec<(i128, i128)> = Vec::new();
     let mut range_on_col: Vec<(i128, i128)> = Vec::new();
-    let mut the_map: Vec<Vec<char>> = map_input.lines().map(|l| l.chars().collect()).collect();
-    let length_longest_line = the_map.iter().map(|v| v.len()).max().unwrap();
-    for line in the_map.iter_mut() {
+    let mut lines = input::grid(map_input);
+    let length_longest_line = lines.iter().map(|v| v.len()).max().unwrap_or(0);
+    for line in lines.iter_mut() {
         let current_length = line.len();
         let missing = length_longest_line - current_length;
         if missing > 0 {
             line.append(&mut vec![' '; missing]);
         }
     }
-    let rows = the_map.len();
-    let cols = the_map[0].len();
+    let the_map = Grid::from_rows(lines);
+    let rows = the_map.rows();
+    let cols = the_map.cols();
     // scan rows
     for row in 0..rows {
         let mut previous = ' ';
         let mut beginning = 0;
         let mut end = cols;
         for col in 0..cols {
-            let current = the_map[row][col];
+            let current = the_map[(row, col)];
             if previous == ' ' && current != ' ' {
                 beginning = col;
             }
@@ -443,7 +435,7 @@ fn parse_input(input: &str) -> Result<TaskData> {
         let mut beginning = 0;
         let mut end = rows;
         for row in 0..rows {
-            let current = the_map[row][col];
+            let current = the_map[(row, col)];
             if previous == ' ' && current != ' ' {
                 beginning = row;
             }
@@ -457,116 +449,57 @@ fn parse_input(input: &str) -> Result<TaskData> {
     Ok(TaskData {
         position: ((0, range_on_row[0].0), 0),
         range_on_row,
-        _rows: rows,
         range_on_col,
-        _cols: cols,
         the_map,
         commands,
         warping_cube: false,
         invert_turns: false,
     })
 }
-fn parse_commands(input: &str) -> Vec<Command> {
+fn parse_commands(input: &str, commands_input: &str) -> Result<Vec<Command>, ParseError> {
+    if let Some(i) = commands_input.find(|c: char| !(c.is_ascii_digit() || c == 'L' || c == 'R')) {
+        return Err(ParseError::at(
+            input,
+            &commands_input[i..],
+            "a number, L or R",
+        ));
+    }
     let mut commands = Vec::new();
     lazy_static! {
         static ref RE: Regex = Regex::new(r"(\d+|[LR])").unwrap();
     }
-    for cap in RE.captures_iter(input) {
+    for cap in RE.captures_iter(commands_input) {
         let part = &cap[1];
-        if let Ok(amount) = i128::from_str_radix(part, 10) {
-            commands.push(Command::Forward(amount));
-        } else {
-            if part.len() != 1 {
-                panic!("something is wrong");
-            }
-            commands.push(Command::Turn(part.chars().nth(0).unwrap()));
+        match part {
+            "L" => commands.push(Command::Turn('L')),
+            "R" => commands.push(Command::Turn('R')),
+            amount => commands.push(Command::Forward(parse::value(input, amount, "a number")?)),
         }
     }
-    commands
-}
-
-fn part_one(input: &str) -> Result<i128> {
-    let mut data = parse_input(input)?;
-    //println!("{:?}", data);
-    data.execute_commands();
-    //data.print();
-    let password = data.get_password();
-    Ok(password)
-}
-
-fn part_two(input: &str) -> Result<i128> {
-    let mut data = parse_input(input)?;
-    data.warping_cube = true;
-    //println!("{:?}", data);
-    data.execute_commands();
-    //data.print();
-    let password = data.get_password();
-    Ok(password)
-}
-
-fn main() -> Result<()> {
-    let mut input = String::new();
-    io::stdin().read_to_string(&mut input)?;
-    let t = std::time::Instant::now();
-    let part_one = part_one(&input)?;
-    let elapsed = t.elapsed();
-    println!("Part one: {} in {:0.2?}", part_one, elapsed);
-    let t = std::time::Instant::now();
-    let part_two = part_two(&input)?;
-    let elapsed = t.elapsed();
-    println!("Part two: {} in {:0.2?}", part_two, elapsed);
-    Ok(())
+    Ok(commands)
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use lazy_static::lazy_static;
-    use std::fs;
+pub struct MonkeyMap;
 
-    lazy_static! {
-        static ref TEST: String = read_from_file("test.txt");
-        static ref INPUT: String = read_from_file("input.txt");
-    }
-
-    fn read_from_file(filename: &str) -> String {
-        fs::read_to_string(filename)
-            .unwrap_or_else(|msg| panic!("error reading {}: {}", filename, msg))
-    }
+impl Solution for MonkeyMap {
+    type Input = TaskData;
 
-    #[test]
-    fn test_one() -> Result<()> {
-        let answer = super::part_one(&TEST)?;
-        assert_eq!(answer, 6032);
-        Ok(())
+    fn parse_input(input: &str) -> Result<TaskData, ParseError> {
+        parse_input(input)
     }
 
-    // Use "cargo test --release -- part_one --nocapture" to print the time
-    #[test]
-    fn part_one() -> Result<()> {
-        let t = std::time::Instant::now();
-        let answer = super::part_one(&INPUT)?;
-        eprintln!("Part one took {:0.2?}", t.elapsed());
-        assert_eq!(answer, 13566);
-        Ok(())
+    fn part_one(mut data: Self::Input) -> Result<Answer> {
+        data.execute_commands();
+        debug!("\n{}", data.render());
+        let password = data.get_password();
+        Ok(Answer::from(password))
     }
 
-    // TODO:
-    // To make this test work a general cube assembly routine will be needed
-    // #[test]
-    // fn test_two() -> Result<()> {
-    //     let answer = super::part_two(&TEST)?;
-    //     assert_eq!(answer, 5031);
-    //     Ok(())
-    // }
-
-    // Use "cargo test --release -- part_two --nocapture" to print the time
-    #[test]
-    fn part_two() -> Result<()> {
-        let t = std::time::Instant::now();
-        let answer = super::part_two(&INPUT)?;
-        eprintln!("Part two took {:0.2?}", t.elapsed());
-        assert_eq!(answer, 11451);
-        Ok(())
+    fn part_two(mut data: Self::Input) -> Result<Answer> {
+        data.warping_cube = true;
+        data.execute_commands();
+        debug!("\n{}", data.render());
+        let password = data.get_password();
+        Ok(Answer::from(password))
     }
 }
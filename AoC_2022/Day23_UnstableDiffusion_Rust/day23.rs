@@ -0,0 +1,149 @@
+use anyhow::Result;
+use aoc_core::{input, record, Answer, ParseError, Solution, Vec2};
+use log::trace;
+use std::collections::{HashMap, HashSet};
+
+// the three positions an elf looks at before moving north, south, west or
+// east, the middle one is where it moves
+const LOOK: [[Vec2; 3]; 4] = [
+    [Vec2::new(-1, -1), Vec2::new(0, -1), Vec2::new(1, -1)], // N
+    [Vec2::new(-1, 1), Vec2::new(0, 1), Vec2::new(1, 1)],    // S
+    [Vec2::new(-1, -1), Vec2::new(-1, 0), Vec2::new(-1, 1)], // W
+    [Vec2::new(1, -1), Vec2::new(1, 0), Vec2::new(1, 1)],    // E
+];
+
+pub struct TaskData {
+    elves: HashSet<Vec2>,
+    current: usize, // 0 North, 1 South, 2 West, 3 East
+}
+
+type DestinationCount = HashMap<Vec2, i128>;
+type Moves = HashMap<Vec2, Vec2>;
+
+impl TaskData {
+    fn propose_moves(&self) -> (DestinationCount, Moves) {
+        let mut moves: Moves = HashMap::new();
+        let mut destination_count = HashMap::new();
+        // check all directions
+
+        for elf in self.elves.iter() {
+            let can_even_move = elf.neighbors8().any(|n| self.elves.contains(&n));
+            if !can_even_move {
+                continue;
+            }
+            let mut check = self.current;
+            loop {
+                let elves_in_dir = LOOK[check]
+                    .iter()
+                    .filter(|&&delta| self.elves.contains(&(*elf + delta)))
+                    .count();
+                if elves_in_dir == 0 {
+                    let proposed = *elf + LOOK[check][1];
+                    moves.insert(*elf, proposed); // destination_count makes sure that it doesn't matter to overwrite
+                    let count = destination_count.entry(proposed).or_insert(0);
+                    *count += 1;
+                    break;
+                }
+                check = (check + 1) % 4;
+                if check == self.current {
+                    // all considered
+                    break;
+                }
+            }
+        }
+        (destination_count, moves)
+    }
+    fn execute_moves(&mut self, destination_count: DestinationCount, moves: Moves) {
+        trace!("{:?}", self.elves);
+        trace!("{:?}", destination_count);
+        trace!("{:?}", moves);
+        for (from, to) in moves.into_iter() {
+            if destination_count[&to] == 1 {
+                self.elves.remove(&from);
+                self.elves.insert(to);
+            }
+        }
+        self.current = (self.current + 1) % 4;
+    }
+    fn simulate_n_rounds(&mut self, n: usize) {
+        trace!("\n{}", self.render());
+        record::frame(|| self.render());
+        for _ in 0..n {
+            let (destination_count, moves) = self.propose_moves();
+            self.execute_moves(destination_count, moves);
+            trace!("\n{}", self.render());
+            record::frame(|| self.render());
+        }
+    }
+    fn simulate_until(&mut self) -> i128 {
+        let mut rounds = 0;
+        loop {
+            let (destination_count, moves) = self.propose_moves();
+            if moves.is_empty() {
+                break;
+            }
+            self.execute_moves(destination_count, moves);
+            record::frame(|| self.render());
+            rounds += 1;
+        }
+        rounds + 1
+    }
+    fn render(&self) -> String {
+        let mut out = String::new();
+        let Some((min, max)) = Vec2::bounding_box(self.elves.iter().copied()) else {
+            return out;
+        };
+        for y in min.y..=max.y {
+            for x in min.x..=max.x {
+                out.push(if self.elves.contains(&Vec2::new(x, y)) {
+                    '#'
+                } else {
+                    '.'
+                });
+            }
+            out.push('\n');
+        }
+        out
+    }
+    fn count_ground(&self) -> i128 {
+        let (min, max) = Vec2::bounding_box(self.elves.iter().copied()).unwrap_or_default();
+        let extend = max - min + Vec2::new(1, 1);
+        extend.x * extend.y - self.elves.len() as i128
+    }
+}
+
+fn parse_input(input: &str) -> Result<TaskData, ParseError> {
+    let mut elves = HashSet::new();
+    for (row, line) in input::lines(input).enumerate() {
+        for (col, (i, c)) in line.char_indices().enumerate() {
+            match c {
+                '#' => {
+                    elves.insert(Vec2::new(col as i128, row as i128));
+                }
+                '.' => {}
+                _ => return Err(ParseError::at(input, &line[i..], "an elf or ground")),
+            }
+        }
+    }
+
+    Ok(TaskData { elves, current: 0 })
+}
+
+pub struct UnstableDiffusion;
+
+impl Solution for UnstableDiffusion {
+    type Input = TaskData;
+
+    fn parse_input(input: &str) -> Result<TaskData, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(mut data: Self::Input) -> Result<Answer> {
+        data.simulate_n_rounds(10);
+        Ok(Answer::from(data.count_ground()))
+    }
+
+    fn part_two(mut data: Self::Input) -> Result<Answer> {
+        Ok(Answer::from(data.simulate_until()))
+    }
+}
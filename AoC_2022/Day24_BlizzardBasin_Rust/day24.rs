@@ -0,0 +1,278 @@
+use anyhow::{bail, Result};
+use aoc_core::cycle::{self, Cycle};
+use aoc_core::noise::Noise;
+use aoc_core::{input, record, search, Answer, Direction, ParseError, Solution, Vec2};
+use log::{debug, trace};
+use std::collections::BTreeMap;
+
+// implement simulation + a*
+
+type Blizzards = BTreeMap<Vec2, Vec<Direction>>; // blizzard at, directions
+
+pub struct TaskData {
+    initial: Blizzards,
+    // the minutes until the blizzards are back where they were
+    repeats: Cycle<usize>,
+    start: Vec2,
+    target: Vec2,
+    rows: i128,
+    cols: i128,
+}
+impl TaskData {
+    // the blizzards at `pos` after `time` minutes, each one blew there in a
+    // straight line from where it started, wrapping around the valley
+    fn blizzards_at(&self, pos: &Vec2, time: usize) -> impl Iterator<Item = Direction> + '_ {
+        let (width, height, time) = (self.cols - 2, self.rows - 2, time as i128);
+        let pos = *pos;
+        Direction::ALL.into_iter().filter(move |&direction| {
+            let offset = direction.offset();
+            let from = Vec2::new(
+                (pos.x - 1 - offset.x * time).rem_euclid(width) + 1,
+                (pos.y - 1 - offset.y * time).rem_euclid(height) + 1,
+            );
+            self.initial
+                .get(&from)
+                .is_some_and(|blizzards| blizzards.contains(&direction))
+        })
+    }
+
+    fn render(&self, time: usize) -> String {
+        let mut out = String::new();
+        for row in 0..self.rows {
+            for col in 0..self.cols {
+                let pos = Vec2::new(col, row);
+                if pos == self.start {
+                    out.push('S');
+                } else if pos == self.target {
+                    out.push('T');
+                } else if row == 0 || row == self.rows - 1 || col == 0 || col == self.cols - 1 {
+                    out.push('#');
+                } else {
+                    let blizzards: Vec<_> = self.blizzards_at(&pos, time).collect();
+                    if !blizzards.is_empty() {
+                        let n = blizzards.len();
+                        if n > 1 {
+                            out.push_str(&n.to_string())
+                        } else if n == 1 {
+                            match blizzards[0] {
+                                Direction::Right => out.push('>'),
+                                Direction::Down => out.push('v'),
+                                Direction::Left => out.push('<'),
+                                Direction::Up => out.push('^'),
+                            }
+                        }
+                    } else {
+                        out.push('.');
+                    }
+                }
+            }
+            out.push('\n');
+        }
+        out
+    }
+    fn record_until(&self, minutes: usize) {
+        for time in 0..=minutes {
+            record::frame(|| self.render(time));
+        }
+    }
+    fn is_possible(&self, pos: &Vec2, time: usize) -> bool {
+        if pos.y <= 0 || pos.y >= self.rows - 1 || pos.x <= 0 || pos.x >= self.cols - 1 {
+            return *pos == self.start || *pos == self.target;
+        }
+        self.blizzards_at(pos, time).next().is_none()
+    }
+    // the blizzards repeat, so a node is the position at a point of that cycle
+    fn find_path(&self, initial_time: usize, from: &Vec2, to: &Vec2) -> Result<usize> {
+        let start = (self.repeats.equivalent_step(initial_time), *from);
+        let neighbors = |&(time, pos): &(usize, Vec2)| {
+            let ntime = time + 1;
+            pos.neighbors4()
+                .chain([pos]) // waiting
+                .filter(move |npos| self.is_possible(npos, ntime))
+                .map(move |npos| ((self.repeats.equivalent_step(ntime), npos), 1))
+        };
+        let manhattan_to_target = |&(_, pos): &(usize, Vec2)| pos.manhattan(to) as usize;
+        let Some(path) = search::astar([start], neighbors, manhattan_to_target, |(_, pos)| {
+            pos == to
+        }) else {
+            bail!("there is no way through the blizzards to {},{}", to.x, to.y);
+        };
+        Ok(initial_time + path.cost)
+    }
+}
+
+fn simulate_blizzard_states(initial: Blizzards, rows: i128, cols: i128) -> Cycle<usize> {
+    let wrap = |pos: &mut Vec2| {
+        if pos.y == 0 {
+            pos.y = rows - 2;
+        } else if pos.y == rows - 1 {
+            pos.y = 1;
+        }
+        if pos.x == 0 {
+            pos.x = cols - 2;
+        } else if pos.x == cols - 1 {
+            pos.x = 1;
+        }
+    };
+    let step = |current: &mut Blizzards| {
+        let mut new_blizzards = Blizzards::new();
+        for (pos, blizzards) in current.iter() {
+            for &blizzard in blizzards {
+                let mut new_pos = *pos + blizzard.offset();
+                wrap(&mut new_pos);
+                new_blizzards.entry(new_pos).or_default().push(blizzard);
+            }
+        }
+        *current = new_blizzards;
+    };
+    // only the maps on the way are kept to spot the repetition, the minutes
+    // are enough to look up the blizzards after it
+    let mut minutes = 0..;
+    cycle::find(initial, step, Blizzards::clone, |_| minutes.next().unwrap())
+}
+
+fn parse_input(input: &str) -> Result<TaskData, ParseError> {
+    // checking for ASCII first keeps the column a valid index into the line
+    let lines: Vec<&str> = input::lines(input).collect();
+    for line in lines.iter() {
+        if let Some(i) = line.find(|c| !"#.<>^v".contains(c)) {
+            return Err(ParseError::at(
+                input,
+                &line[i..],
+                "a wall, open ground or a blizzard",
+            ));
+        }
+    }
+    if lines.len() < 2 {
+        return Err(ParseError::missing(
+            input,
+            "a valley with at least two rows",
+        ));
+    }
+    let grid = input::grid(input);
+    let rows = grid.len();
+    let cols = grid[0].len();
+
+    let mut start_col = -1;
+    let mut target_col = -1;
+    for col in 0..cols {
+        let first_row = grid[0][col];
+        let last_row = grid[rows - 1][col];
+        if first_row == '.' {
+            start_col = col as i128;
+        }
+        if last_row == '.' {
+            target_col = col as i128;
+        }
+    }
+    if start_col == -1 {
+        return Err(ParseError::at(
+            input,
+            lines[0],
+            "an opening in the top wall",
+        ));
+    }
+    if target_col == -1 {
+        return Err(ParseError::at(
+            input,
+            lines[rows - 1],
+            "an opening in the bottom wall",
+        ));
+    }
+    let start = Vec2::new(start_col, 0);
+    let target = Vec2::new(target_col, rows as i128 - 1);
+    let mut initial_blizzards = Blizzards::new();
+    for row in 1..rows - 1 {
+        for col in 1..cols - 1 {
+            let pos = Vec2::new(col as i128, row as i128);
+            let c = grid[row][col];
+            if c != '.' {
+                let vec = initial_blizzards.entry(pos).or_default();
+                vec.push(match c {
+                    '>' => Direction::Right,
+                    'v' => Direction::Down,
+                    '<' => Direction::Left,
+                    '^' => Direction::Up,
+                    _ => return Err(ParseError::at(input, &lines[row][col..], "a blizzard")),
+                });
+            }
+        }
+    }
+    let repeats = simulate_blizzard_states(initial_blizzards.clone(), rows as i128, cols as i128);
+
+    Ok(TaskData {
+        initial: initial_blizzards,
+        repeats,
+        start,
+        target,
+        rows: rows as i128,
+        cols: cols as i128,
+    })
+}
+
+pub struct BlizzardBasin;
+
+impl Solution for BlizzardBasin {
+    type Input = TaskData;
+
+    fn parse_input(input: &str) -> Result<TaskData, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(data: Self::Input) -> Result<Answer> {
+        let minutes = data.find_path(0, &data.start, &data.target)?;
+        data.record_until(minutes);
+        Ok(Answer::from(minutes as i128))
+    }
+
+    fn part_two(data: Self::Input) -> Result<Answer> {
+        let s_to_t = data.find_path(0, &data.start, &data.target)?;
+        debug!("at the target after {} minutes", s_to_t);
+        trace!("\n{}", data.render(s_to_t));
+        let t_to_s = data.find_path(s_to_t, &data.target, &data.start)?;
+        debug!("back at the start after {} minutes", t_to_s);
+        trace!("\n{}", data.render(t_to_s));
+        let minutes = data.find_path(t_to_s, &data.start, &data.target)?;
+        data.record_until(minutes);
+        Ok(Answer::from(minutes as i128))
+    }
+
+    fn worst_cases() -> Vec<(&'static str, String)> {
+        // a valley four times the size of real ones, the blizzards only
+        // repeat after the least common multiple of its width and height
+        let (width, height) = (240, 50);
+        let mut noise = Noise::new(24);
+        let mut wide_valley = format!("#.{}\n", "#".repeat(width));
+        for _ in 0..height {
+            wide_valley.push('#');
+            for col in 0..width {
+                // like in real valleys no blizzard blows into the openings
+                let blizzards: &[char] = if col == 0 || col == width - 1 {
+                    &['.', '.', '<', '>']
+                } else {
+                    &['.', '.', '<', '>', '^', 'v']
+                };
+                wide_valley.push(*noise.pick(blizzards));
+            }
+            wide_valley.push_str("#\n");
+        }
+        wide_valley.push_str(&format!("{}.#\n", "#".repeat(width)));
+        vec![("wide_valley", wide_valley)]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn no_way_through_the_blizzards() {
+        // the blizzard wraps around onto the only ground there is
+        let data = parse_input("#.#\n#v#\n#.#\n").unwrap();
+        let err = BlizzardBasin::part_one(data).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "there is no way through the blizzards to 1,2"
+        );
+    }
+}
@@ -0,0 +1,98 @@
+use anyhow::Result;
+use aoc_core::{input, Answer, ParseError, Solution};
+use log::debug;
+
+pub struct TaskData {
+    snafu_numbers: Vec<Vec<char>>,
+}
+
+fn snafu_to_i128(snafu: &[char]) -> i128 {
+    let mut num = 0;
+    let mut power = 1;
+    for c in snafu.iter().rev() {
+        match c {
+            '2' => num += 2 * power,
+            '1' => num += power,
+            '0' => (),
+            '-' => num -= power,
+            '=' => num -= 2 * power,
+            _ => panic!("invalid snafu number"),
+        }
+        power *= 5;
+    }
+    num
+}
+
+fn i128_to_snafu(num: i128) -> Vec<char> {
+    let mut curr = num;
+    let mut snafu = Vec::new();
+    let mut carry = 0;
+    while curr > 0 || carry != 0 {
+        let modu = curr % 5;
+        let snaf = match (modu, carry) {
+            (0, 0) => ('0', 0),
+            (1, 0) => ('1', 0),
+            (2, 0) => ('2', 0),
+            (3, 0) => ('=', 1),
+            (4, 0) => ('-', 1),
+            (0, 1) => ('1', 0),
+            (1, 1) => ('2', 0),
+            (2, 1) => ('=', 1),
+            (3, 1) => ('-', 1),
+            (4, 1) => ('0', 1),
+            _ => panic!(
+                "invalid state: curr:{}, modu:{}, carry:{}",
+                curr, modu, carry
+            ),
+        };
+        carry = snaf.1;
+        snafu.push(snaf.0);
+        curr /= 5;
+    }
+    // 0, 23 => (=, 1)
+    // 1, 4 => (0, 1)
+    // 1, 0 => (1, 0)
+    // 64:
+    // 64 % 5 == 4 => (-, 1)
+    // 1, 12 % 5 == 2 => (2, 0)
+    // 2 % 5 == 2 => (2, 1),
+    // 1 => 1
+    // 120-
+    // 28 to snafu => %5 == 3 => (=, 1)
+    // 5 to snafu => %5 == 0 => 0 + 1
+    // 1 to snafu => %5 == 1 => 1
+    // 28 is 11=
+    snafu.reverse();
+    snafu
+}
+
+fn parse_input(input: &str) -> Result<TaskData, ParseError> {
+    for line in input::lines(input) {
+        if let Some(i) = line.find(|c| !"=-012".contains(c)) {
+            return Err(ParseError::at(input, &line[i..], "a SNAFU digit"));
+        }
+    }
+    let snafu_numbers = input::grid(input);
+    Ok(TaskData { snafu_numbers })
+}
+
+pub struct FullOfHotAir;
+
+impl Solution for FullOfHotAir {
+    type Input = TaskData;
+    const PARTS: u8 = 1;
+
+    fn parse_input(input: &str) -> Result<TaskData, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(TaskData { snafu_numbers }: Self::Input) -> Result<Answer> {
+        let mut sum = 0;
+        for snafu in snafu_numbers {
+            sum += snafu_to_i128(&snafu);
+        }
+        debug!("dezimal sum: {}", sum);
+        let snafu = i128_to_snafu(sum);
+        Ok(Answer::from(String::from_iter(snafu.iter())))
+    }
+}
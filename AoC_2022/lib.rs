@@ -0,0 +1,190 @@
+//! Advent of Code 2022, one module per `Day<dd>_<Title>_Rust` directory.
+
+use aoc_core::{day, Day};
+
+#[path = "Day01_CalorieCounting_Rust/day01.rs"]
+pub mod day01;
+#[path = "Day02_RockPaperScissors_Rust/day02.rs"]
+pub mod day02;
+#[path = "Day03_RucksackReorganisation_Rust/day03.rs"]
+pub mod day03;
+#[path = "Day04_CampCleanup_Rust/day04.rs"]
+pub mod day04;
+#[path = "Day05_SupplyStacks_Rust/day05.rs"]
+pub mod day05;
+#[path = "Day06_TuningTrouble_Rust/day06.rs"]
+pub mod day06;
+#[path = "Day07_NoSpaceLeftOnDevice_Rust/day07.rs"]
+pub mod day07;
+#[path = "Day08_TreetopTreeHouse_Rust/day08.rs"]
+pub mod day08;
+#[path = "Day09_RopeBridge_Rust/day09.rs"]
+pub mod day09;
+#[path = "Day10_CathodeRayTube_Rust/day10.rs"]
+pub mod day10;
+#[path = "Day11_MonkeyInTheMiddle_Rust/day11.rs"]
+pub mod day11;
+#[path = "Day12_HillClimbingAlgorithm_Rust/day12.rs"]
+pub mod day12;
+#[path = "Day13_DistressSignal_Rust/day13.rs"]
+pub mod day13;
+#[path = "Day14_RegolithReservoir_Rust/day14.rs"]
+pub mod day14;
+#[path = "Day15_BeaconExclusionZone_Rust/day15.rs"]
+pub mod day15;
+#[path = "Day16_ProboscideaVolcanium_Rust/day16.rs"]
+pub mod day16;
+#[path = "Day17_PyroclasticFlow_Rust/day17.rs"]
+pub mod day17;
+#[path = "Day18_BoilingBoulders_Rust/day18.rs"]
+pub mod day18;
+#[path = "Day19_NotEnoughMinerals_Rust/day19.rs"]
+pub mod day19;
+#[path = "Day20_GrovePositioningSystem_Rust/day20.rs"]
+pub mod day20;
+#[path = "Day21_MonkeyMath_Rust/day21.rs"]
+pub mod day21;
+#[path = "Day22_MonkeyMap_Rust/day22.rs"]
+pub mod day22;
+#[path = "Day23_UnstableDiffusion_Rust/day23.rs"]
+pub mod day23;
+#[path = "Day24_BlizzardBasin_Rust/day24.rs"]
+pub mod day24;
+#[path = "Day25_FullOfHotAir_Rust/day25.rs"]
+pub mod day25;
+
+pub const YEAR: u16 = 2022;
+
+pub fn days() -> Vec<Day> {
+    vec![
+        day!(
+            YEAR,
+            1,
+            "Day01_CalorieCounting_Rust",
+            day01::CalorieCounting
+        ),
+        day!(
+            YEAR,
+            2,
+            "Day02_RockPaperScissors_Rust",
+            day02::RockPaperScissors
+        ),
+        day!(
+            YEAR,
+            3,
+            "Day03_RucksackReorganisation_Rust",
+            day03::RucksackReorganisation
+        ),
+        day!(YEAR, 4, "Day04_CampCleanup_Rust", day04::CampCleanup),
+        day!(YEAR, 5, "Day05_SupplyStacks_Rust", day05::SupplyStacks),
+        day!(YEAR, 6, "Day06_TuningTrouble_Rust", day06::TuningTrouble),
+        day!(
+            YEAR,
+            7,
+            "Day07_NoSpaceLeftOnDevice_Rust",
+            day07::NoSpaceLeftOnDevice
+        ),
+        day!(
+            YEAR,
+            8,
+            "Day08_TreetopTreeHouse_Rust",
+            day08::TreetopTreeHouse
+        ),
+        day!(YEAR, 9, "Day09_RopeBridge_Rust", day09::RopeBridge),
+        day!(YEAR, 10, "Day10_CathodeRayTube_Rust", day10::CathodeRayTube),
+        day!(
+            YEAR,
+            11,
+            "Day11_MonkeyInTheMiddle_Rust",
+            day11::MonkeyInTheMiddle
+        ),
+        day!(
+            YEAR,
+            12,
+            "Day12_HillClimbingAlgorithm_Rust",
+            day12::HillClimbingAlgorithm
+        ),
+        day!(YEAR, 13, "Day13_DistressSignal_Rust", day13::DistressSignal),
+        day!(
+            YEAR,
+            14,
+            "Day14_RegolithReservoir_Rust",
+            day14::RegolithReservoir
+        ),
+        day!(
+            YEAR,
+            15,
+            "Day15_BeaconExclusionZone_Rust",
+            day15::BeaconExclusionZone
+        ),
+        day!(
+            YEAR,
+            16,
+            "Day16_ProboscideaVolcanium_Rust",
+            day16::ProboscideaVolcanium
+        ),
+        day!(
+            YEAR,
+            17,
+            "Day17_PyroclasticFlow_Rust",
+            day17::PyroclasticFlow
+        ),
+        day!(
+            YEAR,
+            18,
+            "Day18_BoilingBoulders_Rust",
+            day18::BoilingBoulders
+        ),
+        day!(
+            YEAR,
+            19,
+            "Day19_NotEnoughMinerals_Rust",
+            day19::NotEnoughMinerals
+        ),
+        day!(
+            YEAR,
+            20,
+            "Day20_GrovePositioningSystem_Rust",
+            day20::GrovePositioningSystem
+        ),
+        day!(YEAR, 21, "Day21_MonkeyMath_Rust", day21::MonkeyMath),
+        day!(YEAR, 22, "Day22_MonkeyMap_Rust", day22::MonkeyMap),
+        day!(
+            YEAR,
+            23,
+            "Day23_UnstableDiffusion_Rust",
+            day23::UnstableDiffusion
+        ),
+        day!(YEAR, 24, "Day24_BlizzardBasin_Rust", day24::BlizzardBasin),
+        day!(YEAR, 25, "Day25_FullOfHotAir_Rust", day25::FullOfHotAir),
+    ]
+}
+
+aoc_core::answer_tests!(
+    "answers.toml",
+    day01,
+    day02,
+    day03,
+    day04,
+    day05,
+    day06,
+    day07,
+    day08,
+    day09,
+    day10,
+    day11,
+    day12,
+    day13,
+    day14,
+    day15,
+    day16,
+    day17,
+    day18,
+    day19,
+    day20,
+    day21,
+    day22,
+    day23,
+    day24,
+    day25,
+);
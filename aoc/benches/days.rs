@@ -0,0 +1,60 @@
+//! Benchmarks parsing and both parts of every day separately, on the day's
+//! input.txt if it is checked out, its test.txt otherwise, and on the worst
+//! cases the day provides.
+//!
+//! The benchmarks are named `<year>/day<dd>/<step>/<input>`, so
+//! `cargo bench -p aoc -- 2022/day06` only runs one day.
+
+use aoc_core::remote::Client;
+use aoc_core::Day;
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, Criterion};
+use std::fs;
+use std::path::Path;
+
+fn inputs(day: &Day) -> Vec<(String, String)> {
+    let mut inputs = Vec::new();
+    if let Ok(input) = Client::from_env().local_input(day) {
+        inputs.push((String::from("input"), input));
+    } else if let Ok(input) = fs::read_to_string(Path::new(day.dir).join("test.txt")) {
+        // without a puzzle input every day still gets measured on the example
+        inputs.push((String::from("test"), input));
+    }
+    for (name, input) in day.worst_cases() {
+        inputs.push((String::from(name), input));
+    }
+    inputs
+}
+
+fn bench_day(c: &mut Criterion, day: &Day) {
+    let mut group = c.benchmark_group(format!("{}/day{:02}", day.year, day.day));
+    // some days take seconds per run
+    group.sample_size(10);
+    for (name, input) in inputs(day) {
+        if let Err(err) = day.parse(&input) {
+            eprintln!("{} day {:02} {} skipped: {}", day.year, day.day, name, err);
+            continue;
+        }
+        group.bench_function(format!("parse/{}", name), |b| {
+            b.iter(|| day.parse(black_box(&input)))
+        });
+        for part in 1..=day.parts() {
+            let Some(solve) = day.parsed_part(part) else {
+                continue;
+            };
+            let step = if part == 1 { "part_one" } else { "part_two" };
+            group.bench_function(format!("{}/{}", step, name), |b| {
+                b.iter_batched(|| day.parse(&input).unwrap(), solve, BatchSize::LargeInput)
+            });
+        }
+    }
+    group.finish();
+}
+
+fn all_days(c: &mut Criterion) {
+    for day in aoc_2021::days().iter().chain(aoc_2022::days().iter()) {
+        bench_day(c, day);
+    }
+}
+
+criterion_group!(benches, all_days);
+criterion_main!(benches);
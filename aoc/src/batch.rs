@@ -0,0 +1,412 @@
+//! `aoc batch`, one day on a directory of inputs, like the inputs of
+//! everybody on the team, to catch solutions that only work for one input.
+//!
+//! Every `<name>.txt` may come with a `<name>.answers` that has `part_one`,
+//! `part_two` and `params` like a table of the answers file.
+
+use anyhow::{bail, Context, Result};
+use aoc_core::params::{self, Override};
+use aoc_core::{answers, Answer, Day};
+use std::any::Any;
+use std::fs;
+use std::io::{ErrorKind, Read, Write};
+use std::panic::{self, AssertUnwindSafe};
+use std::path::Path;
+use std::process::{Command, Stdio};
+use std::thread;
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    /// The expected answer.
+    Ok,
+    /// Nothing to compare with.
+    Unchecked,
+    Mismatch(String),
+    Error(String),
+    Panic(String),
+    Timeout,
+}
+
+impl Status {
+    fn flagged(&self) -> bool {
+        !matches!(self, Status::Ok | Status::Unchecked)
+    }
+}
+
+pub struct Row {
+    pub input: String,
+    pub part: u8,
+    pub answer: Option<Answer>,
+    pub elapsed: Option<Duration>,
+    pub status: Status,
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    match payload.downcast::<String>() {
+        Ok(message) => *message,
+        Err(payload) => payload.downcast_ref::<&str>().map_or_else(
+            || String::from("panicked"),
+            |message| String::from(*message),
+        ),
+    }
+}
+
+/// How a part went, its answer and how long it took.
+pub type Outcome = (Status, Option<Answer>, Option<Duration>);
+
+/// Solves a part in this process, a panic included in the outcome.
+pub fn solve_here(day: &Day, part: u8, input: &str, params: &[Override]) -> Outcome {
+    let Some(solve) = day.part(part) else {
+        return (
+            Status::Error(format!("there is no part {}", part)),
+            None,
+            None,
+        );
+    };
+    let t = Instant::now();
+    let result = panic::catch_unwind(AssertUnwindSafe(|| params::with(params, || solve(input))));
+    let elapsed = Some(t.elapsed());
+    match result {
+        Ok(Ok(answer)) => (Status::Unchecked, Some(answer), elapsed),
+        Ok(Err(err)) => (Status::Error(format!("{:#}", err)), None, elapsed),
+        Err(payload) => (Status::Panic(panic_message(payload)), None, elapsed),
+    }
+}
+
+/// The outcome of [`solve_here`] as the child process of [`solve_in_child`]
+/// prints it: the kind of answer or failure on the first line, the
+/// nanoseconds on the second and the answer or message, which may span
+/// lines, after that.
+pub fn report((status, answer, elapsed): &Outcome) -> String {
+    let (kind, text) = match (status, answer) {
+        (_, Some(Answer::Int(n))) => ("int", n.to_string()),
+        (_, Some(Answer::Str(s))) => ("str", s.clone()),
+        (_, Some(Answer::Grid(rows))) => ("grid", rows.join("\n")),
+        (Status::Panic(message), None) => ("panic", message.clone()),
+        (Status::Error(err), None) => ("error", err.clone()),
+        (status, None) => ("error", format!("{:?}", status)),
+    };
+    let nanos = elapsed.map_or(0, |elapsed| elapsed.as_nanos());
+    format!("{}\n{}\n{}", kind, nanos, text)
+}
+
+fn read_report(report: &str) -> Option<Outcome> {
+    let mut lines = report.splitn(3, '\n');
+    let (kind, nanos) = (lines.next()?, lines.next()?.parse().ok()?);
+    let text = String::from(lines.next().unwrap_or(""));
+    let elapsed = Some(Duration::from_nanos(nanos));
+    let answer = |answer| (Status::Unchecked, Some(answer), elapsed);
+    match kind {
+        "int" => Some(answer(Answer::Int(text.parse().ok()?))),
+        "str" => Some(answer(Answer::Str(text))),
+        "grid" => Some(answer(Answer::Grid(
+            text.lines().map(String::from).collect(),
+        ))),
+        "panic" => Some((Status::Panic(text), None, elapsed)),
+        "error" => Some((Status::Error(text), None, elapsed)),
+        _ => None,
+    }
+}
+
+/// Runs `command`, a child process that solves a part of `input` on its
+/// stdin and prints the [`report`], and kills it after `timeout`, so a part
+/// that never ends doesn't take a core from the ones after it.
+pub fn solve_in_child(mut command: Command, input: &str, timeout: Duration) -> Outcome {
+    let child = command
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn();
+    let mut child = match child {
+        Ok(child) => child,
+        Err(err) => {
+            return (
+                Status::Error(format!("error starting the part: {}", err)),
+                None,
+                None,
+            )
+        }
+    };
+    // a pipe that fills up would block either side
+    let (mut stdin, mut stdout) = (child.stdin.take().unwrap(), child.stdout.take().unwrap());
+    let input = String::from(input);
+    let writer = thread::spawn(move || {
+        // a part that is killed stops reading
+        let _ = stdin.write_all(input.as_bytes());
+    });
+    let reader = thread::spawn(move || {
+        let mut report = String::new();
+        let _ = stdout.read_to_string(&mut report);
+        report
+    });
+    let deadline = Instant::now() + timeout;
+    let exit = loop {
+        match child.try_wait() {
+            Ok(None) if Instant::now() < deadline => thread::sleep(Duration::from_millis(5)),
+            Ok(Some(exit)) => break Some(exit),
+            _ => {
+                let _ = child.kill();
+                let _ = child.wait();
+                break None;
+            }
+        }
+    };
+    let _ = writer.join();
+    let report = reader.join().unwrap_or_default();
+    match exit {
+        None => (Status::Timeout, None, None),
+        Some(exit) => read_report(&report).unwrap_or_else(|| {
+            let err = format!("the part ended without a report, {}", exit);
+            (Status::Error(err), None, None)
+        }),
+    }
+}
+
+/// Solves every part of `day` on every input in `dir` with `solve` and
+/// compares with the answers next to them, `params` overriding those of the
+/// inputs.
+pub fn run(
+    day: &Day,
+    dir: &Path,
+    params: &[Override],
+    solve: impl Fn(u8, &str, &[Override]) -> Outcome,
+) -> Result<Vec<Row>> {
+    let mut inputs: Vec<_> = fs::read_dir(dir)
+        .with_context(|| format!("error reading {}", dir.display()))?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<Result<_, _>>()?;
+    inputs.retain(|path| path.extension().is_some_and(|ext| ext == "txt"));
+    inputs.sort();
+    if inputs.is_empty() {
+        bail!("there are no *.txt inputs in {}", dir.display());
+    }
+    let mut rows = Vec::new();
+    for path in inputs {
+        let name = path.file_stem().unwrap().to_string_lossy().into_owned();
+        let input = fs::read_to_string(&path)
+            .with_context(|| format!("error reading {}", path.display()))?;
+        let answers_path = path.with_extension("answers");
+        let answers::Parts {
+            params: mut overrides,
+            answers: expected,
+        } = match fs::read_to_string(&answers_path) {
+            Ok(text) => answers::parse_parts(&text)
+                .with_context(|| format!("error parsing {}", answers_path.display()))?,
+            Err(err) if err.kind() == ErrorKind::NotFound => answers::Parts::default(),
+            Err(err) => {
+                return Err(err)
+                    .with_context(|| format!("error reading {}", answers_path.display()))
+            }
+        };
+        overrides.extend_from_slice(params);
+        for part in 1..=day.parts() {
+            let (mut status, answer, elapsed) = solve(part, &input, &overrides);
+            let expected = expected.iter().find(|(p, _)| *p == part);
+            if let (Some(answer), Some((_, expected))) = (&answer, expected) {
+                status = if answer.to_string() == *expected {
+                    Status::Ok
+                } else {
+                    Status::Mismatch(expected.clone())
+                };
+            }
+            rows.push(Row {
+                input: name.clone(),
+                part,
+                answer,
+                elapsed,
+                status,
+            });
+        }
+    }
+    Ok(rows)
+}
+
+/// The rows as a table with a column per field, one line per part.
+pub fn table(rows: &[Row]) -> String {
+    let cells: Vec<[String; 5]> = rows
+        .iter()
+        .map(|row| {
+            let answer = match &row.answer {
+                Some(Answer::Grid(_)) => String::from("(grid)"),
+                Some(answer) => answer.to_string(),
+                None => String::from("-"),
+            };
+            let elapsed = row
+                .elapsed
+                .map_or_else(|| String::from("-"), |elapsed| format!("{:0.2?}", elapsed));
+            let status = match &row.status {
+                Status::Ok => String::from("ok"),
+                Status::Unchecked => String::from("unchecked"),
+                Status::Mismatch(expected) => format!("MISMATCH, expected {}", expected),
+                Status::Error(err) => format!("ERROR {}", err),
+                Status::Panic(message) => format!("PANIC {}", message),
+                Status::Timeout => String::from("TIMEOUT"),
+            };
+            [
+                row.input.clone(),
+                row.part.to_string(),
+                answer,
+                elapsed,
+                status,
+            ]
+        })
+        .collect();
+    let header = ["input", "part", "answer", "time", "status"].map(String::from);
+    let mut widths = header.clone().map(|h| h.len());
+    for row in cells.iter() {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+    let mut out = String::new();
+    for row in std::iter::once(&header).chain(cells.iter()) {
+        let line: Vec<String> = row
+            .iter()
+            .zip(widths)
+            .map(|(cell, width)| format!("{:width$}", cell, width = width))
+            .collect();
+        out.push_str(line.join("  ").trim_end());
+        out.push('\n');
+    }
+    out
+}
+
+/// How many rows are flagged.
+pub fn flagged(rows: &[Row]) -> usize {
+    rows.iter().filter(|row| row.status.flagged()).count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::{ParseError, Solution};
+
+    // part one sums the numbers, part two panics on negative numbers
+    struct Numbers;
+
+    impl Solution for Numbers {
+        type Input = Vec<i64>;
+
+        fn parse_input(input: &str) -> Result<Vec<i64>, ParseError> {
+            input
+                .split_whitespace()
+                .map(|n| aoc_core::parse::value(input, n, "a number"))
+                .collect()
+        }
+
+        fn part_one(numbers: Vec<i64>) -> Result<Answer> {
+            Ok(Answer::from(numbers.iter().sum::<i64>()))
+        }
+
+        fn part_two(numbers: Vec<i64>) -> Result<Answer> {
+            assert!(numbers.iter().all(|&n| n >= 0), "negative number");
+            Ok(Answer::from(numbers.len()))
+        }
+    }
+
+    #[test]
+    fn flags_mismatches_errors_and_panics() {
+        let dir = std::env::temp_dir().join(format!("aoc_batch_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("alice.txt"), "1 2 3").unwrap();
+        fs::write(dir.join("alice.answers"), "part_one = 6\npart_two = 4\n").unwrap();
+        fs::write(dir.join("bob.txt"), "1 -2").unwrap();
+        fs::write(dir.join("carol.txt"), "0 x").unwrap();
+        fs::write(dir.join("notes.md"), "not an input").unwrap();
+        let day = Day::new::<Numbers>(2022, 1, "");
+
+        let rows = run(&day, &dir, &[], |part, input, params| {
+            solve_here(&day, part, input, params)
+        })
+        .unwrap();
+        let status: Vec<_> = rows
+            .iter()
+            .map(|r| (r.input.as_str(), r.part, &r.status))
+            .collect();
+        assert_eq!(status[0], ("alice", 1, &Status::Ok));
+        assert_eq!(
+            status[1],
+            ("alice", 2, &Status::Mismatch(String::from("4")))
+        );
+        assert_eq!(status[2], ("bob", 1, &Status::Unchecked));
+        assert!(
+            matches!(status[3], ("bob", 2, Status::Panic(message)) if message == "negative number")
+        );
+        assert!(matches!(status[4], ("carol", 1, Status::Error(_))));
+        assert_eq!(rows.len(), 6);
+        assert_eq!(flagged(&rows), 4);
+
+        let table = table(&rows);
+        assert!(table.starts_with("input  part  answer  time"));
+        assert!(table
+            .lines()
+            .nth(2)
+            .unwrap()
+            .contains("MISMATCH, expected 4"));
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn unreadable_answers_are_errors() {
+        let dir = std::env::temp_dir().join(format!("aoc_batch_utf8_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("erin.txt"), "1 2").unwrap();
+        fs::write(dir.join("erin.answers"), b"part_one = \xff\n").unwrap();
+        let day = Day::new::<Numbers>(2022, 1, "");
+
+        let err = run(&day, &dir, &[], |part, input, params| {
+            solve_here(&day, part, input, params)
+        })
+        .err()
+        .unwrap();
+        assert!(format!("{:#}", err).starts_with("error reading"));
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn reports_round_trip() {
+        let day = Day::new::<Numbers>(2022, 1, "");
+        for outcome in [
+            solve_here(&day, 1, "1 2 3", &[]),
+            solve_here(&day, 2, "-1", &[]),
+            solve_here(&day, 1, "x", &[]),
+            (
+                Status::Unchecked,
+                Some(Answer::Grid(vec![String::from("#."), String::from(".#")])),
+                Some(Duration::from_nanos(7)),
+            ),
+        ] {
+            assert_eq!(read_report(&report(&outcome)), Some(outcome));
+        }
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn kills_parts_in_child_processes() {
+        let sh = |script: &str| {
+            let mut command = Command::new("sh");
+            command.args(["-c", script]);
+            command
+        };
+        let timeout = Duration::from_millis(200);
+        let answered = sh("cat > /dev/null; printf 'int\\n1500\\n6'");
+        assert_eq!(
+            solve_in_child(answered, "1 2 3", timeout),
+            (
+                Status::Unchecked,
+                Some(Answer::Int(6)),
+                Some(Duration::from_nanos(1500))
+            )
+        );
+        let t = Instant::now();
+        let hanging = sh("cat > /dev/null; exec sleep 5");
+        assert_eq!(
+            solve_in_child(hanging, "1 2 3", timeout),
+            (Status::Timeout, None, None)
+        );
+        assert!(t.elapsed() < Duration::from_secs(2));
+        let crashed = solve_in_child(sh("exit 3"), "1 2 3", timeout);
+        assert!(matches!(crashed.0, Status::Error(err) if err.starts_with("the part ended")));
+    }
+}
@@ -0,0 +1,399 @@
+use anyhow::{anyhow, bail, Context, Result};
+use aoc_core::params::{self, Override};
+use aoc_core::record::{self, Recorder};
+use aoc_core::remote::Client;
+use aoc_core::{Answer, Day};
+use clap::{Parser, Subcommand};
+use env_logger::Env;
+use serde::Serialize;
+use std::env;
+use std::io::{self, IsTerminal, Read, Write};
+use std::path::PathBuf;
+use std::process;
+use std::time::{Duration, Instant};
+
+mod alloc;
+mod batch;
+mod scaffold;
+
+#[global_allocator]
+static ALLOCATOR: alloc::Counting = alloc::Counting;
+
+const YEARS: [u16; 2] = [aoc_2021::YEAR, aoc_2022::YEAR];
+
+#[derive(Parser)]
+#[command(about = "Runs the Advent of Code solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+    /// What the days log, like `debug` or `aoc_2022::day13=trace`, on top of
+    /// RUST_LOG
+    #[arg(long, global = true, value_name = "FILTER")]
+    log: Option<String>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run a single day or all days of a year.
+    ///
+    /// A single day reads its input from stdin if something is piped in and
+    /// falls back to the input.txt in the day's directory, the cached input
+    /// and downloading the input otherwise.
+    Run {
+        year: u16,
+        day: Option<u8>,
+        /// Only run this part
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Run every day of the year on its input.txt
+        #[arg(long, conflicts_with = "day")]
+        all: bool,
+        /// Record the frames the day draws, to an asciicast if the path ends
+        /// in .cast and to a directory of PPM images otherwise
+        #[arg(long, value_name = "PATH", conflicts_with = "all")]
+        record: Option<PathBuf>,
+        /// Frames per second of the recording
+        #[arg(long, default_value_t = 10, requires = "record")]
+        fps: u32,
+        /// Override a parameter of the day, like row=10 for the example of
+        /// 2022 day 15
+        #[arg(
+            long = "param",
+            value_name = "NAME=VALUE",
+            value_parser = params::parse_override,
+            conflicts_with = "all"
+        )]
+        params: Vec<Override>,
+    },
+    /// Run a day on every *.txt in a directory and compare with the
+    /// <name>.answers next to them.
+    ///
+    /// Mismatches, errors, panics and timeouts are flagged per input. Every
+    /// part runs in a process of its own, killed when it times out.
+    Batch {
+        year: u16,
+        day: u8,
+        dir: PathBuf,
+        /// Seconds a part may take per input
+        #[arg(long, default_value_t = 60)]
+        timeout: u64,
+        /// Override a parameter of the day for every input
+        #[arg(long = "param", value_name = "NAME=VALUE", value_parser = params::parse_override)]
+        params: Vec<Override>,
+    },
+    /// Solve a part of the input on stdin for `aoc batch` and print how it
+    /// went.
+    #[command(hide = true)]
+    BatchPart {
+        year: u16,
+        day: u8,
+        part: u8,
+        #[arg(long = "param", value_name = "NAME=VALUE", value_parser = params::parse_override)]
+        params: Vec<Override>,
+    },
+    /// Solve a part on the day's input and submit the answer.
+    ///
+    /// Answers that were wrong before, or beyond one that was too high or too
+    /// low, are not submitted again.
+    Submit {
+        year: u16,
+        day: u8,
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+    },
+    /// Download the inputs of a year into the cache, see AOC_SESSION.
+    Fetch {
+        year: u16,
+        /// Only these days, all solved days by default
+        days: Vec<u8>,
+    },
+    /// Create the directory of a new day with a stubbed solution and register
+    /// it in the year's lib.rs and answers.toml.
+    New {
+        year: u16,
+        day: u8,
+        /// The title of the puzzle, like "Extended Polymerization", fetched
+        /// from the puzzle page if left out
+        title: Vec<String>,
+    },
+    /// Run every day on its input.txt and print the answers, timings and peak
+    /// memory use as JSON.
+    ///
+    /// Days without an input.txt are left out. Only the JSON goes to stdout,
+    /// whatever the days print goes to stderr.
+    Report {
+        /// Only these years, all of them by default
+        years: Vec<u16>,
+    },
+}
+
+fn main() -> Result<()> {
+    let cli = Cli::parse();
+    init_logging(cli.log.as_deref());
+    match cli.command {
+        Command::Run {
+            year,
+            day,
+            part,
+            all,
+            record,
+            fps,
+            params,
+        } => {
+            if let Some(path) = &record {
+                record::install(Recorder::new(path).fps(fps));
+            }
+            run(year, day, part, all, &params)?;
+            if let Some(recorder) = record::uninstall() {
+                let frames = recorder.finish()?;
+                eprintln!("recorded {} frames", frames);
+            }
+            Ok(())
+        }
+        Command::Batch {
+            year,
+            day,
+            dir,
+            timeout,
+            params,
+        } => {
+            let days = days(year)?;
+            let day = days
+                .iter()
+                .find(|d| d.day == day)
+                .ok_or_else(|| anyhow!("there is no solution for {} day {}", year, day))?;
+            check_params(day, &params)?;
+            let exe = env::current_exe().context("error finding the aoc executable")?;
+            let rows = batch::run(day, &dir, &params, |part, input, params| {
+                let mut command = process::Command::new(&exe);
+                command.arg("batch-part");
+                command.args([year.to_string(), day.day.to_string(), part.to_string()]);
+                for (name, value) in params {
+                    command.arg(format!("--param={}={}", name, value));
+                }
+                batch::solve_in_child(command, input, Duration::from_secs(timeout))
+            })?;
+            print!("{}", batch::table(&rows));
+            match batch::flagged(&rows) {
+                0 => Ok(()),
+                flagged => bail!("{} of {} parts flagged", flagged, rows.len()),
+            }
+        }
+        Command::BatchPart {
+            year,
+            day,
+            part,
+            params,
+        } => {
+            let days = days(year)?;
+            let day = days
+                .iter()
+                .find(|d| d.day == day)
+                .ok_or_else(|| anyhow!("there is no solution for {} day {}", year, day))?;
+            let mut input = String::new();
+            io::stdin().read_to_string(&mut input)?;
+            print!(
+                "{}",
+                batch::report(&batch::solve_here(day, part, &input, &params))
+            );
+            Ok(())
+        }
+        Command::Submit { year, day, part } => submit(year, day, part),
+        Command::Fetch { year, days } => fetch(year, days),
+        Command::New { year, day, title } => {
+            let title = if title.is_empty() {
+                Client::from_env().title(year, day)?
+            } else {
+                title.join(" ")
+            };
+            let dir = scaffold::new_day(year, day, &title)?;
+            println!("created {}", dir.display());
+            Ok(())
+        }
+        Command::Report { years } => report(years),
+    }
+}
+
+// only warnings unless RUST_LOG or --log ask for more, the log goes to stderr
+fn init_logging(filter: Option<&str>) {
+    let mut logger = env_logger::Builder::from_env(Env::default().default_filter_or("warn"));
+    if let Some(filter) = filter {
+        logger.parse_filters(filter);
+    }
+    logger.format_timestamp(None).init();
+}
+
+fn days(year: u16) -> Result<Vec<Day>> {
+    match year {
+        aoc_2021::YEAR => Ok(aoc_2021::days()),
+        aoc_2022::YEAR => Ok(aoc_2022::days()),
+        _ => bail!("there are no solutions for {}", year),
+    }
+}
+
+fn check_params(day: &Day, params: &[Override]) -> Result<()> {
+    params::check(&day.params(), params)
+        .with_context(|| format!("{} day {} can't take the parameters", day.year, day.day))
+}
+
+fn run(year: u16, day: Option<u8>, part: Option<u8>, all: bool, params: &[Override]) -> Result<()> {
+    let days = days(year)?;
+    match day {
+        Some(day) => {
+            let day = days
+                .iter()
+                .find(|d| d.day == day)
+                .ok_or_else(|| anyhow!("there is no solution for {} day {}", year, day))?;
+            check_params(day, params)?;
+            let input = read_input(day)?;
+            params::with(params, || run_day(day, &input, part))
+        }
+        None if all => {
+            for day in days.iter() {
+                match read_input_file(day) {
+                    Ok(input) => run_day(day, &input, part)?,
+                    Err(err) => eprintln!("{} day {:02} skipped: {:#}", year, day.day, err),
+                }
+            }
+            Ok(())
+        }
+        None => bail!("either give a day or use --all"),
+    }
+}
+
+fn submit(year: u16, day: u8, part: u8) -> Result<()> {
+    let days = days(year)?;
+    let day = days
+        .iter()
+        .find(|d| d.day == day)
+        .ok_or_else(|| anyhow!("there is no solution for {} day {}", year, day))?;
+    let solve = day
+        .part(part)
+        .ok_or_else(|| anyhow!("day {} has no part {}", day.day, part))?;
+    let answer = match solve(&read_input(day)?)? {
+        Answer::Grid(rows) => bail!("the answer is a drawing:\n{}", rows.join("\n")),
+        answer => answer.to_string(),
+    };
+    println!("submitting {}", answer);
+    let verdict = Client::from_env().submit(year, day.day, part, &answer)?;
+    println!("{}", verdict);
+    Ok(())
+}
+
+fn fetch(year: u16, days: Vec<u8>) -> Result<()> {
+    let days = if days.is_empty() {
+        self::days(year)?.iter().map(|d| d.day).collect()
+    } else {
+        days
+    };
+    let client = Client::from_env();
+    for day in days {
+        let input = client.input(year, day)?;
+        eprintln!(
+            "{} day {:02}: {} lines in {}",
+            year,
+            day,
+            input.lines().count(),
+            client.cache_path(year, day).display()
+        );
+    }
+    Ok(())
+}
+
+fn read_input(day: &Day) -> Result<String> {
+    let mut stdin = io::stdin();
+    if !stdin.is_terminal() {
+        let mut input = String::new();
+        stdin.read_to_string(&mut input)?;
+        if !input.is_empty() {
+            return Ok(input);
+        }
+    }
+    Client::from_env().day_input(day)
+}
+
+// the checked out or cached input, never downloaded
+fn read_input_file(day: &Day) -> Result<String> {
+    Client::from_env()
+        .local_input(day)
+        .with_context(|| format!("error reading {}", day.input_path().display()))
+}
+
+fn run_day(day: &Day, input: &str, part: Option<u8>) -> Result<()> {
+    println!("{} day {:02}: {}", day.year, day.day, day.title());
+    let parts = match part {
+        Some(part) => part..=part,
+        None => 1..=day.parts(),
+    };
+    for part in parts {
+        let solve = day
+            .part(part)
+            .ok_or_else(|| anyhow!("day {} has no part {}", day.day, part))?;
+        let t = Instant::now();
+        let answer = solve(input)?;
+        let elapsed = t.elapsed();
+        let name = if part == 1 { "one" } else { "two" };
+        match answer {
+            Answer::Grid(_) => println!("Part {} in {:0.2?}:\n{}", name, elapsed, answer),
+            _ => println!("Part {}: {} in {:0.2?}", name, answer, elapsed),
+        }
+    }
+    Ok(())
+}
+
+#[derive(Serialize)]
+struct PartReport {
+    year: u16,
+    day: u8,
+    part: u8,
+    answer: Option<String>,
+    error: Option<String>,
+    duration_ns: u64,
+    /// On top of what was allocated before the part started.
+    peak_allocated_bytes: usize,
+}
+
+fn report(years: Vec<u16>) -> Result<()> {
+    let years = if years.is_empty() {
+        YEARS.to_vec()
+    } else {
+        years
+    };
+    let mut reports = Vec::new();
+    for year in years {
+        for day in days(year)? {
+            let input = match read_input_file(&day) {
+                Ok(input) => input,
+                Err(err) => {
+                    eprintln!("{} day {:02} skipped: {:#}", year, day.day, err);
+                    continue;
+                }
+            };
+            for part in 1..=day.parts() {
+                let solve = day.part(part).expect("every day has its parts");
+                let start = alloc::reset_peak();
+                let t = Instant::now();
+                let result = solve(&input);
+                let duration = t.elapsed();
+                let peak_allocated_bytes = alloc::peak_since(start);
+                let (answer, error) = match result {
+                    Ok(answer) => (Some(answer.to_string()), None),
+                    Err(err) => (None, Some(format!("{:#}", err))),
+                };
+                reports.push(PartReport {
+                    year,
+                    day: day.day,
+                    part,
+                    answer,
+                    error,
+                    duration_ns: u64::try_from(duration.as_nanos()).unwrap_or(u64::MAX),
+                    peak_allocated_bytes,
+                });
+            }
+        }
+    }
+    let mut stdout = io::stdout().lock();
+    serde_json::to_writer_pretty(&mut stdout, &reports)?;
+    writeln!(stdout)?;
+    Ok(())
+}
@@ -0,0 +1,301 @@
+//! `aoc new`, the skeleton of a new day registered everywhere a day has to
+//! be.
+
+use anyhow::{bail, Context, Result};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const TEMPLATE: &str = r#"use anyhow::{bail, Result};
+use aoc_core::{input, Answer, ParseError, Solution};
+
+pub struct TaskData {
+    lines: Vec<String>,
+}
+
+fn parse_input(input: &str) -> Result<TaskData, ParseError> {
+    let lines = input::lines(input).map(String::from).collect();
+    Ok(TaskData { lines })
+}
+
+pub struct {title};
+
+impl Solution for {title} {
+    type Input = TaskData;
+
+    fn parse_input(input: &str) -> Result<TaskData, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(data: Self::Input) -> Result<Answer> {
+        bail!(
+            "part one is not solved yet, the input has {} lines",
+            data.lines.len()
+        )
+    }
+
+    fn part_two(data: Self::Input) -> Result<Answer> {
+        bail!(
+            "part two is not solved yet, the input has {} lines",
+            data.lines.len()
+        )
+    }
+}
+"#;
+
+/// The puzzle title as the name of the day's struct and directory, so
+/// "No Space Left On Device" becomes `NoSpaceLeftOnDevice`.
+pub fn title_case(title: &str) -> String {
+    title
+        .split(|c: char| !c.is_ascii_alphanumeric() && c != '\'')
+        .map(|word| word.replace('\'', ""))
+        .filter(|word| !word.is_empty())
+        .map(|word| {
+            let mut chars = word.chars();
+            let first = chars.next().unwrap().to_ascii_uppercase();
+            std::iter::once(first).chain(chars).collect::<String>()
+        })
+        .collect()
+}
+
+fn year_dir(year: u16) -> PathBuf {
+    let workspace = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+    workspace.join(format!("AoC_{}", year))
+}
+
+/// Creates `Day<dd>_<Title>_Rust` with a stubbed solution and an empty
+/// test.txt and registers the day in the year's lib.rs and answers.toml.
+pub fn new_day(year: u16, day: u8, title: &str) -> Result<PathBuf> {
+    if !(1..=25).contains(&day) {
+        bail!("there is no day {}", day);
+    }
+    let title = title_case(title);
+    if title.is_empty() || title.starts_with(|c: char| c.is_ascii_digit()) {
+        bail!("{:?} can't be the name of a struct", title);
+    }
+    let year_dir = year_dir(year);
+    let lib_path = year_dir.join("lib.rs");
+    let lib =
+        fs::read_to_string(&lib_path).with_context(|| format!("there is no crate for {}", year))?;
+    let dir_name = format!("Day{:02}_{}_Rust", day, title);
+    let lib = register(&lib, day, &dir_name, &title)?;
+    let answers_path = year_dir.join("answers.toml");
+    let answers = fs::read_to_string(&answers_path).unwrap_or_default();
+
+    let dir = year_dir.join(&dir_name);
+    fs::create_dir(&dir).with_context(|| format!("error creating {}", dir.display()))?;
+    fs::write(
+        dir.join(format!("day{:02}.rs", day)),
+        TEMPLATE.replace("{title}", &title),
+    )?;
+    fs::write(dir.join("test.txt"), "")?;
+    fs::write(&lib_path, lib)?;
+    fs::write(&answers_path, add_answers(&answers, day))?;
+    Ok(dir)
+}
+
+// the position of the first item of `items` that belongs after `day`, the
+// items being ordered by the day they start with
+fn insert_at(items: &[(usize, u8)], day: u8, end: usize) -> usize {
+    items
+        .iter()
+        .find(|&&(_, d)| d > day)
+        .map_or(end, |&(at, _)| at)
+}
+
+fn positions<'a>(lib: &'a str, pattern: &'a str) -> impl Iterator<Item = (usize, &'a str)> + 'a {
+    lib.match_indices(pattern)
+        .map(move |(at, _)| (at, &lib[at + pattern.len()..]))
+}
+
+fn leading_day(rest: &str) -> Option<u8> {
+    let digits: String = rest
+        .trim_start()
+        .chars()
+        .take_while(|c| c.is_ascii_digit())
+        .collect();
+    digits.parse().ok()
+}
+
+/// Adds the module, the `day!` and the answer test of a new day to the
+/// text of a year's lib.rs, formatted the way rustfmt would.
+pub fn register(lib: &str, day: u8, dir_name: &str, title: &str) -> Result<String> {
+    let module = format!("day{:02}", day);
+    if lib.contains(&format!("pub mod {};", module)) {
+        bail!("{} is already registered", module);
+    }
+    let mut inserts = Vec::new();
+
+    let mods: Vec<_> = positions(lib, "#[path = \"Day")
+        .filter_map(|(at, rest)| Some((at, rest.get(..2)?.parse().ok()?)))
+        .collect();
+    let Some(days_start) = lib.find("pub fn days()") else {
+        bail!("lib.rs has no days()");
+    };
+    let after_mods = lib[..days_start]
+        .find("\npub const YEAR")
+        .map(|at| at + 1)
+        .unwrap_or(days_start);
+    inserts.push((
+        insert_at(&mods, day, after_mods.saturating_sub(1)),
+        format!(
+            "#[path = \"{}/{}.rs\"]\npub mod {};\n",
+            dir_name, module, module
+        ),
+    ));
+
+    let entries: Vec<_> = positions(lib, "        day!(")
+        .filter(|&(at, _)| at > days_start)
+        .filter_map(|(at, rest)| {
+            let rest = rest.trim_start().strip_prefix("YEAR,")?;
+            Some((at, leading_day(rest)?))
+        })
+        .collect();
+    let Some(vec_end) = lib[days_start..].find("    ]\n}").map(|at| at + days_start) else {
+        bail!("days() doesn't end in a vec![]");
+    };
+    let args = format!("YEAR, {}, \"{}\", {}::{}", day, dir_name, module, title);
+    // rustfmt's fn_call_width
+    let entry = if args.len() <= 60 {
+        format!("        day!({}),\n", args)
+    } else {
+        format!(
+            "        day!(\n            YEAR,\n            {},\n            \"{}\",\n            {}::{}\n        ),\n",
+            day, dir_name, module, title
+        )
+    };
+    inserts.push((insert_at(&entries, day, vec_end), entry));
+
+    let Some(tests_start) = lib.find("aoc_core::answer_tests!(") else {
+        bail!("lib.rs has no answer_tests!");
+    };
+    let tests: Vec<_> = positions(lib, "    day")
+        .filter(|&(at, _)| at > tests_start)
+        .filter_map(|(at, rest)| Some((at, leading_day(rest)?)))
+        .collect();
+    let Some(tests_end) = lib[tests_start..].find(");").map(|at| at + tests_start) else {
+        bail!("answer_tests! isn't closed");
+    };
+    inserts.push((
+        insert_at(&tests, day, tests_end),
+        format!("    {},\n", module),
+    ));
+
+    let mut lib = String::from(lib);
+    inserts.sort_by_key(|&(at, _)| std::cmp::Reverse(at));
+    for (at, text) in inserts {
+        lib.insert_str(at, &text);
+    }
+    Ok(lib)
+}
+
+/// An empty table for the answers of the test input in front of the tables
+/// of later days, to be filled in once they are known.
+pub fn add_answers(answers: &str, day: u8) -> String {
+    let table = format!("[day{:02}.test]\n", day);
+    if answers.contains(&table) {
+        return String::from(answers);
+    }
+    let tables: Vec<_> = positions(answers, "[day")
+        .filter(|&(at, _)| at == 0 || answers[..at].ends_with('\n'))
+        .filter_map(|(at, rest)| Some((at, leading_day(rest)?)))
+        .collect();
+    let mut answers = String::from(answers);
+    match tables.iter().find(|&&(_, d)| d > day) {
+        Some(&(at, _)) => answers.insert_str(at, &format!("{}\n", table)),
+        None if answers.trim_end().is_empty() => answers = table,
+        None => {
+            answers.truncate(answers.trim_end().len());
+            answers.push_str("\n\n");
+            answers.push_str(&table);
+        }
+    }
+    answers
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LIB: &str = r#"//! Advent of Code 2021.
+
+use aoc_core::{day, Day};
+
+#[path = "Day01_SonarSweep_Rust/day01.rs"]
+pub mod day01;
+#[path = "Day20_TrenchMap_Rust/day20.rs"]
+pub mod day20;
+
+pub const YEAR: u16 = 2021;
+
+pub fn days() -> Vec<Day> {
+    vec![
+        day!(YEAR, 1, "Day01_SonarSweep_Rust", day01::SonarSweep),
+        day!(
+            YEAR,
+            20,
+            "Day20_TrenchMap_Rust",
+            day20::TrenchMap
+        ),
+    ]
+}
+
+aoc_core::answer_tests!(
+    "answers.toml",
+    day01,
+    day20,
+);
+"#;
+
+    #[test]
+    fn titles_become_names() {
+        assert_eq!(
+            title_case("Extended Polymerization"),
+            "ExtendedPolymerization"
+        );
+        assert_eq!(
+            title_case("The Treachery of Whales"),
+            "TheTreacheryOfWhales"
+        );
+        assert_eq!(title_case("Not Quite Lisp"), "NotQuiteLisp");
+        assert_eq!(title_case("Monkey's Math!"), "MonkeysMath");
+        assert_eq!(title_case("pyroclastic-flow"), "PyroclasticFlow");
+    }
+
+    #[test]
+    fn registers_in_order() {
+        let lib = register(
+            LIB,
+            14,
+            "Day14_ExtendedPolymerization_Rust",
+            "ExtendedPolymerization",
+        )
+        .unwrap();
+        let day14_mod = lib.find("pub mod day14;").unwrap();
+        assert!(lib.find("pub mod day01;").unwrap() < day14_mod);
+        assert!(day14_mod < lib.find("#[path = \"Day20").unwrap());
+        assert!(lib.contains(
+            "        day!(\n            YEAR,\n            14,\n            \"Day14_ExtendedPolymerization_Rust\",\n            day14::ExtendedPolymerization\n        ),\n        day!(\n            YEAR,\n            20,"
+        ));
+        assert!(lib.contains("    day01,\n    day14,\n    day20,\n);"));
+
+        let lib = register(&lib, 25, "Day25_SeaCucumber_Rust", "SeaCucumber").unwrap();
+        assert!(lib.contains("pub mod day20;\n#[path = \"Day25_SeaCucumber_Rust/day25.rs\"]\npub mod day25;\n\npub const YEAR"));
+        assert!(lib.contains(
+            "        day!(YEAR, 25, \"Day25_SeaCucumber_Rust\", day25::SeaCucumber),\n    ]\n}"
+        ));
+        assert!(lib.contains("    day20,\n    day25,\n);"));
+        assert!(register(&lib, 14, "Day14_Other_Rust", "Other").is_err());
+    }
+
+    #[test]
+    fn adds_an_answers_table_once() {
+        let answers = add_answers("[day01.test]\npart_one = 7\n", 14);
+        assert_eq!(answers, "[day01.test]\npart_one = 7\n\n[day14.test]\n");
+        assert_eq!(add_answers(&answers, 14), answers);
+        assert_eq!(
+            add_answers(&answers, 3),
+            "[day01.test]\npart_one = 7\n\n[day03.test]\n\n[day14.test]\n"
+        );
+        assert_eq!(add_answers("", 3), "[day03.test]\n");
+    }
+}
@@ -0,0 +1,331 @@
+//! Regression tests against the answers recorded in a year's `answers.toml`.
+//!
+//! The file has a table per day and input with the expected answer of each
+//! part, the input being a file in the day's directory named without `.txt`:
+//!
+//! ```toml
+//! [day01.test]
+//! part_one = 24000
+//! part_two = 45000
+//! ```
+//!
+//! Grids are written as arrays of rows. Inputs that aren't there, like the
+//! personal `input.txt` on a fresh checkout, are skipped instead of failing.
+//!
+//! An input that the puzzle solves with other [`params`](crate::params)
+//! gives them next to the answers, lists separated by commas:
+//!
+//! ```toml
+//! [day15.test]
+//! params = { row = 10, max = 20 }
+//! part_one = 26
+//! ```
+
+use crate::params::{self, Override};
+use crate::remote::Client;
+use crate::Day;
+use anyhow::{anyhow, bail, Context, Result};
+use std::fs;
+use std::io::ErrorKind;
+use std::path::Path;
+use std::thread;
+use std::time::Duration;
+
+/// One recorded answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Expected {
+    /// The name of the input file without `.txt`.
+    pub input: String,
+    pub part: u8,
+    /// The answer the way it is displayed.
+    pub answer: String,
+    pub params: Vec<Override>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Passed(Duration),
+    Failed(String),
+    Skipped(String),
+}
+
+/// The answers recorded for `day` in the answers file at `path`.
+pub fn load(path: &Path, day: u8) -> Result<Vec<Expected>> {
+    let text =
+        fs::read_to_string(path).with_context(|| format!("error reading {}", path.display()))?;
+    let table: toml::Table = text
+        .parse()
+        .with_context(|| format!("error parsing {}", path.display()))?;
+    let key = format!("day{:02}", day);
+    let Some(inputs) = table.get(&key) else {
+        return Ok(Vec::new());
+    };
+    let inputs = inputs
+        .as_table()
+        .ok_or_else(|| anyhow!("{} has to be a table of inputs", key))?;
+    let mut expected = Vec::new();
+    for (input, parts) in inputs {
+        let parts = parts
+            .as_table()
+            .ok_or_else(|| anyhow!("{}.{} has to be a table of parts", key, input))?;
+        let Parts { params, answers } =
+            read_parts(parts).with_context(|| format!("in {}.{}", key, input))?;
+        for (part, answer) in answers {
+            expected.push(Expected {
+                input: input.clone(),
+                part,
+                answer,
+                params: params.clone(),
+            });
+        }
+    }
+    Ok(expected)
+}
+
+/// What the answers file has for one input.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Parts {
+    pub params: Vec<Override>,
+    /// The part and its answer the way it is displayed.
+    pub answers: Vec<(u8, String)>,
+}
+
+/// A file with just `params`, `part_one` and `part_two`, like the table of
+/// one input in the answers file.
+pub fn parse_parts(text: &str) -> Result<Parts> {
+    read_parts(&text.parse()?)
+}
+
+fn read_parts(parts: &toml::Table) -> Result<Parts> {
+    let mut params = Vec::new();
+    let mut answers = Vec::new();
+    for (part, answer) in parts {
+        let part = match part.as_str() {
+            "part_one" => 1,
+            "part_two" => 2,
+            "params" => {
+                let table = answer
+                    .as_table()
+                    .ok_or_else(|| anyhow!("params has to be a table"))?;
+                for (name, value) in table {
+                    params.push((name.clone(), param(value)?));
+                }
+                continue;
+            }
+            _ => bail!("{} is not part_one, part_two or params", part),
+        };
+        let answer = display(answer).ok_or_else(|| anyhow!("{} isn't an answer", answer))?;
+        answers.push((part, answer));
+    }
+    Ok(Parts { params, answers })
+}
+
+// the way --param takes the value
+fn param(value: &toml::Value) -> Result<String> {
+    match value {
+        toml::Value::Array(values) => Ok(values
+            .iter()
+            .map(param)
+            .collect::<Result<Vec<_>>>()?
+            .join(",")),
+        toml::Value::String(s) => Ok(s.clone()),
+        toml::Value::Integer(_) | toml::Value::Boolean(_) => Ok(value.to_string()),
+        _ => bail!("{} can't be a parameter", value),
+    }
+}
+
+// the same text as the Display of the matching Answer
+fn display(answer: &toml::Value) -> Option<String> {
+    match answer {
+        toml::Value::Integer(i) => Some(i.to_string()),
+        toml::Value::String(s) => Some(s.clone()),
+        toml::Value::Array(rows) => rows
+            .iter()
+            .map(|row| row.as_str())
+            .collect::<Option<Vec<_>>>()
+            .map(|rows| rows.join("\n")),
+        _ => None,
+    }
+}
+
+/// Solves every expected answer of `day` at the same time.
+pub fn check(day: &Day, expected: &[Expected]) -> Vec<Outcome> {
+    thread::scope(|s| {
+        let checks: Vec<_> = expected
+            .iter()
+            .map(|e| s.spawn(move || check_one(day, e)))
+            .collect();
+        checks
+            .into_iter()
+            .map(|check| {
+                check
+                    .join()
+                    .unwrap_or_else(|_| Outcome::Failed(String::from("the solution panicked")))
+            })
+            .collect()
+    })
+}
+
+fn check_one(day: &Day, expected: &Expected) -> Outcome {
+    let path = Path::new(day.dir).join(format!("{}.txt", expected.input));
+    // a personal input may only be cached
+    let read = match expected.input.as_str() {
+        "input" => Client::from_env().local_input(day),
+        _ => fs::read_to_string(&path),
+    };
+    let input = match read {
+        Ok(input) => input,
+        Err(err) if err.kind() == ErrorKind::NotFound => {
+            return Outcome::Skipped(format!("{} is missing", path.display()))
+        }
+        Err(err) => return Outcome::Failed(format!("error reading {}: {}", path.display(), err)),
+    };
+    let Some(solve) = day.part(expected.part) else {
+        return Outcome::Failed(format!("there is no part {}", expected.part));
+    };
+    let t = std::time::Instant::now();
+    match params::with(&expected.params, || solve(&input)) {
+        Ok(answer) if answer.to_string() == expected.answer => Outcome::Passed(t.elapsed()),
+        Ok(answer) => Outcome::Failed(format!("expected {} but got {}", expected.answer, answer)),
+        Err(err) => Outcome::Failed(format!("{:#}", err)),
+    }
+}
+
+/// Checks day `day` of `days` against the answers file at `path` and panics
+/// with every wrong answer.
+pub fn assert_day(days: &[Day], day: u8, path: &str) {
+    let day = days
+        .iter()
+        .find(|d| d.day == day)
+        .unwrap_or_else(|| panic!("there is no solution for day {}", day));
+    let expected = load(Path::new(path), day.day).unwrap_or_else(|err| panic!("{:#}", err));
+    if expected.is_empty() {
+        eprintln!("{} day {:02}: no answers recorded", day.year, day.day);
+    }
+    let mut failures = Vec::new();
+    for (e, outcome) in expected.iter().zip(check(day, &expected)) {
+        let name = if e.part == 1 { "one" } else { "two" };
+        let label = format!(
+            "{} day {:02} part {} on {}",
+            day.year, day.day, name, e.input
+        );
+        match outcome {
+            Outcome::Passed(elapsed) => eprintln!("{}: ok in {:0.2?}", label, elapsed),
+            Outcome::Skipped(reason) => eprintln!("{}: skipped, {}", label, reason),
+            Outcome::Failed(reason) => failures.push(format!("{}: {}", label, reason)),
+        }
+    }
+    assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+}
+
+/// A test per day that checks the answers recorded in `$answers`, a path
+/// relative to the calling year crate whose `days()` are checked.
+///
+/// The tests are named like the day modules, `day01`, `day02` and so on.
+#[macro_export]
+macro_rules! answer_tests {
+    ($answers:literal, $($name:ident),+ $(,)?) => {
+        #[cfg(test)]
+        mod answer_tests {
+            $(
+                #[test]
+                fn $name() {
+                    let day = stringify!($name)
+                        .trim_start_matches("day")
+                        .parse()
+                        .expect("answer tests are named after the day modules");
+                    let path = concat!(env!("CARGO_MANIFEST_DIR"), "/", $answers);
+                    $crate::answers::assert_day(&super::days(), day, path);
+                }
+            )+
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Answer, ParseError, Solution};
+
+    struct Sum;
+
+    impl Solution for Sum {
+        type Input = Vec<i64>;
+
+        fn parse_input(input: &str) -> Result<Vec<i64>, ParseError> {
+            input
+                .split_whitespace()
+                .map(|n| crate::parse::value(input, n, "a number"))
+                .collect()
+        }
+
+        fn part_one(numbers: Vec<i64>) -> Result<Answer> {
+            Ok(Answer::from(numbers.iter().sum::<i64>()))
+        }
+
+        fn part_two(numbers: Vec<i64>) -> Result<Answer> {
+            Ok(Answer::from(vec![format!("{:?}", numbers)]))
+        }
+    }
+
+    fn dir() -> std::path::PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc_answers_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn checks_recorded_answers_and_skips_missing_inputs() {
+        let dir = dir();
+        fs::write(dir.join("test.txt"), "1 2 3").unwrap();
+        let answers = dir.join("answers.toml");
+        fs::write(
+            &answers,
+            "[day01.test]\npart_one = 6\npart_two = [\"[1, 2, 3]\"]\n\n\
+             [day01.input]\npart_one = 7\n\n\
+             [day02.test]\npart_one = 7\n",
+        )
+        .unwrap();
+        let dir: &'static str = Box::leak(dir.to_string_lossy().into_owned().into_boxed_str());
+        // a year nothing is cached for
+        let day = Day::new::<Sum>(1, 1, dir);
+
+        let expected = load(&answers, 1).unwrap();
+        assert_eq!(expected.len(), 3);
+        let outcomes = check(&day, &expected);
+        let input = expected.iter().position(|e| e.input == "input").unwrap();
+        assert!(matches!(outcomes[input], Outcome::Skipped(_)));
+        assert!(outcomes
+            .iter()
+            .enumerate()
+            .all(|(i, o)| i == input || matches!(o, Outcome::Passed(_))));
+
+        let wrong = Day::new::<Sum>(2022, 2, dir);
+        let outcomes = check(&wrong, &load(&answers, 2).unwrap());
+        assert_eq!(
+            outcomes,
+            [Outcome::Failed(String::from("expected 7 but got 6"))]
+        );
+        assert!(load(&answers, 3).unwrap().is_empty());
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn reads_params_next_to_the_answers() {
+        let Parts { params, answers } = parse_parts(
+            "params = { row = 10, cycles = [20, 60], start = \"AA\" }\npart_one = 26\n",
+        )
+        .unwrap();
+        let params: Vec<_> = params
+            .iter()
+            .map(|(n, v)| (n.as_str(), v.as_str()))
+            .collect();
+        assert_eq!(
+            params,
+            [("cycles", "20,60"), ("row", "10"), ("start", "AA")]
+        );
+        assert_eq!(answers, [(1, String::from("26"))]);
+        assert!(parse_parts("params = 10\n").is_err());
+        assert!(parse_parts("part_three = 10\n").is_err());
+    }
+}
@@ -0,0 +1,97 @@
+//! Fast-forwarding simulations that end up repeating themselves.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// The values of a simulation from the start until its state repeats, enough
+/// of them to know the value after any number of steps.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cycle<V> {
+    /// The steps before the loop is entered.
+    pub start: usize,
+    /// The steps once around the loop.
+    pub length: usize,
+    /// The value after 0, 1, ... up to `start + length` steps.
+    pub values: Vec<V>,
+}
+
+/// Steps `state` until a key shows up a second time, recording `value` of
+/// every state on the way. Equal keys have to mean equal futures.
+pub fn find<S, K, V>(
+    mut state: S,
+    mut step: impl FnMut(&mut S),
+    mut key: impl FnMut(&S) -> K,
+    mut value: impl FnMut(&S) -> V,
+) -> Cycle<V>
+where
+    K: Eq + Hash,
+{
+    let mut seen = HashMap::new();
+    let mut values = Vec::new();
+    loop {
+        values.push(value(&state));
+        let steps = values.len() - 1;
+        if let Some(start) = seen.insert(key(&state), steps) {
+            return Cycle {
+                start,
+                length: steps - start,
+                values,
+            };
+        }
+        step(&mut state);
+    }
+}
+
+impl<V> Cycle<V> {
+    /// The first step that is in the same state as step `n`.
+    pub fn equivalent_step(&self, n: usize) -> usize {
+        if n < self.start {
+            n
+        } else {
+            self.start + (n - self.start) % self.length
+        }
+    }
+
+    /// The value after `n` steps.
+    pub fn value_at(&self, n: usize) -> &V {
+        &self.values[self.equivalent_step(n)]
+    }
+}
+
+impl Cycle<i128> {
+    /// The value after `n` steps for values that grow by the same amount
+    /// every time around the loop, a height or a count for example.
+    pub fn extrapolate(&self, n: usize) -> i128 {
+        if n < self.values.len() {
+            return self.values[n];
+        }
+        let growth = self.values[self.start + self.length] - self.values[self.start];
+        let loops = ((n - self.start) / self.length) as i128;
+        self.values[self.equivalent_step(n)] + loops * growth
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_the_loop() {
+        // 0 1 2 3 4 2 3 4 ...
+        let cycle = find(0, |s| *s = if *s == 4 { 2 } else { *s + 1 }, |s| *s, |s| *s);
+        assert_eq!((cycle.start, cycle.length), (2, 3));
+        assert_eq!(cycle.values, [0, 1, 2, 3, 4, 2]);
+        assert_eq!(cycle.equivalent_step(1), 1);
+        assert_eq!(cycle.equivalent_step(8), 2);
+        assert_eq!(*cycle.value_at(1_000_000_000_000), 4);
+    }
+
+    #[test]
+    fn extrapolates_growing_values() {
+        // the position on a track of length 5 together with the distance walked
+        let cycle = find((0, 0), |s| *s = ((s.0 + 1) % 5, s.1 + 3), |s| s.0, |s| s.1);
+        assert_eq!((cycle.start, cycle.length), (0, 5));
+        assert_eq!(cycle.extrapolate(2), 6);
+        assert_eq!(cycle.extrapolate(1_000_000_000_000), 3_000_000_000_000);
+    }
+}
@@ -0,0 +1,96 @@
+//! Differential tests of an optimized implementation against a slow one
+//! that is obviously right, on generated puzzle inputs.
+//!
+//! A day that keeps a reference next to its fast version checks both with
+//! [`assert_agree`] on inputs from a proptest [`Strategy`] that only makes
+//! valid puzzle inputs. When they disagree, proptest shrinks the input and
+//! the test fails with the smallest one it found, ready for a test.txt.
+//!
+//! Only built with the `differential` feature, which the year crates turn
+//! on for their tests. `PROPTEST_CASES` sets the number of inputs, 256 by
+//! default.
+
+use proptest::strategy::Strategy;
+use proptest::test_runner::{Config, TestCaseError, TestError, TestRunner};
+use std::fmt::Debug;
+
+pub use proptest;
+
+/// Panics with the smallest input from `inputs` that `fast` and `reference`
+/// answer differently. A panic in either of them fails the same way, with
+/// the smallest input that makes it panic.
+pub fn assert_agree<S, R>(inputs: S, fast: impl Fn(&str) -> R, reference: impl Fn(&str) -> R)
+where
+    S: Strategy<Value = String>,
+    R: PartialEq + Debug,
+{
+    // a failing input is printed, not saved next to the sources
+    let config = Config {
+        failure_persistence: None,
+        ..Config::default()
+    };
+    let result = TestRunner::new(config).run(&inputs, |input| {
+        let (fast, reference) = (fast(&input), reference(&input));
+        if fast == reference {
+            Ok(())
+        } else {
+            Err(TestCaseError::fail(format!(
+                "fast {:?} but reference {:?}",
+                fast, reference
+            )))
+        }
+    });
+    match result {
+        Ok(()) => (),
+        Err(TestError::Fail(reason, input)) => panic!(
+            "the implementations disagree, {}, the smallest input is\n{}",
+            reason, input
+        ),
+        Err(TestError::Abort(reason)) => panic!("no inputs were tested: {}", reason),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+    use std::panic;
+
+    fn words() -> impl Strategy<Value = String> {
+        prop::collection::vec(prop::char::range('a', 'z'), 0..40)
+            .prop_map(|letters| letters.into_iter().collect())
+    }
+
+    #[test]
+    fn agreeing_implementations_pass() {
+        assert_agree(words(), |s| s.len(), |s| s.chars().count());
+    }
+
+    #[test]
+    fn disagreements_shrink() {
+        let failed = panic::catch_unwind(|| {
+            // forgets every q
+            let fast = |s: &str| s.chars().filter(|&c| c != 'q').count();
+            assert_agree(words(), fast, |s| s.chars().count());
+        })
+        .unwrap_err();
+        let message = failed.downcast::<String>().unwrap();
+        assert!(message.starts_with("the implementations disagree, fast 0 but reference 1"));
+        assert!(message.ends_with("the smallest input is\nq"));
+    }
+
+    #[test]
+    fn panics_fail() {
+        let failed = panic::catch_unwind(|| {
+            let fast = |s: &str| {
+                assert!(!s.contains('q'), "no q");
+                s.len()
+            };
+            assert_agree(words(), fast, |s| s.len());
+        })
+        .unwrap_err();
+        let message = failed.downcast::<String>().unwrap();
+        assert!(message.starts_with("the implementations disagree"));
+        assert!(message.ends_with("the smallest input is\nq"));
+    }
+}
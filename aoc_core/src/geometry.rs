@@ -0,0 +1,230 @@
+//! Integer vectors for puzzles that live on an unbounded plane or in space.
+//!
+//! `x` grows to the right and `y` grows downwards, the same way the rows of a
+//! puzzle input are read.
+
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Vec2 {
+    pub x: i128,
+    pub y: i128,
+}
+
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Vec3 {
+    pub x: i128,
+    pub y: i128,
+    pub z: i128,
+}
+
+macro_rules! vector_ops {
+    ($vec:ident { $($c:ident),+ }) => {
+        impl Add for $vec {
+            type Output = $vec;
+            fn add(self, rhs: Self) -> Self::Output {
+                $vec { $($c: self.$c + rhs.$c),+ }
+            }
+        }
+
+        impl Sub for $vec {
+            type Output = $vec;
+            fn sub(self, rhs: Self) -> Self::Output {
+                $vec { $($c: self.$c - rhs.$c),+ }
+            }
+        }
+
+        impl Neg for $vec {
+            type Output = $vec;
+            fn neg(self) -> Self::Output {
+                $vec { $($c: -self.$c),+ }
+            }
+        }
+
+        impl Mul<i128> for $vec {
+            type Output = $vec;
+            fn mul(self, rhs: i128) -> Self::Output {
+                $vec { $($c: self.$c * rhs),+ }
+            }
+        }
+
+        impl AddAssign for $vec {
+            fn add_assign(&mut self, rhs: Self) {
+                *self = *self + rhs;
+            }
+        }
+
+        impl SubAssign for $vec {
+            fn sub_assign(&mut self, rhs: Self) {
+                *self = *self - rhs;
+            }
+        }
+
+        impl $vec {
+            pub fn manhattan(&self, other: &$vec) -> i128 {
+                0 $(+ (self.$c - other.$c).abs())+
+            }
+
+            /// The distance when diagonal steps are allowed.
+            pub fn chebyshev(&self, other: &$vec) -> i128 {
+                [$((self.$c - other.$c).abs()),+].into_iter().max().unwrap()
+            }
+
+            /// Every component reduced to -1, 0 or 1, a single step towards
+            /// the direction of the vector.
+            pub fn signum(&self) -> $vec {
+                $vec { $($c: self.$c.signum()),+ }
+            }
+
+            pub fn component_min(&self, other: &$vec) -> $vec {
+                $vec { $($c: self.$c.min(other.$c)),+ }
+            }
+
+            pub fn component_max(&self, other: &$vec) -> $vec {
+                $vec { $($c: self.$c.max(other.$c)),+ }
+            }
+
+            /// The smallest and the largest corner of the box around all
+            /// points, `None` without points.
+            pub fn bounding_box(points: impl IntoIterator<Item = $vec>) -> Option<($vec, $vec)> {
+                points.into_iter().fold(None, |bounds, p| match bounds {
+                    None => Some((p, p)),
+                    Some((min, max)) => Some((min.component_min(&p), max.component_max(&p))),
+                })
+            }
+        }
+    };
+}
+
+vector_ops!(Vec2 { x, y });
+vector_ops!(Vec3 { x, y, z });
+
+/// The eight positions around a point clockwise, starting with up.
+pub const OFFSETS_8: [Vec2; 8] = [
+    Vec2::new(0, -1),
+    Vec2::new(1, -1),
+    Vec2::new(1, 0),
+    Vec2::new(1, 1),
+    Vec2::new(0, 1),
+    Vec2::new(-1, 1),
+    Vec2::new(-1, 0),
+    Vec2::new(-1, -1),
+];
+
+/// The six cubes sharing a face with a cube.
+pub const OFFSETS_6: [Vec3; 6] = [
+    Vec3::new(-1, 0, 0),
+    Vec3::new(1, 0, 0),
+    Vec3::new(0, -1, 0),
+    Vec3::new(0, 1, 0),
+    Vec3::new(0, 0, -1),
+    Vec3::new(0, 0, 1),
+];
+
+impl Vec2 {
+    pub const fn new(x: i128, y: i128) -> Self {
+        Vec2 { x, y }
+    }
+
+    /// Up, right, down and left of this point.
+    pub fn neighbors4(self) -> impl Iterator<Item = Vec2> {
+        Direction::ALL.into_iter().map(move |d| self + d.offset())
+    }
+
+    pub fn neighbors8(self) -> impl Iterator<Item = Vec2> {
+        OFFSETS_8.into_iter().map(move |d| self + d)
+    }
+}
+
+impl Vec3 {
+    pub const fn new(x: i128, y: i128, z: i128) -> Self {
+        Vec3 { x, y, z }
+    }
+
+    pub fn neighbors6(self) -> impl Iterator<Item = Vec3> {
+        OFFSETS_6.into_iter().map(move |d| self + d)
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    /// Clockwise starting with up.
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    pub fn turn_right(self) -> Direction {
+        Direction::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn turn_left(self) -> Direction {
+        Direction::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn opposite(self) -> Direction {
+        Direction::ALL[(self as usize + 2) % 4]
+    }
+
+    /// A single step in this direction.
+    pub fn offset(self) -> Vec2 {
+        match self {
+            Direction::Up => Vec2::new(0, -1),
+            Direction::Right => Vec2::new(1, 0),
+            Direction::Down => Vec2::new(0, 1),
+            Direction::Left => Vec2::new(-1, 0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arithmetic_and_distances() {
+        let a = Vec2::new(1, -2);
+        let b = Vec2::new(-3, 5);
+        assert_eq!(a + b, Vec2::new(-2, 3));
+        assert_eq!(a - b, Vec2::new(4, -7));
+        assert_eq!(-a * 2, Vec2::new(-2, 4));
+        assert_eq!(a.manhattan(&b), 11);
+        assert_eq!(a.chebyshev(&b), 7);
+        assert_eq!((b - a).signum(), Vec2::new(-1, 1));
+        let c = Vec3::new(1, 2, 3);
+        assert_eq!(c.manhattan(&Vec3::default()), 6);
+        assert_eq!(c.neighbors6().filter(|n| n.manhattan(&c) == 1).count(), 6);
+    }
+
+    #[test]
+    fn bounding_boxes() {
+        let points = [Vec2::new(2, 0), Vec2::new(-1, 4), Vec2::new(0, -3)];
+        assert_eq!(
+            Vec2::bounding_box(points),
+            Some((Vec2::new(-1, -3), Vec2::new(2, 4)))
+        );
+        assert_eq!(Vec3::bounding_box([]), None);
+    }
+
+    #[test]
+    fn turning() {
+        use Direction::*;
+        assert_eq!(Up.turn_right(), Right);
+        assert_eq!(Up.turn_left(), Left);
+        assert_eq!(Left.turn_right().turn_right(), Left.opposite());
+        let origin = Vec2::default();
+        for d in Direction::ALL {
+            assert_eq!(d.offset() + d.opposite().offset(), origin);
+            assert_eq!(origin.neighbors8().filter(|&n| n == d.offset()).count(), 1);
+        }
+    }
+}
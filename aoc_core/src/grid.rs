@@ -0,0 +1,287 @@
+//! A rectangular grid of cells addressed by `(row, col)`.
+
+use crate::{input, ParseError};
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+/// A position in a grid as `(row, col)`.
+pub type Pos = (usize, usize);
+
+/// Up, right, down, left as `(row, col)` offsets.
+pub const FOUR_NEIGHBORS: [(isize, isize); 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+
+/// Clockwise starting with up as `(row, col)` offsets.
+pub const EIGHT_NEIGHBORS: [(isize, isize); 8] = [
+    (-1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+    (1, 0),
+    (1, -1),
+    (0, -1),
+    (-1, -1),
+];
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    rows: usize,
+    cols: usize,
+}
+
+impl<T> Grid<T> {
+    /// A grid with every cell set to `value`.
+    pub fn new(rows: usize, cols: usize, value: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid {
+            cells: vec![value; rows * cols],
+            rows,
+            cols,
+        }
+    }
+
+    /// Panics if the rows don't all have the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Grid<T> {
+        let cols = rows.first().map_or(0, Vec::len);
+        assert!(
+            rows.iter().all(|row| row.len() == cols),
+            "all rows of a grid need the same length"
+        );
+        Grid {
+            rows: rows.len(),
+            cols,
+            cells: rows.into_iter().flatten().collect(),
+        }
+    }
+
+    /// Parses a map with one character per cell, `cell` returns `None` for
+    /// characters that aren't `expected`.
+    pub fn parse(
+        text: &str,
+        expected: &str,
+        cell: impl Fn(char) -> Option<T>,
+    ) -> Result<Grid<T>, ParseError> {
+        let mut cells = Vec::new();
+        let mut rows = 0;
+        let mut cols = None;
+        for line in input::lines(text) {
+            let before = cells.len();
+            for (i, c) in line.char_indices() {
+                cells.push(cell(c).ok_or_else(|| ParseError::at(text, &line[i..], expected))?);
+            }
+            let len = cells.len() - before;
+            if *cols.get_or_insert(len) != len {
+                return Err(ParseError::at(
+                    text,
+                    line,
+                    format!("a row of {} cells", cols.unwrap_or(0)),
+                ));
+            }
+            rows += 1;
+        }
+        match cols {
+            Some(cols) if cols > 0 => Ok(Grid { cells, rows, cols }),
+            _ => Err(ParseError::missing(text, format!("a row of {}", expected))),
+        }
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn contains(&self, (row, col): Pos) -> bool {
+        row < self.rows && col < self.cols
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        if self.contains(pos) {
+            Some(&self.cells[pos.0 * self.cols + pos.1])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        if self.contains(pos) {
+            Some(&mut self.cells[pos.0 * self.cols + pos.1])
+        } else {
+            None
+        }
+    }
+
+    /// The position `delta` away from `pos` if it is inside the grid.
+    pub fn offset(&self, (row, col): Pos, (drow, dcol): (isize, isize)) -> Option<Pos> {
+        let pos = (row.checked_add_signed(drow)?, col.checked_add_signed(dcol)?);
+        self.contains(pos).then_some(pos)
+    }
+
+    /// The positions of signed coordinates that lie inside the grid.
+    pub fn checked_pos(&self, row: i64, col: i64) -> Option<Pos> {
+        let pos = (usize::try_from(row).ok()?, usize::try_from(col).ok()?);
+        self.contains(pos).then_some(pos)
+    }
+
+    /// Up, right, down and left of `pos` as far as they are inside.
+    pub fn neighbors4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        FOUR_NEIGHBORS
+            .iter()
+            .filter_map(move |&delta| self.offset(pos, delta))
+    }
+
+    /// All eight surrounding positions of `pos` as far as they are inside.
+    pub fn neighbors8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        EIGHT_NEIGHBORS
+            .iter()
+            .filter_map(move |&delta| self.offset(pos, delta))
+    }
+
+    /// Every position in row-major order.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let cols = self.cols;
+        (0..self.rows).flat_map(move |row| (0..cols).map(move |col| (row, col)))
+    }
+
+    /// Every cell in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.cells.iter_mut()
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.cols..(row + 1) * self.cols]
+    }
+
+    pub fn column(&self, col: usize) -> impl DoubleEndedIterator<Item = &T> {
+        self.cells.iter().skip(col).step_by(self.cols.max(1))
+    }
+
+    pub fn iter_rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.cols.max(1))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            rows: self.rows,
+            cols: self.cols,
+        }
+    }
+
+    /// Builds a grid cell by cell.
+    pub fn from_fn(rows: usize, cols: usize, mut f: impl FnMut(Pos) -> T) -> Grid<T> {
+        let cells = (0..rows)
+            .flat_map(|row| (0..cols).map(move |col| (row, col)))
+            .map(&mut f)
+            .collect();
+        Grid { cells, rows, cols }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// Rows become columns.
+    pub fn transpose(&self) -> Grid<T> {
+        Grid::from_fn(self.cols, self.rows, |(row, col)| self[(col, row)].clone())
+    }
+
+    /// A quarter turn clockwise.
+    pub fn rotate_right(&self) -> Grid<T> {
+        Grid::from_fn(self.cols, self.rows, |(row, col)| {
+            self[(self.rows - 1 - col, row)].clone()
+        })
+    }
+
+    /// A quarter turn counterclockwise.
+    pub fn rotate_left(&self) -> Grid<T> {
+        Grid::from_fn(self.cols, self.rows, |(row, col)| {
+            self[(col, self.cols - 1 - row)].clone()
+        })
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("{:?} is outside of the grid", pos))
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("{:?} is outside of the grid", pos))
+    }
+}
+
+/// One line per row without separators between the cells.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, row) in self.iter_rows().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn digits(text: &str) -> Result<Grid<u32>, ParseError> {
+        Grid::parse(text, "a digit", |c| c.to_digit(10))
+    }
+
+    #[test]
+    fn parse_and_access() {
+        let grid = digits("123\r\n456\r\n").unwrap();
+        assert_eq!((grid.rows(), grid.cols()), (2, 3));
+        assert_eq!(grid[(1, 0)], 4);
+        assert_eq!(grid.get((2, 0)), None);
+        assert_eq!(grid.row(1), [4, 5, 6]);
+        assert_eq!(grid.column(2).collect::<Vec<_>>(), [&3, &6]);
+        assert_eq!(grid.to_string(), "123\n456");
+
+        let err = digits("123\n4x6").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 2));
+        let err = digits("123\n45").unwrap_err();
+        assert_eq!(err.expected, "a row of 3 cells");
+        assert!(digits("").is_err());
+    }
+
+    #[test]
+    fn neighbors_stay_inside() {
+        let grid = Grid::new(3, 3, 0);
+        assert_eq!(
+            grid.neighbors4((0, 0)).collect::<Vec<_>>(),
+            [(0, 1), (1, 0)]
+        );
+        assert_eq!(grid.neighbors8((1, 1)).count(), 8);
+        assert_eq!(grid.neighbors8((2, 2)).count(), 3);
+        assert_eq!(grid.checked_pos(-1, 0), None);
+        assert_eq!(grid.checked_pos(2, 1), Some((2, 1)));
+    }
+
+    #[test]
+    fn transpose_and_rotate() {
+        let grid = Grid::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+        assert_eq!(grid.transpose().to_string(), "14\n25\n36");
+        assert_eq!(grid.rotate_right().to_string(), "41\n52\n63");
+        assert_eq!(grid.rotate_left().to_string(), "36\n25\n14");
+        assert_eq!(grid.rotate_right().rotate_left(), grid);
+    }
+}
@@ -0,0 +1,65 @@
+//! Splitting puzzle inputs the same way no matter if they use CRLF or LF line
+//! endings and if they end with a newline or not.
+
+/// The lines of the input without their line endings.
+pub fn lines(input: &str) -> impl Iterator<Item = &str> {
+    input.lines().map(|l| l.strip_suffix('\r').unwrap_or(l))
+}
+
+/// The sections of the input that are separated by blank lines.
+///
+/// A block keeps the line endings between its own lines but never the ones
+/// around it, consecutive blank lines count as one separator.
+pub fn blocks(input: &str) -> Vec<&str> {
+    let mut blocks = Vec::new();
+    let mut start = None;
+    let mut end = 0;
+    let mut offset = 0;
+    for line in input.split_inclusive('\n') {
+        let content = line.trim_end_matches(['\r', '\n']);
+        if content.is_empty() {
+            if let Some(start) = start.take() {
+                blocks.push(&input[start..end]);
+            }
+        } else {
+            start.get_or_insert(offset);
+            end = offset + content.len();
+        }
+        offset += line.len();
+    }
+    if let Some(start) = start {
+        blocks.push(&input[start..end]);
+    }
+    blocks
+}
+
+/// The input as rows of characters.
+pub fn grid(input: &str) -> Vec<Vec<char>> {
+    lines(input).map(|l| l.chars().collect()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LF: &str = "1\n2\n\n  3\n\n\n4\n";
+    const CRLF: &str = "1\r\n2\r\n\r\n  3\r\n\r\n\r\n4\r\n";
+
+    #[test]
+    fn blocks_ignore_line_endings() {
+        for input in [LF, CRLF, LF.trim_end(), CRLF.trim_end()] {
+            let blocks = blocks(input);
+            assert_eq!(blocks.len(), 3, "{:?}", input);
+            assert_eq!(lines(blocks[0]).collect::<Vec<_>>(), ["1", "2"]);
+            assert_eq!(blocks[1], "  3");
+            assert_eq!(blocks[2], "4");
+        }
+    }
+
+    #[test]
+    fn lines_and_grid_ignore_line_endings() {
+        assert_eq!(lines("ab\r\ncd\r").collect::<Vec<_>>(), ["ab", "cd"]);
+        assert_eq!(grid("ab\ncd\n"), grid("ab\r\ncd"));
+        assert_eq!(grid("ab\ncd"), [['a', 'b'], ['c', 'd']]);
+    }
+}
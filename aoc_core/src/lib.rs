@@ -0,0 +1,25 @@
+//! Shared building blocks for the Advent of Code solutions.
+
+pub mod answers;
+pub mod cycle;
+#[cfg(feature = "differential")]
+pub mod differential;
+pub mod geometry;
+pub mod grid;
+pub mod input;
+pub mod noise;
+pub mod ocr;
+pub mod params;
+pub mod parse;
+pub mod record;
+mod registry;
+pub mod remote;
+pub mod search;
+mod solution;
+pub mod submit;
+
+pub use geometry::{Direction, Vec2, Vec3};
+pub use grid::Grid;
+pub use parse::ParseError;
+pub use registry::{Day, Parsed, ParsedPart, Part};
+pub use solution::{Answer, Solution};
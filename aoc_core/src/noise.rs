@@ -0,0 +1,61 @@
+//! Pseudo random numbers for the synthetic inputs of
+//! [`Solution::worst_cases`](crate::Solution::worst_cases).
+//!
+//! The same seed gives the same numbers on every machine and run, so the
+//! worst cases stay the same between benchmarks.
+
+use std::ops::RangeInclusive;
+
+/// A xorshift generator, good enough to scatter puzzle inputs.
+#[derive(Debug, Clone)]
+pub struct Noise {
+    state: u64,
+}
+
+impl Noise {
+    pub fn new(seed: u64) -> Self {
+        // xorshift never leaves 0
+        Noise {
+            state: seed ^ 0x9e37_79b9_7f4a_7c15,
+        }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state ^= self.state << 13;
+        self.state ^= self.state >> 7;
+        self.state ^= self.state << 17;
+        self.state
+    }
+
+    /// A number in `range`, both ends included.
+    pub fn range(&mut self, range: RangeInclusive<i128>) -> i128 {
+        let (lo, hi) = range.into_inner();
+        assert!(lo <= hi, "the range {}..={} is empty", lo, hi);
+        let span = (hi - lo) as u128 + 1;
+        lo + (u128::from(self.next_u64()) % span) as i128
+    }
+
+    /// One of `items`.
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.range(0..=items.len() as i128 - 1) as usize]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn same_seed_same_numbers() {
+        let (mut a, mut b) = (Noise::new(7), Noise::new(7));
+        let numbers: Vec<_> = (0..100).map(|_| a.range(-3..=3)).collect();
+        assert_eq!(
+            numbers,
+            (0..100).map(|_| b.range(-3..=3)).collect::<Vec<_>>()
+        );
+        assert!(numbers.iter().all(|n| (-3..=3).contains(n)));
+        assert!((-3..=3).all(|n| numbers.contains(&n)));
+        assert_ne!(Noise::new(0).next_u64(), 0);
+        assert_eq!(*Noise::new(1).pick(&['x']), 'x');
+    }
+}
@@ -0,0 +1,163 @@
+//! Reading the block letters that some puzzles draw instead of printing an
+//! answer.
+//!
+//! There are two fonts: letters of 4x6 pixels one column apart (CRT screens,
+//! folded paper) and letters of 6x10 pixels two columns apart (stars in the
+//! sky).
+
+use crate::{Answer, Grid};
+
+struct Font {
+    width: usize,
+    height: usize,
+    spacing: usize,
+    glyphs: &'static [(char, &'static [&'static str])],
+}
+
+const SMALL: Font = Font {
+    width: 4,
+    height: 6,
+    spacing: 1,
+    glyphs: &[
+        ('A', &[".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+        ('B', &["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+        ('C', &[".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+        ('E', &["####", "#...", "###.", "#...", "#...", "####"]),
+        ('F', &["####", "#...", "###.", "#...", "#...", "#..."]),
+        ('G', &[".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+        ('H', &["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+        ('J', &["..##", "...#", "...#", "...#", "#..#", ".##."]),
+        ('K', &["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+        ('L', &["#...", "#...", "#...", "#...", "#...", "####"]),
+        ('O', &[".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+        ('P', &["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+        ('R', &["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+        ('S', &[".###", "#...", "#...", ".##.", "...#", "###."]),
+        ('U', &["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+        ('Z', &["####", "...#", "..#.", ".#..", "#...", "####"]),
+    ],
+};
+
+#[rustfmt::skip]
+const LARGE: Font = Font {
+    width: 6,
+    height: 10,
+    spacing: 2,
+    glyphs: &[
+        ('A', &["..##..", ".#..#.", "#....#", "#....#", "#....#", "######", "#....#", "#....#", "#....#", "#....#"]),
+        ('B', &["#####.", "#....#", "#....#", "#....#", "#####.", "#....#", "#....#", "#....#", "#....#", "#####."]),
+        ('C', &[".####.", "#....#", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#....#", ".####."]),
+        ('E', &["######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....", "#.....", "######"]),
+        ('F', &["######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....", "#.....", "#....."]),
+        ('G', &[".####.", "#....#", "#.....", "#.....", "#.....", "#..###", "#....#", "#....#", "#...##", ".###.#"]),
+        ('H', &["#....#", "#....#", "#....#", "#....#", "######", "#....#", "#....#", "#....#", "#....#", "#....#"]),
+        ('J', &["...###", "....#.", "....#.", "....#.", "....#.", "....#.", "....#.", "#...#.", "#...#.", ".###.."]),
+        ('K', &["#....#", "#...#.", "#..#..", "#.#...", "##....", "##....", "#.#...", "#..#..", "#...#.", "#....#"]),
+        ('L', &["#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "######"]),
+        ('N', &["#....#", "##...#", "##...#", "#.#..#", "#.#..#", "#..#.#", "#..#.#", "#...##", "#...##", "#....#"]),
+        ('P', &["#####.", "#....#", "#....#", "#....#", "#####.", "#.....", "#.....", "#.....", "#.....", "#....."]),
+        ('R', &["#####.", "#....#", "#....#", "#....#", "#####.", "#..#..", "#...#.", "#...#.", "#....#", "#....#"]),
+        ('X', &["#....#", "#....#", ".#..#.", ".#..#.", "..##..", "..##..", ".#..#.", ".#..#.", "#....#", "#....#"]),
+        ('Z', &["######", ".....#", ".....#", "....#.", "...#..", "..#...", ".#....", "#.....", "#.....", "######"]),
+    ],
+};
+
+impl Font {
+    /// The letters of a grid that is exactly one line of text, the blank
+    /// columns after the last letter may be cut off.
+    fn recognize(&self, pixels: &Grid<bool>) -> Option<String> {
+        let pitch = self.width + self.spacing;
+        let letters = (pixels.cols() + self.spacing) / pitch;
+        if pixels.rows() != self.height || letters == 0 || pixels.cols() > letters * pitch {
+            return None;
+        }
+        let lit = |row: usize, col: usize| pixels.get((row, col)).copied().unwrap_or(false);
+        (0..letters)
+            .map(|i| {
+                let left = i * pitch;
+                let spacing_is_blank = (0..self.height)
+                    .all(|row| (left + self.width..left + pitch).all(|col| !lit(row, col)));
+                if !spacing_is_blank {
+                    return None;
+                }
+                self.glyphs.iter().find_map(|&(letter, glyph)| {
+                    let matches = glyph.iter().enumerate().all(|(row, line)| {
+                        line.chars()
+                            .enumerate()
+                            .all(|(col, c)| (c == '#') == lit(row, left + col))
+                    });
+                    matches.then_some(letter)
+                })
+            })
+            .collect()
+    }
+}
+
+/// The text written in either font, `None` unless every letter is known.
+pub fn recognize(pixels: &Grid<bool>) -> Option<String> {
+    SMALL.recognize(pixels).or_else(|| LARGE.recognize(pixels))
+}
+
+/// The text of a screen drawn with `#` on `.`, or the screen itself if it
+/// can't be read.
+pub fn answer(rows: Vec<String>) -> Answer {
+    let pixels = Grid::parse(&rows.join("\n"), "# or .", |c| match c {
+        '#' => Some(true),
+        '.' => Some(false),
+        _ => None,
+    });
+    match pixels.ok().as_ref().and_then(recognize) {
+        Some(text) => Answer::from(text),
+        None => Answer::from(rows),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn screen(rows: &[&str]) -> Vec<String> {
+        rows.iter().map(|&row| String::from(row)).collect()
+    }
+
+    #[test]
+    fn reads_small_letters() {
+        let rows = screen(&[
+            "#..#.####.#....#.....##..",
+            "#..#.#....#....#....#..#.",
+            "####.###..#....#....#..#.",
+            "#..#.#....#....#....#..#.",
+            "#..#.#....#....#....#..#.",
+            "#..#.####.####.####..##..",
+        ]);
+        assert_eq!(answer(rows.clone()), "HELLO");
+        // without the blank column at the end
+        let cut: Vec<String> = rows.iter().map(|row| String::from(&row[..24])).collect();
+        assert_eq!(answer(cut), "HELLO");
+    }
+
+    #[test]
+    fn reads_large_letters() {
+        let glyph = |letter| LARGE.glyphs.iter().find(|g| g.0 == letter).unwrap().1;
+        let rows: Vec<String> = (0..10)
+            .map(|row| format!("{}..{}", glyph('X')[row], glyph('Z')[row]))
+            .collect();
+        assert_eq!(answer(rows), "XZ");
+    }
+
+    #[test]
+    fn keeps_what_it_cannot_read() {
+        let square = screen(&["#####", "#...#", "#...#", "#...#", "#####"]);
+        assert_eq!(answer(square.clone()), Answer::from(square));
+        // a known letter next to an unknown one
+        let rows = screen(&[
+            "####.#..#",
+            "#.....##.",
+            "###..#..#",
+            "#....#..#",
+            "#.....##.",
+            "#....#..#",
+        ]);
+        assert_eq!(answer(rows.clone()), Answer::from(rows));
+    }
+}
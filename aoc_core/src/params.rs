@@ -0,0 +1,268 @@
+//! The numbers a puzzle text sets apart from the input, like the row that
+//! 2022 day 15 looks at, which is another one for the example.
+//!
+//! A day declares them with [`params!`](crate::params!) as a struct with
+//! defaults and reads them with `Params::get()` in its parts:
+//!
+//! ```
+//! aoc_core::params! {
+//!     pub struct Params {
+//!         /// The row part one counts
+//!         row: i128 = 2_000_000,
+//!     }
+//! }
+//!
+//! assert_eq!(Params::get().unwrap().row, 2_000_000);
+//! let overrides = [aoc_core::params::parse_override("row=10").unwrap()];
+//! let row = aoc_core::params::with(&overrides, || Params::get().unwrap().row);
+//! assert_eq!(row, 10);
+//! ```
+//!
+//! The defaults are overridden by `name=value` pairs for everything solved
+//! inside [`with`] on the same thread, from `--param` or the `params` of an
+//! input in answers.toml.
+
+use anyhow::{anyhow, bail, Context, Result};
+use std::cell::RefCell;
+
+/// A parameter as `name` and `value`, like `--param name=value`.
+pub type Override = (String, String);
+
+/// A value of a parameter, written the way `--param` takes it.
+pub trait Value: Sized {
+    fn parse(text: &str) -> Result<Self>;
+
+    fn show(&self) -> String;
+}
+
+macro_rules! impl_value_int {
+    ($($t:ty),*) => {
+        $(
+            impl Value for $t {
+                // underscores are allowed like in the code, 1_000_000
+                fn parse(text: &str) -> Result<Self> {
+                    Ok(text.trim().replace('_', "").parse()?)
+                }
+
+                fn show(&self) -> String {
+                    self.to_string()
+                }
+            }
+        )*
+    };
+}
+
+impl_value_int!(i8, i16, i32, i64, i128, u8, u16, u32, u64, u128, usize, isize);
+
+impl Value for bool {
+    fn parse(text: &str) -> Result<Self> {
+        Ok(text.trim().parse()?)
+    }
+
+    fn show(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl Value for String {
+    fn parse(text: &str) -> Result<Self> {
+        Ok(String::from(text))
+    }
+
+    fn show(&self) -> String {
+        self.clone()
+    }
+}
+
+/// A comma separated list.
+impl<T: Value> Value for Vec<T> {
+    fn parse(text: &str) -> Result<Self> {
+        if text.trim().is_empty() {
+            return Ok(Vec::new());
+        }
+        text.split(',').map(T::parse).collect()
+    }
+
+    fn show(&self) -> String {
+        self.iter().map(T::show).collect::<Vec<_>>().join(",")
+    }
+}
+
+/// The parameters of a day, implemented by [`params!`](crate::params!).
+pub trait Params: Default {
+    /// Every parameter with its default value.
+    fn defaults() -> Vec<(&'static str, String)>;
+
+    fn set(&mut self, name: &str, value: &str) -> Result<()>;
+}
+
+thread_local! {
+    static OVERRIDES: RefCell<Vec<Override>> = const { RefCell::new(Vec::new()) };
+}
+
+// puts the overrides of the enclosing with back, even if the part panicked
+struct Restore(Vec<Override>);
+
+impl Drop for Restore {
+    fn drop(&mut self) {
+        OVERRIDES.with(|o| *o.borrow_mut() = std::mem::take(&mut self.0));
+    }
+}
+
+/// Runs `f` with `overrides` on top of the defaults of every [`Params`] it
+/// gets on this thread.
+pub fn with<T>(overrides: &[Override], f: impl FnOnce() -> T) -> T {
+    let outer = OVERRIDES.with(|o| o.replace(overrides.to_vec()));
+    let _restore = Restore(outer);
+    f()
+}
+
+/// The defaults of `P` with the overrides of the enclosing [`with`].
+pub fn current<P: Params>() -> Result<P> {
+    let mut params = P::default();
+    OVERRIDES.with(|o| {
+        for (name, value) in o.borrow().iter() {
+            params
+                .set(name, value)
+                .with_context(|| format!("invalid parameter {}={}", name, value))?;
+        }
+        Ok(params)
+    })
+}
+
+/// Reads `name=value`.
+pub fn parse_override(text: &str) -> Result<Override> {
+    let (name, value) = text
+        .split_once('=')
+        .ok_or_else(|| anyhow!("expected NAME=VALUE instead of {:?}", text))?;
+    Ok((String::from(name.trim()), String::from(value.trim())))
+}
+
+/// Fails on overrides that aren't one of the `defaults`, naming the ones
+/// there are.
+pub fn check(defaults: &[(&str, String)], overrides: &[Override]) -> Result<()> {
+    for (name, _) in overrides {
+        if defaults.iter().any(|(known, _)| known == name) {
+            continue;
+        }
+        if defaults.is_empty() {
+            bail!("there is no parameter {}, there are none", name);
+        }
+        let known: Vec<_> = defaults
+            .iter()
+            .map(|(known, default)| format!("{}={}", known, default))
+            .collect();
+        bail!("there is no parameter {}, only {}", name, known.join(" "));
+    }
+    Ok(())
+}
+
+/// A struct of parameters with a default for every field, public fields
+/// and a `get()` for the values of the enclosing [`with`].
+///
+/// The calling crate needs `anyhow`, like every crate with a
+/// [`Solution`](crate::Solution).
+#[macro_export]
+macro_rules! params {
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident {
+            $($(#[$field_meta:meta])* $field:ident: $ty:ty = $default:expr),* $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, PartialEq)]
+        $vis struct $name {
+            $($(#[$field_meta])* pub $field: $ty,)*
+        }
+
+        impl Default for $name {
+            fn default() -> Self {
+                $name {
+                    $($field: $default,)*
+                }
+            }
+        }
+
+        impl $crate::params::Params for $name {
+            fn defaults() -> Vec<(&'static str, String)> {
+                let defaults = <$name as Default>::default();
+                vec![$((stringify!($field), $crate::params::Value::show(&defaults.$field)),)*]
+            }
+
+            fn set(&mut self, name: &str, value: &str) -> ::anyhow::Result<()> {
+                match name {
+                    $(stringify!($field) => self.$field = $crate::params::Value::parse(value)?,)*
+                    _ => ::anyhow::bail!("there is no parameter {}", name),
+                }
+                Ok(())
+            }
+        }
+
+        impl $name {
+            /// The defaults with the overrides the part is solved with.
+            pub fn get() -> ::anyhow::Result<Self> {
+                $crate::params::current()
+            }
+
+            pub fn defaults() -> Vec<(&'static str, String)> {
+                <$name as $crate::params::Params>::defaults()
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::params! {
+        struct Example {
+            row: i128 = 2_000_000,
+            start: String = String::from("AA"),
+            cycles: Vec<i64> = vec![20, 60],
+        }
+    }
+
+    fn overrides(text: &[&str]) -> Vec<Override> {
+        text.iter().map(|t| parse_override(t).unwrap()).collect()
+    }
+
+    #[test]
+    fn overrides_only_inside_with() {
+        assert_eq!(Example::get().unwrap(), Example::default());
+        let example = with(&overrides(&["row=1_0", "cycles=1,2,3"]), || {
+            // the inner overrides replace the outer ones
+            let start = with(&overrides(&["start=BB"]), || Example::get().unwrap());
+            assert_eq!((start.row, start.start.as_str()), (2_000_000, "BB"));
+            Example::get().unwrap()
+        });
+        assert_eq!(example.row, 10);
+        assert_eq!(example.cycles, [1, 2, 3]);
+        assert_eq!(Example::get().unwrap(), Example::default());
+
+        let err = with(&overrides(&["row=ten"]), Example::get).unwrap_err();
+        assert!(format!("{:#}", err).starts_with("invalid parameter row=ten"));
+        assert!(parse_override("row").is_err());
+    }
+
+    #[test]
+    fn checks_names() {
+        let defaults = Example::defaults();
+        assert_eq!(
+            defaults,
+            [
+                ("row", String::from("2000000")),
+                ("start", String::from("AA")),
+                ("cycles", String::from("20,60")),
+            ]
+        );
+        assert!(check(&defaults, &overrides(&["start=ZZ"])).is_ok());
+        let err = check(&defaults, &overrides(&["y=10"])).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "there is no parameter y, only row=2000000 start=AA cycles=20,60"
+        );
+        assert!(check(&[], &overrides(&["y=10"])).is_err());
+    }
+}
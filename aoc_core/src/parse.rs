@@ -0,0 +1,127 @@
+//! Parse errors that point at the offending spot of the input.
+//!
+//! The parsers only ever look at slices of the original input, so the position
+//! of an error is derived from where the offending slice starts.
+
+use regex::{Captures, Regex};
+use std::str::FromStr;
+use thiserror::Error;
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error("line {line}, column {column}: expected {expected}, found {found:?}")]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub expected: String,
+    pub found: String,
+}
+
+impl ParseError {
+    /// An error at `found`, which has to be a slice of `text`.
+    pub fn at(text: &str, found: &str, expected: impl Into<String>) -> ParseError {
+        let (line, column) = position(text, found);
+        ParseError {
+            line,
+            column,
+            expected: expected.into(),
+            found: String::from(found.lines().next().unwrap_or("")),
+        }
+    }
+
+    /// An error for something missing at the end of `text`.
+    pub fn missing(text: &str, expected: impl Into<String>) -> ParseError {
+        ParseError::at(text, &text[text.len()..], expected)
+    }
+
+    /// Moves an error of a parser that only saw `part`, a slice of `text`, to
+    /// its position in `text`.
+    pub fn within(self, text: &str, part: &str) -> ParseError {
+        let (line, column) = position(text, part);
+        ParseError {
+            line: line + self.line - 1,
+            column: if self.line == 1 {
+                column + self.column - 1
+            } else {
+                self.column
+            },
+            ..self
+        }
+    }
+}
+
+pub trait Within {
+    /// See [`ParseError::within`].
+    fn within(self, text: &str, part: &str) -> Self;
+}
+
+impl<T> Within for Result<T, ParseError> {
+    fn within(self, text: &str, part: &str) -> Self {
+        self.map_err(|e| e.within(text, part))
+    }
+}
+
+/// 1-based line and column of `found` in `text`, the start if it isn't a slice
+/// of `text`.
+fn position(text: &str, found: &str) -> (usize, usize) {
+    let offset = (found.as_ptr() as usize)
+        .checked_sub(text.as_ptr() as usize)
+        .filter(|&offset| offset <= text.len())
+        .unwrap_or(0);
+    let before = text.get(..offset).unwrap_or("");
+    let line = before.matches('\n').count() + 1;
+    let column = before.rsplit('\n').next().unwrap_or("").chars().count() + 1;
+    (line, column)
+}
+
+/// Parses `found`, a slice of `text`.
+pub fn value<T: FromStr>(text: &str, found: &str, expected: &str) -> Result<T, ParseError> {
+    found
+        .parse()
+        .map_err(|_| ParseError::at(text, found, expected))
+}
+
+/// The next item of a split of `text`.
+pub fn next<'t>(
+    items: &mut impl Iterator<Item = &'t str>,
+    text: &str,
+    expected: &str,
+) -> Result<&'t str, ParseError> {
+    items
+        .next()
+        .ok_or_else(|| ParseError::missing(text, expected))
+}
+
+/// The captures of `re` on `line`, a slice of `text`.
+pub fn captures<'t>(re: &Regex, text: &str, line: &'t str) -> Result<Captures<'t>, ParseError> {
+    re.captures(line)
+        .ok_or_else(|| ParseError::at(text, line, format!("a line matching `{}`", re)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn position_of_a_slice() {
+        let text = "1 2\n3 x 5\n";
+        let x = &text[6..7];
+        let err = value::<i32>(text, x, "a number").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 3));
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 3: expected a number, found \"x\""
+        );
+        let missing = ParseError::missing(&text[..3], "a third number");
+        assert_eq!((missing.line, missing.column), (1, 4));
+    }
+
+    #[test]
+    fn within_shifts_to_the_outer_text() {
+        let text = "ok\nstill ok\nbad line";
+        let line = text.lines().nth(2).unwrap();
+        let err = value::<i32>(line, &line[4..], "a number").within(text, line);
+        let err = err.unwrap_err();
+        assert_eq!((err.line, err.column), (3, 5));
+        assert_eq!(err.found, "line");
+    }
+}
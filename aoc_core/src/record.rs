@@ -0,0 +1,265 @@
+//! Recording the frames of a simulation to watch it afterwards.
+//!
+//! A day pushes a frame with [`frame`] wherever it would have printed its
+//! state. Nothing is rendered unless a [`Recorder`] is installed, so the days
+//! can push frames in their hottest loops.
+//!
+//! A recorder writes an [asciicast](https://docs.asciinema.org/manual/asciicast/v2/)
+//! for `asciinema play` if its path ends in `.cast` and a directory of
+//! numbered PPM images otherwise, one pixel block per character.
+
+use std::fs::{self, File};
+use std::io::{self, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+
+/// One picture of a simulation, the text it would have printed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    rows: Vec<String>,
+}
+
+impl Frame {
+    pub fn new(text: &str) -> Self {
+        Frame {
+            rows: text.lines().map(String::from).collect(),
+        }
+    }
+
+    pub fn rows(&self) -> &[String] {
+        &self.rows
+    }
+
+    pub fn width(&self) -> usize {
+        self.rows
+            .iter()
+            .map(|row| row.chars().count())
+            .max()
+            .unwrap_or(0)
+    }
+
+    pub fn height(&self) -> usize {
+        self.rows.len()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Asciicast,
+    Ppm,
+}
+
+/// Collects frames and writes them in one of the [`Format`]s.
+pub struct Recorder {
+    path: PathBuf,
+    format: Format,
+    fps: u32,
+    scale: usize,
+    frames: Vec<Frame>,
+}
+
+impl Recorder {
+    /// Records to `path`, an asciicast if it ends in `.cast` and a directory
+    /// of images otherwise.
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        let path = path.into();
+        let format = match path.extension() {
+            Some(ext) if ext == "cast" => Format::Asciicast,
+            _ => Format::Ppm,
+        };
+        Recorder {
+            path,
+            format,
+            fps: 10,
+            scale: 4,
+            frames: Vec::new(),
+        }
+    }
+
+    /// How many frames are played per second, 10 by default.
+    pub fn fps(mut self, fps: u32) -> Self {
+        self.fps = fps.max(1);
+        self
+    }
+
+    /// The pixels per character in images, 4 by default.
+    pub fn scale(mut self, scale: usize) -> Self {
+        self.scale = scale.max(1);
+        self
+    }
+
+    pub fn format(&self) -> Format {
+        self.format
+    }
+
+    pub fn push(&mut self, frame: Frame) {
+        self.frames.push(frame);
+    }
+
+    pub fn frames(&self) -> &[Frame] {
+        &self.frames
+    }
+
+    /// Writes the recording and returns the number of frames.
+    pub fn finish(self) -> io::Result<usize> {
+        match self.format {
+            Format::Asciicast => {
+                let file = File::create(&self.path)?;
+                write_asciicast(&self.frames, self.fps, BufWriter::new(file))?;
+            }
+            Format::Ppm => {
+                fs::create_dir_all(&self.path)?;
+                for (i, frame) in self.frames.iter().enumerate() {
+                    let file = File::create(frame_path(&self.path, i))?;
+                    write_ppm(frame, self.scale, BufWriter::new(file))?;
+                }
+            }
+        }
+        Ok(self.frames.len())
+    }
+}
+
+fn frame_path(dir: &Path, i: usize) -> PathBuf {
+    dir.join(format!("frame_{:06}.ppm", i))
+}
+
+/// Writes the frames as an asciicast v2, every frame replacing the screen.
+pub fn write_asciicast<W: Write>(frames: &[Frame], fps: u32, mut w: W) -> io::Result<()> {
+    let width = frames.iter().map(Frame::width).max().unwrap_or(0);
+    let height = frames.iter().map(Frame::height).max().unwrap_or(0);
+    writeln!(
+        w,
+        r#"{{"version": 2, "width": {}, "height": {}}}"#,
+        width.max(1),
+        height.max(1)
+    )?;
+    for (i, frame) in frames.iter().enumerate() {
+        let time = i as f64 / fps as f64;
+        // clear the screen and start at the top left
+        let mut screen = String::from("\x1b[2J\x1b[H");
+        screen.push_str(&frame.rows.join("\r\n"));
+        writeln!(w, "[{:.3}, \"o\", {}]", time, json_string(&screen))?;
+    }
+    w.flush()
+}
+
+fn json_string(s: &str) -> String {
+    let mut out = String::from('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+/// Writes a frame as a binary PPM, every character a `scale` by `scale`
+/// block of its [`color`].
+pub fn write_ppm<W: Write>(frame: &Frame, scale: usize, mut w: W) -> io::Result<()> {
+    let (width, height) = (frame.width().max(1), frame.height().max(1));
+    write!(w, "P6\n{} {}\n255\n", width * scale, height * scale)?;
+    for r in 0..height {
+        let mut line: Vec<char> = frame
+            .rows
+            .get(r)
+            .map_or(Vec::new(), |row| row.chars().collect());
+        line.resize(width, ' ');
+        let pixels: Vec<u8> = line.iter().flat_map(|&c| color(c).repeat(scale)).collect();
+        for _ in 0..scale {
+            w.write_all(&pixels)?;
+        }
+    }
+    w.flush()
+}
+
+/// The color of a character in images, empty space is dark, walls and
+/// rocks are light and everything else gets a color of its own.
+pub fn color(c: char) -> [u8; 3] {
+    match c {
+        '.' | ' ' => [16, 16, 24],
+        '#' => [200, 200, 200],
+        '-' | '|' => [110, 110, 110],
+        _ => {
+            // spread the characters over the hues
+            let h = (c as u32).wrapping_mul(2654435761) >> 24;
+            let channel = |shift: u32| (64 + (h.wrapping_mul(shift) % 192)) as u8;
+            [channel(7), channel(13), channel(29)]
+        }
+    }
+}
+
+static RECORDING: AtomicBool = AtomicBool::new(false);
+static RECORDER: Mutex<Option<Recorder>> = Mutex::new(None);
+
+/// Starts recording every frame that is pushed from now on.
+pub fn install(recorder: Recorder) {
+    *RECORDER.lock().unwrap() = Some(recorder);
+    RECORDING.store(true, Ordering::Relaxed);
+}
+
+/// Stops recording and hands back the recorder with its frames.
+pub fn uninstall() -> Option<Recorder> {
+    RECORDING.store(false, Ordering::Relaxed);
+    RECORDER.lock().unwrap().take()
+}
+
+pub fn is_recording() -> bool {
+    RECORDING.load(Ordering::Relaxed)
+}
+
+/// Records the frame `render` draws, `render` is only called while
+/// recording.
+pub fn frame(render: impl FnOnce() -> String) {
+    if !is_recording() {
+        return;
+    }
+    let frame = Frame::new(&render());
+    if let Some(recorder) = RECORDER.lock().unwrap().as_mut() {
+        recorder.push(frame);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn writes_asciicast_and_ppm() {
+        let frames = [Frame::new("#.\n.#\n"), Frame::new("@\"\n")];
+        let mut cast = Vec::new();
+        write_asciicast(&frames, 2, &mut cast).unwrap();
+        let cast = String::from_utf8(cast).unwrap();
+        let lines: Vec<&str> = cast.lines().collect();
+        assert_eq!(lines[0], r#"{"version": 2, "width": 2, "height": 2}"#);
+        assert_eq!(lines[1], r#"[0.000, "o", "\u001b[2J\u001b[H#.\r\n.#"]"#);
+        assert_eq!(lines[2], r#"[0.500, "o", "\u001b[2J\u001b[H@\""]"#);
+
+        let mut ppm = Vec::new();
+        write_ppm(&frames[0], 2, &mut ppm).unwrap();
+        let header = b"P6\n4 4\n255\n";
+        assert_eq!(&ppm[..header.len()], header);
+        let pixels = &ppm[header.len()..];
+        assert_eq!(pixels.len(), 4 * 4 * 3);
+        // the top left block is a wall, the top right one empty
+        assert_eq!(pixels[..6], [200; 6]);
+        assert_eq!(pixels[6..9], color('.'));
+        assert_eq!(pixels[12 * 3..12 * 3 + 3], color('.'));
+    }
+
+    #[test]
+    fn renders_only_while_recording() {
+        let mut rendered = false;
+        frame(|| {
+            rendered = true;
+            String::new()
+        });
+        assert!(!rendered);
+    }
+}
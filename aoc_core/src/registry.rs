@@ -0,0 +1,148 @@
+use crate::{Answer, ParseError, Solution};
+use anyhow::Result;
+use std::any::Any;
+use std::path::{Path, PathBuf};
+
+/// Solves one part of a day given the raw puzzle input.
+pub type Part = fn(&str) -> Result<Answer>;
+
+/// The parsed input of a day, only the day itself knows its type.
+pub type Parsed = Box<dyn Any>;
+
+/// Solves one part of a day given its parsed input.
+pub type ParsedPart = fn(Parsed) -> Result<Answer>;
+
+/// A solved day as seen by the runner.
+#[derive(Clone, Copy)]
+pub struct Day {
+    pub year: u16,
+    pub day: u8,
+    /// Directory of the day, e.g. `.../AoC_2022/Day01_CalorieCounting_Rust`.
+    pub dir: &'static str,
+    parts: u8,
+    part_one: Part,
+    part_two: Part,
+    parse: fn(&str) -> Result<Parsed, ParseError>,
+    parsed_parts: [ParsedPart; 2],
+    worst_cases: fn() -> Vec<(&'static str, String)>,
+    params: fn() -> Vec<(&'static str, String)>,
+}
+
+impl Day {
+    pub fn new<S: Solution>(year: u16, day: u8, dir: &'static str) -> Self
+    where
+        S::Input: 'static,
+    {
+        Day {
+            year,
+            day,
+            dir,
+            parts: S::PARTS,
+            part_one: S::solve_part_one,
+            part_two: S::solve_part_two,
+            parse: |input| Ok(Box::new(S::parse_input(input)?)),
+            parsed_parts: [
+                |parsed| S::part_one(*downcast::<S>(parsed)),
+                |parsed| S::part_two(*downcast::<S>(parsed)),
+            ],
+            worst_cases: S::worst_cases,
+            params: S::params,
+        }
+    }
+
+    /// The puzzle title taken from the `Day<dd>_<Title>_Rust` directory name.
+    pub fn title(&self) -> &'static str {
+        let name = self.dir.rsplit(['/', '\\']).next().unwrap_or(self.dir);
+        let name = name.strip_suffix("_Rust").unwrap_or(name);
+        name.split_once('_').map_or(name, |(_, title)| title)
+    }
+
+    pub fn part(&self, part: u8) -> Option<Part> {
+        match part {
+            1 => Some(self.part_one),
+            2 if self.parts == 2 => Some(self.part_two),
+            _ => None,
+        }
+    }
+
+    pub fn parts(&self) -> u8 {
+        self.parts
+    }
+
+    /// Only parses the input, to time parsing and solving apart.
+    pub fn parse(&self, input: &str) -> Result<Parsed, ParseError> {
+        (self.parse)(input)
+    }
+
+    /// Solves a part on an input from [`Day::parse`] of the same day.
+    pub fn parsed_part(&self, part: u8) -> Option<ParsedPart> {
+        self.part(part)
+            .map(|_| self.parsed_parts[usize::from(part) - 1])
+    }
+
+    pub fn worst_cases(&self) -> Vec<(&'static str, String)> {
+        (self.worst_cases)()
+    }
+
+    /// The parameters of the day and their defaults.
+    pub fn params(&self) -> Vec<(&'static str, String)> {
+        (self.params)()
+    }
+
+    pub fn input_path(&self) -> PathBuf {
+        Path::new(self.dir).join("input.txt")
+    }
+}
+
+fn downcast<S: Solution>(parsed: Parsed) -> Box<S::Input>
+where
+    S::Input: 'static,
+{
+    parsed
+        .downcast()
+        .unwrap_or_else(|_| panic!("the input was parsed by another day"))
+}
+
+/// Registers a day of the calling year crate, its directory is given
+/// relative to the crate.
+#[macro_export]
+macro_rules! day {
+    ($year:expr, $day:expr, $dir:literal, $solution:ty) => {
+        $crate::Day::new::<$solution>($year, $day, concat!(env!("CARGO_MANIFEST_DIR"), "/", $dir))
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ParseError;
+
+    struct LastDay;
+
+    impl Solution for LastDay {
+        type Input = ();
+        const PARTS: u8 = 1;
+
+        fn parse_input(_input: &str) -> Result<(), ParseError> {
+            Ok(())
+        }
+
+        fn part_one(_input: ()) -> Result<Answer> {
+            Ok(Answer::from(0))
+        }
+    }
+
+    #[test]
+    fn title_from_dir() {
+        let day = crate::day!(2022, 25, "Day25_FullOfHotAir_Rust", LastDay);
+        assert_eq!(day.title(), "FullOfHotAir");
+        assert_eq!(day.parts(), 1);
+        assert!(day.part(2).is_none());
+        assert!(day.parsed_part(2).is_none());
+        let parsed = day.parse("").unwrap();
+        assert_eq!(day.parsed_part(1).unwrap()(parsed).unwrap(), 0);
+        assert!(day
+            .input_path()
+            .ends_with("Day25_FullOfHotAir_Rust/input.txt"));
+    }
+}
@@ -0,0 +1,374 @@
+//! Downloading puzzle inputs from adventofcode.com, cached on disk.
+//!
+//! The inputs are personal, so they are fetched with the `session` cookie of
+//! a logged in browser and kept in the user's cache directory instead of the
+//! repository. A checked out `input.txt` in the day's directory still comes
+//! first.
+//!
+//! Everything is configured by environment variables:
+//!
+//! - `AOC_SESSION`: the session cookie, read from `aoc/session` in the
+//!   config directory if unset
+//! - `AOC_CACHE_DIR`: where inputs are cached, `aoc` in the cache directory
+//!   by default
+//! - `AOC_BASE_URL`: the server, `https://adventofcode.com` by default
+
+use crate::Day;
+use anyhow::{anyhow, bail, Context, Result};
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::env;
+use std::fs;
+use std::io::{self, ErrorKind};
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+pub const BASE_URL: &str = "https://adventofcode.com";
+
+const USER_AGENT: &str = "github.com/jgpr-code/advent-of-code aoc runner";
+
+pub struct Client {
+    base_url: String,
+    session: Option<String>,
+    cache_dir: PathBuf,
+    min_interval: Duration,
+    agent: ureq::Agent,
+}
+
+fn home_dir() -> Option<PathBuf> {
+    env::var_os("HOME")
+        .or_else(|| env::var_os("USERPROFILE"))
+        .map(PathBuf::from)
+}
+
+fn user_dir(xdg: &str, fallback: &str) -> PathBuf {
+    env::var_os(xdg)
+        .map(PathBuf::from)
+        .or_else(|| home_dir().map(|home| home.join(fallback)))
+        .unwrap_or_else(env::temp_dir)
+        .join("aoc")
+}
+
+fn read_session() -> Option<String> {
+    if let Ok(session) = env::var("AOC_SESSION") {
+        return Some(session);
+    }
+    let path = user_dir("XDG_CONFIG_HOME", ".config").join("session");
+    fs::read_to_string(path).ok()
+}
+
+impl Client {
+    /// A client for `base_url` that caches in `cache_dir`, without a session.
+    pub fn new(base_url: &str, cache_dir: impl Into<PathBuf>) -> Self {
+        Client {
+            base_url: String::from(base_url.trim_end_matches('/')),
+            session: None,
+            cache_dir: cache_dir.into(),
+            min_interval: Duration::from_secs(5),
+            agent: ureq::AgentBuilder::new()
+                .user_agent(USER_AGENT)
+                .timeout(Duration::from_secs(30))
+                .build(),
+        }
+    }
+
+    /// The client the environment asks for, see the module documentation.
+    pub fn from_env() -> Self {
+        let base_url = env::var("AOC_BASE_URL").unwrap_or_else(|_| String::from(BASE_URL));
+        let cache_dir = env::var_os("AOC_CACHE_DIR")
+            .map(PathBuf::from)
+            .unwrap_or_else(|| user_dir("XDG_CACHE_HOME", ".cache"));
+        let client = Client::new(&base_url, cache_dir);
+        match read_session() {
+            Some(session) => client.session(&session),
+            None => client,
+        }
+    }
+
+    pub fn session(mut self, session: &str) -> Self {
+        self.session = Some(String::from(session.trim()));
+        self
+    }
+
+    /// The least time between two requests, also across processes sharing
+    /// the cache. 5 seconds by default.
+    pub fn min_interval(mut self, min_interval: Duration) -> Self {
+        self.min_interval = min_interval;
+        self
+    }
+
+    pub fn cache_dir(&self) -> &Path {
+        &self.cache_dir
+    }
+
+    pub fn cache_path(&self, year: u16, day: u8) -> PathBuf {
+        self.cache_dir
+            .join(year.to_string())
+            .join(format!("day{:02}.txt", day))
+    }
+
+    /// The cached input of a day, `None` if it was never downloaded.
+    pub fn cached_input(&self, year: u16, day: u8) -> Option<String> {
+        fs::read_to_string(self.cache_path(year, day)).ok()
+    }
+
+    /// The input of a day from the cache or downloaded into it.
+    pub fn input(&self, year: u16, day: u8) -> Result<String> {
+        if let Some(input) = self.cached_input(year, day) {
+            return Ok(input);
+        }
+        let input = self.request(&format!("/{}/day/{}/input", year, day), None)?;
+        let path = self.cache_path(year, day);
+        fs::create_dir_all(path.parent().unwrap())?;
+        fs::write(&path, &input).with_context(|| format!("error writing {}", path.display()))?;
+        Ok(input)
+    }
+
+    /// The title of a day from the `--- Day N: Title ---` heading of its
+    /// puzzle page.
+    pub fn title(&self, year: u16, day: u8) -> Result<String> {
+        let page = self.request(&format!("/{}/day/{}", year, day), None)?;
+        parse_title(&page, day)
+            .ok_or_else(|| anyhow!("the page of {} day {} has no title", year, day))
+    }
+
+    /// The checked out input.txt of `day` or else its cached input, without
+    /// downloading anything.
+    pub fn local_input(&self, day: &Day) -> io::Result<String> {
+        match fs::read_to_string(day.input_path()) {
+            Err(err) if err.kind() == ErrorKind::NotFound => self
+                .cached_input(day.year, day.day)
+                .ok_or_else(|| io::Error::new(ErrorKind::NotFound, "input.txt is missing")),
+            read => read,
+        }
+    }
+
+    /// The input of `day` from [`local_input`](Self::local_input) or
+    /// downloaded.
+    pub fn day_input(&self, day: &Day) -> Result<String> {
+        match self.local_input(day) {
+            Err(err) if err.kind() == ErrorKind::NotFound => self.input(day.year, day.day),
+            read => read.with_context(|| format!("error reading {}", day.input_path().display())),
+        }
+    }
+
+    /// GETs `path` or POSTs `form` to it with the session cookie.
+    pub(crate) fn request(&self, path: &str, form: Option<&[(&str, &str)]>) -> Result<String> {
+        let Some(session) = &self.session else {
+            bail!("set AOC_SESSION to the session cookie of adventofcode.com");
+        };
+        self.throttle()?;
+        let url = format!("{}{}", self.base_url, path);
+        let cookie = format!("session={}", session);
+        let response = match form {
+            Some(form) => self.agent.post(&url).set("Cookie", &cookie).send_form(form),
+            None => self.agent.get(&url).set("Cookie", &cookie).call(),
+        };
+        match response {
+            Ok(response) => Ok(response.into_string()?),
+            Err(ureq::Error::Status(status, response)) => {
+                let body = response.into_string().unwrap_or_default();
+                Err(anyhow!(
+                    "{} answered {}: {}",
+                    url,
+                    status,
+                    body.lines().next().unwrap_or("")
+                ))
+            }
+            Err(err) => Err(err).with_context(|| format!("error requesting {}", url)),
+        }
+    }
+
+    // waits until min_interval passed since the last request of any client
+    // sharing the cache
+    fn throttle(&self) -> Result<()> {
+        let stamp = self.cache_dir.join("last_request");
+        let now = || {
+            SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+        };
+        let last = fs::read_to_string(&stamp)
+            .ok()
+            .and_then(|millis| millis.trim().parse().ok())
+            .map(Duration::from_millis);
+        if let Some(wait) = last.and_then(|last| (last + self.min_interval).checked_sub(now())) {
+            thread::sleep(wait);
+        }
+        fs::create_dir_all(&self.cache_dir)?;
+        fs::write(&stamp, now().as_millis().to_string())?;
+        Ok(())
+    }
+}
+
+/// The title in the `--- Day N: Title ---` heading of the puzzle page of
+/// `day`, with the HTML entities of the page resolved.
+pub fn parse_title(page: &str, day: u8) -> Option<String> {
+    lazy_static! {
+        static ref HEADING: Regex = Regex::new(r"---\s*Day (\d+):\s*(.*?)\s*---").unwrap();
+    }
+    let title = HEADING
+        .captures_iter(page)
+        .find(|heading| heading[1].parse() == Ok(day))?[2]
+        .replace("&apos;", "'")
+        .replace("&#39;", "'")
+        .replace("&quot;", "\"")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&amp;", "&");
+    Some(title)
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::sync::mpsc;
+    use std::time::Instant;
+
+    /// A request the stub server got, the request line, the headers and the
+    /// body.
+    pub struct Request {
+        pub line: String,
+        pub headers: Vec<String>,
+        pub body: String,
+    }
+
+    impl Request {
+        pub fn header(&self, name: &str) -> Option<&str> {
+            self.headers.iter().find_map(|header| {
+                let (key, value) = header.split_once(':')?;
+                key.eq_ignore_ascii_case(name).then_some(value.trim())
+            })
+        }
+    }
+
+    /// Serves `responses` one request each as status and body, returns the
+    /// base url and the requests it got.
+    pub fn stub_server(responses: Vec<(u16, String)>) -> (String, mpsc::Receiver<Request>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let (sender, requests) = mpsc::channel();
+        thread::spawn(move || {
+            for (status, body) in responses {
+                let (stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream);
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                let mut headers = Vec::new();
+                loop {
+                    let mut header = String::new();
+                    reader.read_line(&mut header).unwrap();
+                    if header.trim().is_empty() {
+                        break;
+                    }
+                    headers.push(String::from(header.trim()));
+                }
+                let mut request = Request {
+                    line: String::from(line.trim()),
+                    headers,
+                    body: String::new(),
+                };
+                let length = request
+                    .header("Content-Length")
+                    .and_then(|length| length.parse().ok())
+                    .unwrap_or(0);
+                let mut content = vec![0; length];
+                io::Read::read_exact(&mut reader, &mut content).unwrap();
+                request.body = String::from_utf8(content).unwrap();
+                let mut stream = reader.into_inner();
+                write!(
+                    stream,
+                    "HTTP/1.1 {} Stub\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                )
+                .unwrap();
+                sender.send(request).unwrap();
+            }
+        });
+        (url, requests)
+    }
+
+    pub fn cache_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc_{}_{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn downloads_inputs_once() {
+        let (url, requests) = stub_server(vec![(200, String::from("1\n2\n"))]);
+        let dir = cache_dir("download");
+        let client = Client::new(&url, &dir)
+            .session("abc")
+            .min_interval(Duration::ZERO);
+
+        assert_eq!(client.input(2022, 1).unwrap(), "1\n2\n");
+        let request = requests.recv().unwrap();
+        assert_eq!(request.line, "GET /2022/day/1/input HTTP/1.1");
+        assert_eq!(request.header("Cookie"), Some("session=abc"));
+        // the second time comes from the cache, the server would hang
+        assert_eq!(client.input(2022, 1).unwrap(), "1\n2\n");
+        assert_eq!(
+            client.cache_path(2022, 1),
+            dir.join("2022").join("day01.txt")
+        );
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn fetches_titles() {
+        let page = "<main>\n<article class=\"day-desc\"><h2>--- Day 14: Extended Polymerization ---</h2>\n<p>The incredible pressures</p></article>";
+        let (url, requests) = stub_server(vec![(200, String::from(page))]);
+        let dir = cache_dir("title");
+        let client = Client::new(&url, &dir)
+            .session("abc")
+            .min_interval(Duration::ZERO);
+
+        assert_eq!(client.title(2021, 14).unwrap(), "Extended Polymerization");
+        assert_eq!(requests.recv().unwrap().line, "GET /2021/day/14 HTTP/1.1");
+        assert_eq!(
+            parse_title(
+                "<h2>--- Day 2: I Was Told There Would Be No Math ---</h2>",
+                2
+            )
+            .as_deref(),
+            Some("I Was Told There Would Be No Math")
+        );
+        assert_eq!(
+            parse_title(
+                "<h2>--- Day 7: Some Assembly &amp; Santa&apos;s Sleigh ---</h2>",
+                7
+            )
+            .as_deref(),
+            Some("Some Assembly & Santa's Sleigh")
+        );
+        assert_eq!(parse_title("<h2>--- Day 7: Wrong Day ---</h2>", 8), None);
+        assert_eq!(parse_title("<p>Please log in</p>", 8), None);
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn reports_errors_and_throttles() {
+        let not_yet = "Please don't repeatedly request this endpoint before it unlocks!";
+        let (url, requests) =
+            stub_server(vec![(404, String::from(not_yet)), (200, String::from("3"))]);
+        let dir = cache_dir("throttle");
+        let client = Client::new(&url, &dir).min_interval(Duration::from_millis(300));
+        assert!(client.input(2022, 25).is_err());
+
+        let client = client.session("abc");
+        let t = Instant::now();
+        let err = client.input(2022, 25).unwrap_err();
+        assert!(err.to_string().contains(not_yet));
+        assert!(client.cached_input(2022, 25).is_none());
+        assert_eq!(client.input(2022, 24).unwrap(), "3");
+        assert!(t.elapsed() >= Duration::from_millis(300));
+        assert_eq!(requests.iter().count(), 2);
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,231 @@
+//! Shortest paths through graphs that are only given by a neighbour function.
+//!
+//! All searches accept several starts at once and stop at the first node for
+//! which `is_goal` holds.
+
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<N, C> {
+    pub cost: C,
+    /// From the start to the goal, both included.
+    pub nodes: Vec<N>,
+}
+
+// every node reached so far with the node it was reached from and its cost
+struct Visited<N, C> {
+    nodes: Vec<(N, Option<usize>, C)>,
+    index: HashMap<N, usize>,
+}
+
+impl<N: Clone + Eq + Hash, C: Copy> Visited<N, C> {
+    fn new() -> Self {
+        Visited {
+            nodes: Vec::new(),
+            index: HashMap::new(),
+        }
+    }
+
+    fn insert(&mut self, node: N, parent: Option<usize>, cost: C) -> usize {
+        let i = self.nodes.len();
+        self.index.insert(node.clone(), i);
+        self.nodes.push((node, parent, cost));
+        i
+    }
+
+    fn path(&self, goal: usize) -> Path<N, C> {
+        let mut nodes = Vec::new();
+        let mut current = Some(goal);
+        while let Some(i) = current {
+            nodes.push(self.nodes[i].0.clone());
+            current = self.nodes[i].1;
+        }
+        nodes.reverse();
+        Path {
+            cost: self.nodes[goal].2,
+            nodes,
+        }
+    }
+}
+
+/// Breadth-first search where every step costs one.
+pub fn bfs<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbors: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, usize>>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut visited = Visited::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if !visited.index.contains_key(&start) {
+            queue.push_back(visited.insert(start, None, 0));
+        }
+    }
+    while let Some(i) = queue.pop_front() {
+        let (node, _, steps) = visited.nodes[i].clone();
+        if is_goal(&node) {
+            return Some(visited.path(i));
+        }
+        for next in neighbors(&node) {
+            if !visited.index.contains_key(&next) {
+                queue.push_back(visited.insert(next, Some(i), steps + 1));
+            }
+        }
+    }
+    None
+}
+
+/// The number of steps to every node that can be reached from the starts.
+pub fn bfs_distances<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbors: impl FnMut(&N) -> I,
+) -> HashMap<N, usize>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut distances = HashMap::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if !distances.contains_key(&start) {
+            distances.insert(start.clone(), 0);
+            queue.push_back((start, 0));
+        }
+    }
+    while let Some((node, steps)) = queue.pop_front() {
+        for next in neighbors(&node) {
+            if !distances.contains_key(&next) {
+                distances.insert(next.clone(), steps + 1);
+                queue.push_back((next, steps + 1));
+            }
+        }
+    }
+    distances
+}
+
+/// Cheapest path where `neighbors` yields every next node with the cost of
+/// the step there.
+pub fn dijkstra<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    neighbors: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, C>>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    astar(starts, neighbors, |_| C::default(), is_goal)
+}
+
+/// Like [`dijkstra`], but nodes that `heuristic` estimates closer to a goal
+/// are tried first. The estimate must never be more than the real cost.
+pub fn astar<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbors: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, C>>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut visited = Visited::new();
+    // estimated total cost, cost so far, node
+    let mut queue = BinaryHeap::new();
+    for start in starts {
+        if !visited.index.contains_key(&start) {
+            let estimate = heuristic(&start);
+            let i = visited.insert(start, None, C::default());
+            queue.push(Reverse((estimate, C::default(), i)));
+        }
+    }
+    while let Some(Reverse((_, cost, i))) = queue.pop() {
+        if cost > visited.nodes[i].2 {
+            continue; // reached cheaper in the meantime
+        }
+        let node = visited.nodes[i].0.clone();
+        if is_goal(&node) {
+            return Some(visited.path(i));
+        }
+        for (next, step) in neighbors(&node) {
+            let next_cost = cost + step;
+            let j = match visited.index.get(&next) {
+                Some(&j) if visited.nodes[j].2 <= next_cost => continue,
+                Some(&j) => {
+                    visited.nodes[j].1 = Some(i);
+                    visited.nodes[j].2 = next_cost;
+                    j
+                }
+                None => visited.insert(next.clone(), Some(i), next_cost),
+            };
+            queue.push(Reverse((next_cost + heuristic(&next), next_cost, j)));
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Grid;
+
+    const MAZE: &str = "\
+..#....
+.##.##.
+...#...
+.#...#.";
+
+    fn maze() -> Grid<bool> {
+        Grid::parse(MAZE, "a wall or open space", |c| match c {
+            '#' => Some(false),
+            '.' => Some(true),
+            _ => None,
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn bfs_finds_a_shortest_path() {
+        let maze = maze();
+        let neighbors = |&pos: &(usize, usize)| maze.neighbors4(pos).filter(|&n| maze[n]);
+        let path = bfs([(0, 0)], neighbors, |&pos| pos == (0, 6)).unwrap();
+        assert_eq!(path.cost, 12);
+        assert_eq!(path.nodes.len(), 13);
+        assert_eq!(path.nodes[..3], [(0, 0), (1, 0), (2, 0)]);
+        assert!(path
+            .nodes
+            .windows(2)
+            .all(|w| w[0].0.abs_diff(w[1].0) + w[0].1.abs_diff(w[1].1) == 1));
+        // the second start is next to the goal
+        let path = bfs([(0, 0), (0, 3)], neighbors, |&pos| pos == (0, 6)).unwrap();
+        assert_eq!(path.nodes, [(0, 3), (0, 4), (0, 5), (0, 6)]);
+        assert!(bfs([(0, 0)], neighbors, |&pos| pos == (0, 2)).is_none());
+
+        let distances = bfs_distances([(0, 0)], neighbors);
+        assert_eq!(distances[&(3, 6)], 11);
+        assert_eq!(distances.len(), maze.iter().filter(|&&open| open).count());
+    }
+
+    #[test]
+    fn dijkstra_and_astar_agree() {
+        let risk = Grid::parse("1163\n1381\n2136", "a digit", |c| c.to_digit(10)).unwrap();
+        let goal = (2, 3);
+        let neighbors = |&pos: &(usize, usize)| risk.neighbors4(pos).map(|n| (n, risk[n]));
+        let cheapest = dijkstra([(0, 0)], neighbors, |&pos| pos == goal).unwrap();
+        assert_eq!(cheapest.cost, 13);
+        let cost: u32 = cheapest.nodes[1..].iter().map(|&pos| risk[pos]).sum();
+        assert_eq!(cost, cheapest.cost);
+        let manhattan = |&(r, c): &(usize, usize)| (goal.0.abs_diff(r) + goal.1.abs_diff(c)) as u32;
+        let estimated = astar([(0, 0)], neighbors, manhattan, |&pos| pos == goal).unwrap();
+        assert_eq!(estimated.cost, cheapest.cost);
+    }
+}
@@ -0,0 +1,126 @@
+use crate::ParseError;
+use anyhow::{bail, Context, Result};
+use std::fmt;
+
+/// The answer to one part of a puzzle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Int(i128),
+    Str(String),
+    /// Pixel output like a CRT screen, one `String` per row.
+    Grid(Vec<String>),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Int(i) => write!(f, "{}", i),
+            Answer::Str(s) => write!(f, "{}", s),
+            Answer::Grid(rows) => write!(f, "{}", rows.join("\n")),
+        }
+    }
+}
+
+macro_rules! impl_from_int {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    Answer::Int(value as i128)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_int!(i8, i16, i32, i64, i128, u8, u16, u32, u64, usize, isize);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Str(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Str(String::from(value))
+    }
+}
+
+impl From<Vec<String>> for Answer {
+    fn from(rows: Vec<String>) -> Self {
+        Answer::Grid(rows)
+    }
+}
+
+impl PartialEq<i128> for Answer {
+    fn eq(&self, other: &i128) -> bool {
+        matches!(self, Answer::Int(i) if i == other)
+    }
+}
+
+impl PartialEq<&str> for Answer {
+    fn eq(&self, other: &&str) -> bool {
+        matches!(self, Answer::Str(s) if s == other)
+    }
+}
+
+/// A day of Advent of Code.
+///
+/// The input is parsed once per part, so the parts are free to consume or
+/// mutate it.
+pub trait Solution {
+    type Input;
+
+    /// The number of parts, only the last day of a year has just one.
+    const PARTS: u8 = 2;
+
+    fn parse_input(input: &str) -> Result<Self::Input, ParseError>;
+
+    fn part_one(input: Self::Input) -> Result<Answer>;
+
+    fn part_two(_input: Self::Input) -> Result<Answer> {
+        bail!("there is no part two")
+    }
+
+    /// Synthetic inputs by name that push the solution to its limits, they
+    /// are benchmarked next to the puzzle input.
+    fn worst_cases() -> Vec<(&'static str, String)> {
+        Vec::new()
+    }
+
+    /// The parameters of [`params!`](crate::params!) with their defaults,
+    /// for days with numbers that aren't in the input.
+    fn params() -> Vec<(&'static str, String)> {
+        Vec::new()
+    }
+
+    fn solve_part_one(input: &str) -> Result<Answer> {
+        Self::part_one(Self::parse_input(input).context("invalid puzzle input")?)
+    }
+
+    fn solve_part_two(input: &str) -> Result<Answer> {
+        Self::part_two(Self::parse_input(input).context("invalid puzzle input")?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn answer_display() {
+        assert_eq!(Answer::from(42u8).to_string(), "42");
+        assert_eq!(Answer::from(-7i64).to_string(), "-7");
+        assert_eq!(Answer::from("CMZ").to_string(), "CMZ");
+        let grid = Answer::from(vec![String::from("#.#"), String::from(".#.")]);
+        assert_eq!(grid.to_string(), "#.#\n.#.");
+    }
+
+    #[test]
+    fn answer_compares_to_literals() {
+        assert_eq!(Answer::from(24000usize), 24000);
+        assert_eq!(Answer::from("CMZ"), "CMZ");
+        assert_ne!(Answer::from("24000"), 24000);
+    }
+}
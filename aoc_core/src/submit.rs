@@ -0,0 +1,392 @@
+//! Submitting answers to adventofcode.com and remembering the verdicts.
+//!
+//! Every attempt is kept next to the cached inputs, so an answer that was
+//! already wrong, or is beyond an answer that was too high or too low, is
+//! refused before it costs another lockout. So is any answer while the
+//! lockout after a wrong one lasts.
+
+use crate::remote::Client;
+use anyhow::{bail, Context, Result};
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::fmt;
+use std::fs::{self, OpenOptions};
+use std::io::{ErrorKind, Write};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// What the server made of an answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong without a hint.
+    Wrong,
+    /// Submitted too soon after the last attempt, nothing was checked.
+    Wait(Duration),
+    /// The part is already solved or not unlocked yet.
+    WrongLevel,
+    /// A page that isn't understood, its text.
+    Unknown(String),
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::TooHigh => write!(f, "too high"),
+            Verdict::TooLow => write!(f, "too low"),
+            Verdict::Wrong => write!(f, "wrong"),
+            Verdict::Wait(wait) => write!(f, "wait {}s", wait.as_secs()),
+            Verdict::WrongLevel => write!(f, "already solved or not unlocked"),
+            Verdict::Unknown(text) => write!(f, "unknown: {}", text),
+        }
+    }
+}
+
+/// The verdict of the page the server answers a submission with.
+pub fn parse_verdict(page: &str) -> Verdict {
+    lazy_static! {
+        static ref ARTICLE: Regex = Regex::new(r"(?s)<article>(.*?)</article>").unwrap();
+        static ref TAG: Regex = Regex::new(r"<[^>]*>").unwrap();
+        static ref LEFT: Regex =
+            Regex::new(r"(?:(\d+)h )?(?:(\d+)m )?(\d+)s left to wait").unwrap();
+    }
+    let article = ARTICLE
+        .captures(page)
+        .map_or(page, |caps| caps.get(1).unwrap().as_str());
+    let text = TAG.replace_all(article, "");
+    let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    if text.contains("That's the right answer") {
+        Verdict::Correct
+    } else if text.contains("not the right answer") {
+        if text.contains("too high") {
+            Verdict::TooHigh
+        } else if text.contains("too low") {
+            Verdict::TooLow
+        } else {
+            Verdict::Wrong
+        }
+    } else if let Some(caps) = LEFT.captures(&text) {
+        let unit = |i: usize| caps.get(i).map_or(0, |n| n.as_str().parse().unwrap_or(0));
+        Verdict::Wait(Duration::from_secs(unit(1) * 3600 + unit(2) * 60 + unit(3)))
+    } else if text.contains("right level") {
+        Verdict::WrongLevel
+    } else {
+        Verdict::Unknown(text)
+    }
+}
+
+/// How long a page with a wrong answer asks to wait before the next one.
+pub fn parse_lockout(page: &str) -> Option<Duration> {
+    lazy_static! {
+        static ref PLEASE_WAIT: Regex =
+            Regex::new(r"(?i)please wait (one|\d+) (second|minute|hour)s? before trying again")
+                .unwrap();
+    }
+    let caps = PLEASE_WAIT.captures(page)?;
+    let count = if caps[1].eq_ignore_ascii_case("one") {
+        1
+    } else {
+        caps[1].parse().ok()?
+    };
+    let unit = match caps[2].to_lowercase().as_str() {
+        "second" => 1,
+        "minute" => 60,
+        _ => 3600,
+    };
+    Some(Duration::from_secs(count * unit))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Attempt {
+    part: u8,
+    verdict: Verdict,
+    answer: String,
+}
+
+/// The attempts for one day, a line per attempt in the form
+/// `<part>\t<verdict>\t<answer>`, waits are kept as the unix time they end.
+#[derive(Debug)]
+pub struct History {
+    path: PathBuf,
+    attempts: Vec<Attempt>,
+    wait_until: u64,
+}
+
+fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
+
+impl History {
+    pub fn load(path: impl Into<PathBuf>) -> Result<Self> {
+        let path = path.into();
+        let text = match fs::read_to_string(&path) {
+            Ok(text) => text,
+            Err(err) if err.kind() == ErrorKind::NotFound => String::new(),
+            Err(err) => {
+                return Err(err).with_context(|| format!("error reading {}", path.display()))
+            }
+        };
+        let mut history = History {
+            path,
+            attempts: Vec::new(),
+            wait_until: 0,
+        };
+        for line in text.lines() {
+            let mut fields = line.splitn(3, '\t');
+            let (Some(part), Some(verdict), Some(answer)) =
+                (fields.next(), fields.next(), fields.next())
+            else {
+                continue;
+            };
+            let verdict = match verdict {
+                "correct" => Verdict::Correct,
+                "too_high" => Verdict::TooHigh,
+                "too_low" => Verdict::TooLow,
+                "wrong" => Verdict::Wrong,
+                "wait_until" => {
+                    history.wait_until = history.wait_until.max(answer.parse().unwrap_or(0));
+                    continue;
+                }
+                _ => continue,
+            };
+            history.attempts.push(Attempt {
+                part: part.parse().unwrap_or(0),
+                verdict,
+                answer: String::from(answer),
+            });
+        }
+        Ok(history)
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// The correct answer of `part` if it was submitted.
+    pub fn solution(&self, part: u8) -> Option<&str> {
+        self.attempts
+            .iter()
+            .find(|a| a.part == part && a.verdict == Verdict::Correct)
+            .map(|a| a.answer.as_str())
+    }
+
+    /// Why `answer` shouldn't be submitted for `part` at unix time `now`,
+    /// `None` if nothing speaks against it.
+    pub fn objection(&self, part: u8, answer: &str, now: u64) -> Option<String> {
+        if let Some(solution) = self.solution(part) {
+            return Some(format!("part {} is already solved with {}", part, solution));
+        }
+        if let Some(known) = self
+            .attempts
+            .iter()
+            .find(|a| a.part == part && a.answer == answer)
+        {
+            return Some(format!(
+                "{} was already submitted, it's {}",
+                answer, known.verdict
+            ));
+        }
+        if let Ok(value) = answer.parse::<i128>() {
+            for attempt in self.attempts.iter().filter(|a| a.part == part) {
+                let Ok(bound) = attempt.answer.parse::<i128>() else {
+                    continue;
+                };
+                match attempt.verdict {
+                    Verdict::TooHigh if value >= bound => {
+                        return Some(format!("{} was too high already", bound))
+                    }
+                    Verdict::TooLow if value <= bound => {
+                        return Some(format!("{} was too low already", bound))
+                    }
+                    _ => (),
+                }
+            }
+        }
+        if now < self.wait_until {
+            return Some(format!(
+                "wait {}s before submitting again",
+                self.wait_until - now
+            ));
+        }
+        None
+    }
+
+    /// Remembers the verdict on `answer`, a [`Verdict::Wait`] as the time
+    /// it ends.
+    pub fn record(&mut self, part: u8, answer: &str, verdict: &Verdict, now: u64) -> Result<()> {
+        let line = match verdict {
+            Verdict::Correct => format!("{}\tcorrect\t{}", part, answer),
+            Verdict::TooHigh => format!("{}\ttoo_high\t{}", part, answer),
+            Verdict::TooLow => format!("{}\ttoo_low\t{}", part, answer),
+            Verdict::Wrong => format!("{}\twrong\t{}", part, answer),
+            Verdict::Wait(wait) => {
+                self.wait_until = now + wait.as_secs();
+                format!("{}\twait_until\t{}", part, self.wait_until)
+            }
+            Verdict::WrongLevel | Verdict::Unknown(_) => return Ok(()),
+        };
+        if !matches!(verdict, Verdict::Wait(_)) {
+            self.attempts.push(Attempt {
+                part,
+                verdict: verdict.clone(),
+                answer: String::from(answer),
+            });
+        }
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        writeln!(file, "{}", line)?;
+        Ok(())
+    }
+}
+
+impl Client {
+    pub fn history_path(&self, year: u16, day: u8) -> PathBuf {
+        self.cache_dir()
+            .join(year.to_string())
+            .join(format!("day{:02}_answers.tsv", day))
+    }
+
+    /// Submits `answer` for `part` of a day unless the history already
+    /// tells it's wrong, and records the verdict.
+    pub fn submit(&self, year: u16, day: u8, part: u8, answer: &str) -> Result<Verdict> {
+        let mut history = History::load(self.history_path(year, day))?;
+        if let Some(objection) = history.objection(part, answer, unix_now()) {
+            bail!("not submitting {}: {}", answer, objection);
+        }
+        let level = part.to_string();
+        let page = self.request(
+            &format!("/{}/day/{}/answer", year, day),
+            Some(&[("level", level.as_str()), ("answer", answer)]),
+        )?;
+        let verdict = parse_verdict(&page);
+        history.record(part, answer, &verdict, unix_now())?;
+        // a wrong answer also locks out the next one for a while
+        if matches!(verdict, Verdict::TooHigh | Verdict::TooLow | Verdict::Wrong) {
+            if let Some(lockout) = parse_lockout(&page) {
+                history.record(part, answer, &Verdict::Wait(lockout), unix_now())?;
+            }
+        }
+        Ok(verdict)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::remote::tests::{cache_dir, stub_server};
+
+    fn page(text: &str) -> String {
+        format!(
+            "<html><main>\n<article><p>{} [<a href=\"/2022/day/19\">Return to Day 19</a>]</p></article>\n</main></html>",
+            text
+        )
+    }
+
+    #[test]
+    fn reads_verdicts() {
+        let right = "That's the right answer! You are <span class=\"day-success\">one gold star</span> closer.";
+        assert_eq!(parse_verdict(&page(right)), Verdict::Correct);
+        let high = "That's not the right answer; your answer is too high. Please wait one minute before trying again.";
+        assert_eq!(parse_verdict(&page(high)), Verdict::TooHigh);
+        let low = "That's not the right answer; your answer is too low.";
+        assert_eq!(parse_verdict(&page(low)), Verdict::TooLow);
+        let wrong = "That's not the right answer. If you're stuck, make sure you're using the full input data.";
+        assert_eq!(parse_verdict(&page(wrong)), Verdict::Wrong);
+        let wait = "You gave an answer too recently; you have to wait after submitting an answer before trying again. You have 1m 12s left to wait.";
+        assert_eq!(
+            parse_verdict(&page(wait)),
+            Verdict::Wait(Duration::from_secs(72))
+        );
+        assert_eq!(parse_lockout(&page(high)), Some(Duration::from_secs(60)));
+        let later = "That's not the right answer. Please wait 5 minutes before trying again.";
+        assert_eq!(parse_lockout(&page(later)), Some(Duration::from_secs(300)));
+        assert_eq!(parse_lockout(&page(low)), None);
+        let level = "You don't seem to be solving the right level. Did you already complete it?";
+        assert_eq!(parse_verdict(&page(level)), Verdict::WrongLevel);
+        assert!(matches!(
+            parse_verdict("<p>maintenance</p>"),
+            Verdict::Unknown(_)
+        ));
+    }
+
+    #[test]
+    fn refuses_what_is_known_to_be_wrong() {
+        let dir = cache_dir("history");
+        let path = dir.join("day19_answers.tsv");
+        let mut history = History::load(&path).unwrap();
+        history.record(1, "1500", &Verdict::TooHigh, 0).unwrap();
+        history.record(1, "900", &Verdict::TooLow, 0).unwrap();
+        history.record(1, "1234", &Verdict::Wrong, 0).unwrap();
+        history
+            .record(1, "1300", &Verdict::Wait(Duration::from_secs(60)), 10)
+            .unwrap();
+
+        let history = History::load(&path).unwrap();
+        assert!(history.objection(1, "1234", 100).is_some());
+        assert!(history.objection(1, "1600", 100).is_some());
+        assert!(history.objection(1, "900", 100).is_some());
+        assert!(history.objection(1, "1300", 50).is_some());
+        assert_eq!(history.objection(1, "1300", 100), None);
+        assert_eq!(history.objection(2, "1600", 100), None);
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn submits_and_remembers() {
+        let high = page("That's not the right answer; your answer is too high.");
+        let right = page("That's the right answer!");
+        let (url, requests) = stub_server(vec![(200, high), (200, right)]);
+        let dir = cache_dir("submit");
+        let client = Client::new(&url, &dir)
+            .session("abc")
+            .min_interval(Duration::ZERO);
+
+        assert_eq!(
+            client.submit(2022, 19, 2, "2000").unwrap(),
+            Verdict::TooHigh
+        );
+        let request = requests.recv().unwrap();
+        assert_eq!(request.line, "POST /2022/day/19/answer HTTP/1.1");
+        assert_eq!(request.body, "level=2&answer=2000");
+        // refused without asking the server
+        assert!(client.submit(2022, 19, 2, "2500").is_err());
+        assert_eq!(
+            client.submit(2022, 19, 2, "1834").unwrap(),
+            Verdict::Correct
+        );
+        assert!(client.submit(2022, 19, 2, "1834").is_err());
+        assert_eq!(requests.iter().count(), 1);
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn waits_out_the_lockout_of_a_wrong_answer() {
+        let low = page(
+            "That's not the right answer; your answer is too low. \
+             Please wait one minute before trying again.",
+        );
+        let (url, requests) = stub_server(vec![(200, low)]);
+        let dir = cache_dir("lockout");
+        let client = Client::new(&url, &dir)
+            .session("abc")
+            .min_interval(Duration::ZERO);
+
+        assert_eq!(client.submit(2022, 19, 1, "900").unwrap(), Verdict::TooLow);
+        requests.recv().unwrap();
+        let err = client.submit(2022, 19, 1, "1000").unwrap_err();
+        assert!(err.to_string().starts_with("not submitting 1000: wait"));
+        assert_eq!(requests.iter().count(), 0);
+        fs::remove_dir_all(dir).unwrap();
+    }
+}